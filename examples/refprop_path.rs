@@ -0,0 +1,23 @@
+use refprop::Fluid;
+
+// Print the REFPROP directory the constructors would use, and where it
+// came from.  Handy to check a REFPROP_BUILD_PATH baked in at build time.
+fn main() {
+    match Fluid::discover_refprop_path() {
+        Ok(path) => {
+            let source = if std::env::var("REFPROP_PATH").ok().as_deref() == Some(path.as_str()) {
+                "REFPROP_PATH"
+            } else if Fluid::baked_refprop_path() == Some(path.as_str()) {
+                "REFPROP_BUILD_PATH (baked in)"
+            } else {
+                "standard install location"
+            };
+            println!("{path}");
+            eprintln!("from {source}");
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            std::process::exit(1);
+        }
+    }
+}
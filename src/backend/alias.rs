@@ -0,0 +1,111 @@
+//! Fluid-name resolution: R-numbers, common names and CAS numbers →
+//! REFPROP `.FLD` file stems.
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+use std::sync::{LazyLock, RwLock};
+
+/// Built-in aliases for names that do not match a REFPROP file name.
+const BUILTIN_ALIASES: &[(&str, &str)] = &[
+    ("R50", "METHANE"),
+    ("R170", "ETHANE"),
+    ("R290", "PROPANE"),
+    ("R600", "BUTANE"),
+    ("R600A", "ISOBUTAN"),
+    ("ISOBUTANE", "ISOBUTAN"),
+    ("R601", "PENTANE"),
+    ("R601A", "IPENTANE"),
+    ("ISOPENTANE", "IPENTANE"),
+    ("R1270", "PROPYLEN"),
+    ("PROPYLENE", "PROPYLEN"),
+    ("R702", "HYDROGEN"),
+    ("R704", "HELIUM"),
+    ("R717", "AMMONIA"),
+    ("NH3", "AMMONIA"),
+    ("R718", "WATER"),
+    ("H2O", "WATER"),
+    ("R728", "NITROGEN"),
+    ("N2", "NITROGEN"),
+    ("R732", "OXYGEN"),
+    ("O2", "OXYGEN"),
+    ("R740", "ARGON"),
+    ("R744", "CO2"),
+    ("CARBONDIOXIDE", "CO2"),
+];
+
+/// Aliases registered at runtime; they take precedence over everything.
+static USER_ALIASES: LazyLock<RwLock<HashMap<String, String>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
+/// Normalise a fluid name or file name to an upper-case `.FLD` stem.
+fn stem(name: &str) -> String {
+    let upper = name.trim().to_uppercase();
+    match upper.strip_suffix(".FLD") {
+        Some(s) => s.to_string(),
+        None => upper,
+    }
+}
+
+/// Register (or replace) a process-wide alias `name` → `fld`.
+pub fn register(name: &str, fld: &str) {
+    let mut map = USER_ALIASES.write().unwrap_or_else(|e| e.into_inner());
+    map.insert(stem(name), stem(fld));
+}
+
+/// Alias registered with [`register`], if any.
+pub fn user_alias(upper_name: &str) -> Option<String> {
+    let map = USER_ALIASES.read().unwrap_or_else(|e| e.into_inner());
+    map.get(upper_name).cloned()
+}
+
+/// Built-in alias, if any.
+pub fn builtin_alias(upper_name: &str) -> Option<&'static str> {
+    BUILTIN_ALIASES
+        .iter()
+        .find(|(alias, _)| *alias == upper_name)
+        .map(|(_, fld)| *fld)
+}
+
+/// `true` for strings shaped like a CAS registry number (`74-98-6`).
+pub fn is_cas_number(s: &str) -> bool {
+    let parts: Vec<&str> = s.split('-').collect();
+    parts.len() == 3
+        && (2..=7).contains(&parts[0].len())
+        && parts[1].len() == 2
+        && parts[2].len() == 1
+        && parts.iter().all(|p| p.bytes().all(|b| b.is_ascii_digit()))
+}
+
+/// Scan the `.FLD` headers in `fluids_dir` for the given CAS number and
+/// return the matching file stem.
+///
+/// REFPROP fluid files carry the CAS number on a header line tagged
+/// `!CAS number`; only the first few lines of each file are read.
+pub fn find_by_cas(fluids_dir: &Path, cas: &str) -> Option<String> {
+    let entries = std::fs::read_dir(fluids_dir).ok()?;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let is_fld = path
+            .extension()
+            .is_some_and(|e| e.eq_ignore_ascii_case("fld"));
+        if !is_fld {
+            continue;
+        }
+        let Ok(file) = File::open(&path) else {
+            continue;
+        };
+        let found = BufReader::new(file)
+            .split(b'\n')
+            .take(10)
+            .flatten()
+            .map(|line| String::from_utf8_lossy(&line).into_owned())
+            .filter(|line| line.to_uppercase().contains("!CAS"))
+            .any(|line| line.split('!').next().unwrap_or_default().trim() == cas);
+        if found {
+            return path.file_stem().map(|s| s.to_string_lossy().to_uppercase());
+        }
+    }
+    None
+}
@@ -1 +1,2 @@
+pub mod alias;
 pub mod refprop;
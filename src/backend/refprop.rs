@@ -1,10 +1,14 @@
 use std::os::raw::c_long;
-use std::path::PathBuf;
-use std::sync::atomic::{AtomicUsize, Ordering};
-use std::sync::{Mutex, MutexGuard};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, OnceLock, TryLockError};
+use std::time::{Duration, Instant};
 
+use crate::capture::OutputCapture;
 use crate::sys::*;
 
+use super::alias;
+
 use crate::error::*;
 use crate::properties::*;
 
@@ -13,13 +17,97 @@ use crate::properties::*;
 // only re-call SETUPdll when the active fluid changes.
 static REFPROP_LOCK: Mutex<usize> = Mutex::new(0);
 static NEXT_BACKEND_ID: AtomicUsize = AtomicUsize::new(1);
+// Whether REFPROP's global extrapolation flag is currently switched on,
+// so a backend that does not want it can switch it back off on setup.
+static EXTRAPOLATION_FLAG_SET: AtomicBool = AtomicBool::new(false);
+// Whether REFPROP's console output was switched off for quiet setup.
+static CONSOLE_OUTPUT_OFF: AtomicBool = AtomicBool::new(false);
+// Whether the last SETUPdll ran with a non-default equation of state
+// (SETMODdll), so the next backend on the default model resets it.
+static EOS_MODEL_SET: AtomicBool = AtomicBool::new(false);
+// Contention counters of REFPROP_LOCK, reported by `lock_stats`.
+static LOCK_ACQUISITIONS: AtomicU64 = AtomicU64::new(0);
+static LOCK_WAIT_TOTAL_NS: AtomicU64 = AtomicU64::new(0);
+static LOCK_WAIT_MAX_NS: AtomicU64 = AtomicU64::new(0);
+
+/// `FLAGSdll` name of REFPROP's "allow extrapolation" switch, as listed
+/// in the `FLAGSdll` section of the REFPROP 10 documentation (`jflag`
+/// 1 = on, 0 = off, -999 = query).  The extrapolation test in
+/// `tests/flash_calculations.rs` checks the name against the DLL.
+const EXTRAPOLATION_FLAG: &str = "Extrapolate";
+
+/// `FLAGSdll` name of REFPROP's console-output switch, cleared for
+/// quiet setup (see [`crate::capture::set_quiet_setup`]).
+const CONSOLE_OUTPUT_FLAG: &str = "Write to console";
+
+/// Warning codes REFPROP emits for inputs slightly outside the
+/// validated range (see `LIMITX`):
+///
+/// | Code | Meaning                                   |
+/// |------|-------------------------------------------|
+/// | -1   | T above Tmax (but below 1.5·Tmax)         |
+/// | -2   | D above Dmax (but below 1.1·Dmax)         |
+/// | -4   | P above Pmax (but below 2·Pmax)           |
+/// | -3, -5, -6, -7 | combinations of the above       |
+///
+/// Composition warnings (-8 and up) are never suppressed.
+const EXTRAPOLATION_WARNINGS: [i32; 7] = [-1, -2, -3, -4, -5, -6, -7];
+
+/// Deviation of a `.MIX` composition sum from 1 above which the
+/// renormalization is reported on stderr.
+const COMPOSITION_SUM_TOLERANCE: f64 = 1e-6;
+
+/// Isochores of the inversion curve behind
+/// [`RefpropBackend::jt_inversion_maximum`].
+const INVERSION_CURVE_POINTS: usize = 200;
+
+/// Input pairs accepted by [`RefpropBackend::get`], in canonical
+/// order.  Each pair is also accepted swapped, and `"D"` may be spelled
+/// `"RHO"`.  [`flash_pair_inner`](RefpropBackend::flash_pair_inner)
+/// dispatches from this list.
+pub const INPUT_PAIRS: [(&str, &str); 12] = [
+    ("T", "P"),
+    ("P", "H"),
+    ("P", "S"),
+    ("T", "Q"),
+    ("P", "Q"),
+    ("T", "D"),
+    ("T", "H"),
+    ("T", "S"),
+    ("P", "D"),
+    ("D", "H"),
+    ("D", "S"),
+    ("H", "S"),
+];
+
+/// Output keys accepted by [`RefpropBackend::get`], aliases included.
+/// The `*MOLAR` keys return the REFPROP molar value whatever the
+/// configured units.
+pub const OUTPUT_KEYS: [&str; 27] = [
+    "T", "P", "D", "RHO", "H", "S", "Q", "CV", "CP", "W", "A", "E", "U", "ETA", "V", "VIS", "TCX",
+    "L", "LAMBDA", "TSAT", "PSAT", "DMOLAR", "HMOLAR", "SMOLAR", "UMOLAR", "CVMOLAR", "CPMOLAR",
+];
+
+/// Output keys accepted by [`RefpropBackend::get1`].
+const GET1_OUTPUTS: [&str; 10] = [
+    "PSAT", "TSAT", "DLIQ", "DVAP", "TCRIT", "PCRIT", "DCRIT", "M", "TTRP", "TNBP",
+];
+
+/// File name of a [`RefpropBackend::new_from_definition`] fluid inside
+/// its private directory.
+const DEFINITION_FILE: &str = "DEFINITION.FLD";
+
+/// Fixed-size molar composition array, as exchanged with REFPROP.
+type Composition = [f64; REFPROP_NC_MAX];
 
 // ── Backend ─────────────────────────────────────────────────────────
 
 #[allow(dead_code)]
 pub struct RefpropBackend {
     id: usize,
-    lib: RefpropLibrary,
+    /// Shared with every backend built from the same
+    /// [`from_library`](Self::from_library) handle.
+    lib: Arc<RefpropLibrary>,
     refprop_path: PathBuf,
     /// Number of components (1 for pure fluids).
     nc: usize,
@@ -28,6 +116,30 @@ pub struct RefpropBackend {
     /// Pipe-separated fluid file string, e.g. `"R134A.FLD"` or
     /// `"R32.FLD|R125.FLD"`.
     hfld_str: String,
+    /// Evaluate outside the validated range without range warnings.
+    allow_extrapolation: bool,
+    /// Warning codes (`ierr < 0`) accepted without logging, sorted.
+    ignored_warnings: Vec<i32>,
+    /// Rule for two-phase viscosity (and conductivity) estimates.
+    two_phase_viscosity: TwoPhaseViscosityModel,
+    /// Enthalpy/entropy reference state passed to `SETUPdll`.
+    reference_state: ReferenceState,
+    /// Equation-of-state model code set with `SETMODdll` before each
+    /// `SETUPdll`; `None` for REFPROP's recommended model.
+    eos_model: Option<String>,
+    /// Maximum Joule-Thomson inversion (T, P), computed on first use
+    /// and cleared when the model or the composition changes.
+    jt_inversion_max: OnceLock<(f64, f64)>,
+    /// Number of flash calculations run so far (diagnostic).
+    flashes: AtomicUsize,
+    /// Number of `TRNPRPdll` calls run so far (diagnostic).
+    transport_calls: AtomicUsize,
+    /// Number of REFPROP warnings logged so far (diagnostic).
+    warnings: AtomicUsize,
+    /// Private directory holding an in-memory fluid definition written
+    /// to disk (see [`new_from_definition`](Self::new_from_definition));
+    /// removed on drop.
+    temp_dir: Option<PathBuf>,
 }
 
 impl RefpropBackend {
@@ -38,18 +150,53 @@ impl RefpropBackend {
     /// Create a backend for a **pure fluid** or a **predefined mixture**
     /// (auto-detected from `.FLD` / `.MIX` files).
     pub fn new(fluid_name: &str, refprop_path: &str) -> Result<Self> {
-        let path = PathBuf::from(refprop_path);
-        if !path.exists() {
-            return Err(RefpropError::LibraryNotFound(refprop_path.to_string()));
+        Self::new_with_paths(fluid_name, refprop_path, refprop_path)
+    }
+
+    /// Like [`new`](Self::new), but loads the shared library from
+    /// `dll_dir` and the fluid files from `data_dir`, the directory
+    /// holding the `fluids/` and `mixtures/` folders (passed to
+    /// `SETPATHdll`).
+    pub fn new_with_paths(fluid_name: &str, dll_dir: &str, data_dir: &str) -> Result<Self> {
+        let dll_path = PathBuf::from(dll_dir);
+        if !dll_path.exists() {
+            return Err(RefpropError::LibraryNotFound(dll_dir.to_string()));
+        }
+        let path = PathBuf::from(data_dir);
+        if !path.is_dir() {
+            return Err(RefpropError::FluidNotFound(format!(
+                "{fluid_name} (fluids directory {data_dir} does not exist)"
+            )));
         }
 
-        let lib = RefpropLibrary::load_from_dir(&path)
-            .map_err(|e| RefpropError::LibraryNotFound(e.to_string()))?;
+        // Lenient: a DLL lacking e.g. SETMIXdll still serves pure
+        // fluids; calls to a missing routine fail with SymbolNotFound.
+        let lib = RefpropLibrary::load_lenient_from_dir(&dll_path)?;
+        Self::with_library(Arc::new(lib), fluid_name, path)
+    }
+
+    /// Create a backend around an already loaded library, skipping
+    /// path discovery and library loading.  Fluid files are looked up
+    /// in the directory the library was loaded from.
+    pub fn from_library(lib: Arc<RefpropLibrary>, fluid_name: &str) -> Result<Self> {
+        let path = lib.path().parent().unwrap_or(Path::new("")).to_path_buf();
+        if !path.is_dir() {
+            return Err(RefpropError::FluidNotFound(format!(
+                "{fluid_name} (library {} has no data directory)",
+                lib.path().display()
+            )));
+        }
+        Self::with_library(lib, fluid_name, path)
+    }
 
+    /// Body of [`new_with_paths`](Self::new_with_paths) and
+    /// [`from_library`](Self::from_library): `path` is the data root
+    /// passed to `SETPATHdll`.
+    fn with_library(lib: Arc<RefpropLibrary>, fluid_name: &str, path: PathBuf) -> Result<Self> {
         // Set REFPROP path first (needed for both pure & mix)
         Self::set_path_raw(&lib, &path);
 
-        let upper = fluid_name.to_uppercase();
+        let upper = Self::resolve_fluid_name(&path, fluid_name);
         let fld_exists = Self::fluid_file_exists(&path, &upper);
         let mix_path = Self::find_mix_file(&path, &upper);
 
@@ -68,6 +215,7 @@ impl RefpropBackend {
             let mut ierr: i32 = 0;
             let mut herr = [0i8; REFPROP_STRLEN];
 
+            let _quiet = Self::quiet_setup_guard(&lib);
             unsafe {
                 lib.SETMIXdll(
                     hmxnme.as_ptr(),
@@ -83,9 +231,17 @@ impl RefpropBackend {
                     REFPROP_STRLEN as c_long,
                     REFPROP_FILESTR as c_long,
                     REFPROP_STRLEN as c_long,
+                )
+            }?;
+            Self::check_err(ierr, &herr)?;
+
+            let sum = Self::normalize_composition(&mut z[..nc as usize]);
+            if (sum - 1.0).abs() > COMPOSITION_SUM_TOLERANCE {
+                eprintln!(
+                    "[refprop] warning: composition of {} sums to {sum}, renormalized to 1",
+                    mix.display()
                 );
             }
-            Self::check_err(ierr, &herr)?;
 
             let id = NEXT_BACKEND_ID.fetch_add(1, Ordering::Relaxed);
             let hfld_str = from_c_string(&hfld_buf);
@@ -97,6 +253,16 @@ impl RefpropBackend {
                 nc: nc as usize,
                 z,
                 hfld_str,
+                allow_extrapolation: false,
+                ignored_warnings: Vec::new(),
+                two_phase_viscosity: TwoPhaseViscosityModel::default(),
+                reference_state: ReferenceState::default(),
+                eos_model: None,
+                jt_inversion_max: OnceLock::new(),
+                flashes: AtomicUsize::new(0),
+                transport_calls: AtomicUsize::new(0),
+                warnings: AtomicUsize::new(0),
+                temp_dir: None,
             })
         } else if fld_exists {
             // ── Pure fluid (.FLD file) ──────────────────────────────
@@ -111,6 +277,16 @@ impl RefpropBackend {
                 nc: 1,
                 z,
                 hfld_str,
+                allow_extrapolation: false,
+                ignored_warnings: Vec::new(),
+                two_phase_viscosity: TwoPhaseViscosityModel::default(),
+                reference_state: ReferenceState::default(),
+                eos_model: None,
+                jt_inversion_max: OnceLock::new(),
+                flashes: AtomicUsize::new(0),
+                transport_calls: AtomicUsize::new(0),
+                warnings: AtomicUsize::new(0),
+                temp_dir: None,
             };
             backend.setup_fluid_locked()?;
             Ok(backend)
@@ -135,17 +311,43 @@ impl RefpropBackend {
             )));
         }
 
-        let lib = RefpropLibrary::load_from_dir(&path)
-            .map_err(|e| RefpropError::LibraryNotFound(e.to_string()))?;
+        let resolved: Vec<String> = components
+            .iter()
+            .map(|(name, _)| Self::resolve_fluid_name(&path, name))
+            .collect();
+        let hfld_str = resolved
+            .iter()
+            .map(|upper| format!("{upper}.FLD"))
+            .collect::<Vec<_>>()
+            .join("|");
+
+        // to_c_string truncates silently: SETUPdll would then load a
+        // shorter (or half-named) component list.
+        if hfld_str.len() >= REFPROP_FILESTR {
+            return Err(RefpropError::InvalidInput(format!(
+                "Fluid file string for the {} components is {} characters long; \
+                 REFPROP accepts at most {}",
+                components.len(),
+                hfld_str.len(),
+                REFPROP_FILESTR - 1
+            )));
+        }
+
+        // Check every component up front: SETUPdll only reports a
+        // missing file as a generic error for the whole mixture.
+        for ((name, _), upper) in components.iter().zip(&resolved) {
+            if !Self::fluid_file_exists(&path, upper) {
+                return Err(RefpropError::FluidNotFound(format!(
+                    "mixture component {name} (no {upper}.FLD in fluids/)"
+                )));
+            }
+        }
+
+        let lib = Arc::new(RefpropLibrary::load_lenient_from_dir(&path)?);
 
         Self::set_path_raw(&lib, &path);
 
         let nc = components.len();
-        let hfld_str: String = components
-            .iter()
-            .map(|(name, _)| format!("{}.FLD", name.to_uppercase()))
-            .collect::<Vec<_>>()
-            .join("|");
 
         let mut z = [0.0f64; REFPROP_NC_MAX];
         for (i, (_, frac)) in components.iter().enumerate() {
@@ -160,23 +362,137 @@ impl RefpropBackend {
             nc,
             z,
             hfld_str,
+            allow_extrapolation: false,
+            ignored_warnings: Vec::new(),
+            two_phase_viscosity: TwoPhaseViscosityModel::default(),
+            reference_state: ReferenceState::default(),
+            eos_model: None,
+            jt_inversion_max: OnceLock::new(),
+            flashes: AtomicUsize::new(0),
+            transport_calls: AtomicUsize::new(0),
+            warnings: AtomicUsize::new(0),
+            temp_dir: None,
         };
         backend.setup_fluid_locked()?;
         Ok(backend)
     }
 
+    /// Create a backend for a pure fluid from the **contents** of a
+    /// `.FLD` file instead of a file in the installed `fluids/` folder.
+    ///
+    /// The contents are written to a private temporary directory and
+    /// passed to `SETUPdll` by full path; the directory is removed when
+    /// the backend is dropped.  The DLL and `HMX.BNC` still come from
+    /// `refprop_path`.  The contents are raw bytes, so files with
+    /// Latin-1 comments load unchanged.
+    pub fn new_from_definition(fld_contents: &[u8], refprop_path: &str) -> Result<Self> {
+        let path = PathBuf::from(refprop_path);
+        if !path.exists() {
+            return Err(RefpropError::LibraryNotFound(refprop_path.to_string()));
+        }
+        if fld_contents.trim_ascii().is_empty() {
+            return Err(RefpropError::InvalidInput(
+                "Fluid definition is empty".into(),
+            ));
+        }
+
+        let lib = Arc::new(RefpropLibrary::load_lenient_from_dir(&path)?);
+        Self::set_path_raw(&lib, &path);
+
+        let temp_dir = Self::write_definition(&std::env::temp_dir(), fld_contents)?;
+        let fld_path = temp_dir.join(DEFINITION_FILE);
+
+        let mut z = [0.0f64; REFPROP_NC_MAX];
+        z[0] = 1.0;
+        let id = NEXT_BACKEND_ID.fetch_add(1, Ordering::Relaxed);
+        let backend = Self {
+            id,
+            lib,
+            refprop_path: path,
+            nc: 1,
+            z,
+            hfld_str: fld_path.to_string_lossy().into_owned(),
+            allow_extrapolation: false,
+            ignored_warnings: Vec::new(),
+            two_phase_viscosity: TwoPhaseViscosityModel::default(),
+            reference_state: ReferenceState::default(),
+            eos_model: None,
+            jt_inversion_max: OnceLock::new(),
+            flashes: AtomicUsize::new(0),
+            transport_calls: AtomicUsize::new(0),
+            warnings: AtomicUsize::new(0),
+            temp_dir: Some(temp_dir),
+        };
+        backend.setup_fluid_locked()?;
+        Ok(backend)
+    }
+
+    /// Write `contents` to [`DEFINITION_FILE`] in a new directory
+    /// `refprop-def-<pid>-<n>` under `base` and return the directory.
+    ///
+    /// The directory is created exclusively (owner-only on Unix), moving
+    /// on to the next `n` when the name is taken, and the file with
+    /// `create_new`, so nothing planted in a shared temporary directory
+    /// is followed.  The directory is removed again if the write fails.
+    fn write_definition(base: &Path, contents: &[u8]) -> Result<PathBuf> {
+        use std::io::Write;
+
+        static NEXT_DEFINITION: AtomicUsize = AtomicUsize::new(0);
+
+        let mut builder = std::fs::DirBuilder::new();
+        #[cfg(unix)]
+        std::os::unix::fs::DirBuilderExt::mode(&mut builder, 0o700);
+        let dir = loop {
+            let n = NEXT_DEFINITION.fetch_add(1, Ordering::Relaxed);
+            let dir = base.join(format!("refprop-def-{}-{n}", std::process::id()));
+            match builder.create(&dir) {
+                Ok(()) => break dir,
+                Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => continue,
+                Err(e) => return Err(e.into()),
+            }
+        };
+
+        let written = std::fs::File::options()
+            .write(true)
+            .create_new(true)
+            .open(dir.join(DEFINITION_FILE))
+            .and_then(|mut file| file.write_all(contents));
+        if let Err(e) = written {
+            let _ = std::fs::remove_dir_all(&dir);
+            return Err(e.into());
+        }
+        Ok(dir)
+    }
+
     // ================================================================
     //  Lock helper
     // ================================================================
 
     /// Acquire the global REFPROP lock, recovering gracefully from
-    /// poisoning instead of panicking.
+    /// poisoning instead of panicking.  The wait is recorded in the
+    /// counters reported by [`lock_stats`](Self::lock_stats).
     fn lock_refprop() -> Result<MutexGuard<'static, usize>> {
-        REFPROP_LOCK.lock().map_err(|_| {
+        let start = Instant::now();
+        let guard = REFPROP_LOCK.lock().map_err(|_| {
             RefpropError::CalculationFailed(
                 "REFPROP global lock is poisoned (a previous call panicked)".into(),
             )
-        })
+        })?;
+        let waited = u64::try_from(start.elapsed().as_nanos()).unwrap_or(u64::MAX);
+        LOCK_ACQUISITIONS.fetch_add(1, Ordering::Relaxed);
+        LOCK_WAIT_TOTAL_NS.fetch_add(waited, Ordering::Relaxed);
+        LOCK_WAIT_MAX_NS.fetch_max(waited, Ordering::Relaxed);
+        Ok(guard)
+    }
+
+    /// Acquisition count and wait times of the global REFPROP lock,
+    /// across all backends since the process started.
+    pub fn lock_stats() -> LockStats {
+        LockStats {
+            acquisitions: LOCK_ACQUISITIONS.load(Ordering::Relaxed),
+            total_wait: Duration::from_nanos(LOCK_WAIT_TOTAL_NS.load(Ordering::Relaxed)),
+            max_wait: Duration::from_nanos(LOCK_WAIT_MAX_NS.load(Ordering::Relaxed)),
+        }
     }
 
     // ================================================================
@@ -193,6 +509,28 @@ impl RefpropBackend {
         Ok(())
     }
 
+    /// Molar vapor quality as a fraction: finite and within [0, 1].
+    /// The bounds are included (saturated liquid and saturated vapor);
+    /// anything outside is rejected instead of being clamped to them.
+    fn validate_quality(q: f64) -> Result<()> {
+        if !(0.0..=1.0).contains(&q) {
+            return Err(RefpropError::InvalidInput(format!(
+                "quality must be between 0 and 1 (saturated liquid to saturated vapor), got {q}"
+            )));
+        }
+        Ok(())
+    }
+
+    fn validate_kph(kph: i32) -> Result<()> {
+        if kph == 1 || kph == 2 {
+            Ok(())
+        } else {
+            Err(RefpropError::InvalidInput(format!(
+                "kph must be 1 (bubble) or 2 (dew), got {kph}"
+            )))
+        }
+    }
+
     // ================================================================
     //  Setup helpers
     // ================================================================
@@ -203,6 +541,53 @@ impl RefpropBackend {
         unsafe { lib.SETPATHdll(path_c.as_ptr(), path_str.len() as c_long) };
     }
 
+    /// Map a user-supplied fluid name to a REFPROP file stem.
+    ///
+    /// Order: runtime aliases, then the name itself when a `.FLD` or
+    /// `.MIX` file exists, then built-in aliases (R-numbers, common
+    /// names), then a CAS-number scan of the `.FLD` headers.  Falls
+    /// back to the upper-cased name so the caller reports it as missing.
+    fn resolve_fluid_name(base: &PathBuf, name: &str) -> String {
+        let upper = name.trim().to_uppercase();
+        if let Some(fld) = alias::user_alias(&upper) {
+            return fld;
+        }
+        if Self::fluid_file_exists(base, &upper) || Self::find_mix_file(base, &upper).is_some() {
+            return upper;
+        }
+        if let Some(fld) = alias::builtin_alias(&upper) {
+            return fld.to_string();
+        }
+        if alias::is_cas_number(&upper) {
+            for dir in ["fluids", "FLUIDS"] {
+                if let Some(fld) = alias::find_by_cas(&base.join(dir), &upper) {
+                    return fld;
+                }
+            }
+        }
+        upper
+    }
+
+    /// Scale `z` so that `z.iter().sum()` is exactly `1.0` and return
+    /// the original sum.
+    ///
+    /// The last fraction is set to `1 − (sum of the others)`: the
+    /// rounding error of that subtraction is at most half an ulp of a
+    /// number in (0, 1), which the final addition always rounds away.
+    fn normalize_composition(z: &mut [f64]) -> f64 {
+        let sum: f64 = z.iter().sum();
+        if sum <= 0.0 {
+            return sum;
+        }
+        for x in z.iter_mut() {
+            *x /= sum;
+        }
+        if let Some((last, rest)) = z.split_last_mut() {
+            *last = 1.0 - rest.iter().sum::<f64>();
+        }
+        sum
+    }
+
     fn fluid_file_exists(base: &PathBuf, upper_name: &str) -> bool {
         let fld = format!("{upper_name}.FLD");
         base.join("fluids").join(&fld).exists() || base.join("FLUIDS").join(&fld).exists()
@@ -236,10 +621,22 @@ impl RefpropBackend {
         let nc_i: i32 = self.nc as i32;
         let hfld = to_c_string(&self.hfld_str, REFPROP_FILESTR);
         let hfmix = to_c_string("HMX.BNC", REFPROP_STRLEN);
-        let hrf = to_c_string("DEF", REFPROP_STRLEN);
+        let hrf = to_c_string(self.reference_state.code(), REFPROP_STRLEN);
         let mut ierr: i32 = 0;
         let mut herr = [0i8; REFPROP_STRLEN];
 
+        let _quiet = Self::quiet_setup_guard(&self.lib);
+        match &self.eos_model {
+            Some(model) => {
+                self.set_model_raw("EOS", model)?;
+                EOS_MODEL_SET.store(true, Ordering::Relaxed);
+            }
+            None if EOS_MODEL_SET.load(Ordering::Relaxed) => {
+                self.set_model_raw("NBS", "NBS")?;
+                EOS_MODEL_SET.store(false, Ordering::Relaxed);
+            }
+            None => {}
+        }
         unsafe {
             self.lib.SETUPdll(
                 &nc_i,
@@ -255,9 +652,214 @@ impl RefpropBackend {
             );
         }
         Self::check_err(ierr, &herr)?;
+        self.apply_extrapolation_flag();
+        Ok(())
+    }
+
+    /// SETMODdll: use model `model` of type `htype` for every component
+    /// at the next SETUPdll (`"NBS"`, `"NBS"` restores the recommended
+    /// models).  **Caller must hold REFPROP_LOCK.**
+    fn set_model_raw(&self, htype: &str, model: &str) -> Result<()> {
+        const CODE_LEN: usize = 3;
+
+        let nc_i: i32 = self.nc as i32;
+        let htype = to_c_string(htype, REFPROP_STRLEN);
+        let hmix = to_c_string("HMX", REFPROP_STRLEN);
+        let code = format!("{model:<CODE_LEN$}");
+        let hcomp: Vec<i8> = code
+            .bytes()
+            .take(CODE_LEN)
+            .map(|b| b as i8)
+            .cycle()
+            .take(CODE_LEN * REFPROP_NC_MAX)
+            .collect();
+        let mut ierr: i32 = 0;
+        let mut herr = [0i8; REFPROP_STRLEN];
+        unsafe {
+            self.lib.SETMODdll(
+                &nc_i,
+                htype.as_ptr(),
+                hmix.as_ptr(),
+                hcomp.as_ptr(),
+                &mut ierr,
+                herr.as_mut_ptr(),
+                CODE_LEN as c_long,
+                CODE_LEN as c_long,
+                CODE_LEN as c_long,
+                REFPROP_STRLEN as c_long,
+            )
+        }?;
+        Self::check_err(ierr, &herr)
+    }
+
+    /// Push this backend's extrapolation setting into REFPROP's global
+    /// flags.  Skipped when the flag is already in the wanted state.
+    /// **Caller must hold REFPROP_LOCK.**
+    ///
+    /// Older DLLs without `FLAGSdll` (or without the flag) are not an
+    /// error: warning suppression in [`check`](Self::check) still
+    /// applies.
+    fn apply_extrapolation_flag(&self) {
+        if EXTRAPOLATION_FLAG_SET.load(Ordering::Relaxed) == self.allow_extrapolation {
+            return;
+        }
+        if Self::set_flag_raw(&self.lib, EXTRAPOLATION_FLAG, self.allow_extrapolation) {
+            EXTRAPOLATION_FLAG_SET.store(self.allow_extrapolation, Ordering::Relaxed);
+        }
+    }
+
+    /// Bring REFPROP's console flag in line with quiet setup before a
+    /// `SETUPdll` / `SETMIXdll` call.  **Caller must hold REFPROP_LOCK.**
+    ///
+    /// When quiet setup is on but the DLL does not know the flag, the
+    /// returned capture discards stdout for the rest of the setup.
+    fn quiet_setup_guard(lib: &RefpropLibrary) -> Option<OutputCapture> {
+        let quiet = crate::capture::quiet_setup();
+        if CONSOLE_OUTPUT_OFF.load(Ordering::Relaxed) != quiet
+            && Self::set_flag_raw(lib, CONSOLE_OUTPUT_FLAG, !quiet)
+        {
+            CONSOLE_OUTPUT_OFF.store(quiet, Ordering::Relaxed);
+        }
+        if quiet && !CONSOLE_OUTPUT_OFF.load(Ordering::Relaxed) {
+            crate::capture::discard_stdout()
+        } else {
+            None
+        }
+    }
+
+    /// FLAGSdll: switch the named REFPROP flag on or off.  Returns
+    /// `false` when the DLL lacks `FLAGSdll` or rejects the flag.
+    /// **Caller must hold REFPROP_LOCK.**
+    fn set_flag_raw(lib: &RefpropLibrary, flag: &str, on: bool) -> bool {
+        let hflag = to_c_string(flag, REFPROP_STRLEN);
+        let jflag: i32 = on as i32;
+        let mut kflag: i32 = 0;
+        let mut ierr: i32 = 0;
+        let mut herr = [0i8; REFPROP_STRLEN];
+
+        let called = unsafe {
+            lib.FLAGSdll(
+                hflag.as_ptr(),
+                &jflag,
+                &mut kflag,
+                &mut ierr,
+                herr.as_mut_ptr(),
+                REFPROP_STRLEN as c_long,
+                REFPROP_STRLEN as c_long,
+            )
+        };
+        called.is_ok() && ierr == 0
+    }
+
+    /// Select the rule used for two-phase transport estimates.
+    pub fn set_two_phase_viscosity_model(&mut self, model: TwoPhaseViscosityModel) {
+        self.two_phase_viscosity = model;
+    }
+
+    pub fn two_phase_viscosity_model(&self) -> TwoPhaseViscosityModel {
+        self.two_phase_viscosity
+    }
+
+    /// Change the enthalpy/entropy reference state and set REFPROP up
+    /// again with it.  On failure the previous state is kept.
+    pub fn set_reference_state(&mut self, reference: ReferenceState) -> Result<()> {
+        let previous = std::mem::replace(&mut self.reference_state, reference);
+        let mut cid = Self::lock_refprop()?;
+        if let Err(e) = self.setup_fluid_inner() {
+            self.reference_state = previous;
+            *cid = 0;
+            return Err(e);
+        }
+        *cid = self.id;
+        Ok(())
+    }
+
+    pub fn reference_state(&self) -> ReferenceState {
+        self.reference_state
+    }
+
+    /// Switch the equation of state (`None`: REFPROP's recommended one)
+    /// and set REFPROP up again with it.  On failure the previous model
+    /// is kept.
+    pub fn set_eos_model(&mut self, model: Option<String>) -> Result<()> {
+        let previous = std::mem::replace(&mut self.eos_model, model);
+        let mut cid = Self::lock_refprop()?;
+        if let Err(e) = self.setup_fluid_inner() {
+            self.eos_model = previous;
+            *cid = 0;
+            return Err(e);
+        }
+        *cid = self.id;
+        self.jt_inversion_max.take();
+        Ok(())
+    }
+
+    pub fn eos_model(&self) -> Option<&str> {
+        self.eos_model.as_deref()
+    }
+
+    /// Equation-of-state model codes listed in the `.FLD` file of a
+    /// pure fluid: the recommended `#EOS` model first, then the
+    /// alternatives of the `@EOS` sections.
+    pub fn available_eos_models(&self) -> Result<Vec<String>> {
+        if self.nc != 1 {
+            return Err(RefpropError::InvalidInput(
+                "available_models is only defined for pure fluids".into(),
+            ));
+        }
+        let (_, contents) = self.fld_contents(0)?;
+        Ok(Self::eos_models(&contents))
+    }
+
+    /// Model code on the line after each `#EOS` / `@EOS` header.
+    fn eos_models(contents: &str) -> Vec<String> {
+        let mut models: Vec<String> = Vec::new();
+        let mut lines = contents.lines().map(str::trim);
+        while let Some(line) = lines.next() {
+            let upper = line.to_uppercase();
+            if !(upper.starts_with("#EOS") || upper.starts_with("@EOS")) {
+                continue;
+            }
+            let code = lines
+                .next()
+                .and_then(|model| model.split_whitespace().next())
+                .map(str::to_uppercase);
+            if let Some(code) = code.filter(|c| !models.contains(c)) {
+                models.push(code);
+            }
+        }
+        models
+    }
+
+    /// Enable or disable extrapolation beyond the validated range.
+    pub fn set_allow_extrapolation(&mut self, allow: bool) -> Result<()> {
+        self.allow_extrapolation = allow;
+        let mut cid = Self::lock_refprop()?;
+        self.ensure_setup(&mut cid)?;
+        self.apply_extrapolation_flag();
+        Ok(())
+    }
+
+    /// Replace the warning codes that [`check`](Self::check) accepts
+    /// without logging.  Only warnings (negative codes) can be ignored.
+    pub fn set_ignored_warnings(&mut self, codes: &[i32]) -> Result<()> {
+        if let Some(code) = codes.iter().find(|&&c| c >= 0) {
+            return Err(RefpropError::InvalidInput(format!(
+                "Only REFPROP warnings (negative codes) can be ignored, got {code}"
+            )));
+        }
+        let mut codes = codes.to_vec();
+        codes.sort_unstable();
+        codes.dedup();
+        self.ignored_warnings = codes;
         Ok(())
     }
 
+    /// Warning codes currently accepted without logging.
+    pub fn ignored_warnings(&self) -> &[i32] {
+        &self.ignored_warnings
+    }
+
     /// Ensure REFPROP is set up for *this* backend.
     /// **Caller must hold `current_id` from REFPROP_LOCK.**
     fn ensure_setup(&self, current_id: &mut usize) -> Result<()> {
@@ -274,6 +876,17 @@ impl RefpropBackend {
     // ================================================================
 
     fn flash_tp_inner(&self, t: f64, p: f64) -> Result<ThermoProp> {
+        Ok(self.flash_tp_xy_inner(t, p)?.0)
+    }
+
+    /// TPFLSHdll wrapper also returning the liquid and vapor densities
+    /// (`dl`, `dv`) and compositions (`x`, `y`) of a two-phase state.
+    fn flash_tp_xy_inner(
+        &self,
+        t: f64,
+        p: f64,
+    ) -> Result<(ThermoProp, f64, f64, Composition, Composition)> {
+        self.flashes.fetch_add(1, Ordering::Relaxed);
         let (mut d, mut dl, mut dv) = (0.0, 0.0, 0.0);
         let mut x = [0.0f64; REFPROP_NC_MAX];
         let mut y = [0.0f64; REFPROP_NC_MAX];
@@ -302,10 +915,10 @@ impl RefpropBackend {
                 &mut ierr,
                 herr.as_mut_ptr(),
                 REFPROP_STRLEN as c_long,
-            );
-        }
-        Self::check_err(ierr, &herr)?;
-        Ok(ThermoProp {
+            )
+        }?;
+        self.check(ierr, &herr)?;
+        let props = ThermoProp {
             temperature: t,
             pressure: p,
             density: d,
@@ -316,10 +929,12 @@ impl RefpropBackend {
             sound_speed: w,
             quality: q,
             internal_energy: e,
-        })
+        };
+        Ok((props, dl, dv, x, y))
     }
 
     fn flash_ph_inner(&self, p: f64, h_in: f64) -> Result<ThermoProp> {
+        self.flashes.fetch_add(1, Ordering::Relaxed);
         let (mut t, mut d, mut dl, mut dv) = (0.0, 0.0, 0.0, 0.0);
         let mut x = [0.0f64; REFPROP_NC_MAX];
         let mut y = [0.0f64; REFPROP_NC_MAX];
@@ -347,9 +962,9 @@ impl RefpropBackend {
                 &mut ierr,
                 herr.as_mut_ptr(),
                 REFPROP_STRLEN as c_long,
-            );
-        }
-        Self::check_err(ierr, &herr)?;
+            )
+        }?;
+        self.check(ierr, &herr)?;
         Ok(ThermoProp {
             temperature: t,
             pressure: p,
@@ -365,6 +980,7 @@ impl RefpropBackend {
     }
 
     fn flash_ps_inner(&self, p: f64, s_in: f64) -> Result<ThermoProp> {
+        self.flashes.fetch_add(1, Ordering::Relaxed);
         let (mut t, mut d, mut dl, mut dv) = (0.0, 0.0, 0.0, 0.0);
         let mut x = [0.0f64; REFPROP_NC_MAX];
         let mut y = [0.0f64; REFPROP_NC_MAX];
@@ -392,9 +1008,9 @@ impl RefpropBackend {
                 &mut ierr,
                 herr.as_mut_ptr(),
                 REFPROP_STRLEN as c_long,
-            );
-        }
-        Self::check_err(ierr, &herr)?;
+            )
+        }?;
+        self.check(ierr, &herr)?;
         Ok(ThermoProp {
             temperature: t,
             pressure: p,
@@ -413,6 +1029,16 @@ impl RefpropBackend {
     ///
     /// `kph`: **1** = bubble point, **2** = dew point.
     fn sat_t_inner(&self, t: f64, kph: i32) -> Result<SaturationProps> {
+        self.sat_t_xy_inner(t, kph).map(|(sat, _, _)| sat)
+    }
+
+    /// SATTdll wrapper that also returns the liquid (`x`) and vapor
+    /// (`y`) phase compositions.
+    fn sat_t_xy_inner(
+        &self,
+        t: f64,
+        kph: i32,
+    ) -> Result<(SaturationProps, Composition, Composition)> {
         let (mut p, mut dl, mut dv) = (0.0, 0.0, 0.0);
         let mut x = [0.0f64; REFPROP_NC_MAX];
         let mut y = [0.0f64; REFPROP_NC_MAX];
@@ -432,21 +1058,81 @@ impl RefpropBackend {
                 &mut ierr,
                 herr.as_mut_ptr(),
                 REFPROP_STRLEN as c_long,
-            );
-        }
-        Self::check_err(ierr, &herr)?;
-        Ok(SaturationProps {
+            )
+        }?;
+        self.check(ierr, &herr)?;
+        let sat = SaturationProps {
             temperature: t,
             pressure: p,
             density_liquid: dl,
             density_vapor: dv,
-        })
+        };
+        Ok((sat, x, y))
+    }
+
+    /// SATDdll wrapper: saturation state whose liquid or vapor density
+    /// is `d`, with `kr` mapped to [`Phase::Liquid`] / [`Phase::Vapor`].
+    fn sat_d_inner(&self, d: f64) -> Result<(SaturationProps, Phase)> {
+        // Highest-temperature root where several exist
+        let kph: i32 = 1;
+        let mut kr: i32 = 0;
+        let (mut t, mut p, mut dl, mut dv) = (0.0, 0.0, 0.0, 0.0);
+        let mut x = [0.0f64; REFPROP_NC_MAX];
+        let mut y = [0.0f64; REFPROP_NC_MAX];
+        let mut ierr: i32 = 0;
+        let mut herr = [0i8; REFPROP_STRLEN];
+
+        unsafe {
+            self.lib.SATDdll(
+                &d,
+                self.z.as_ptr(),
+                &kph,
+                &mut kr,
+                &mut t,
+                &mut p,
+                &mut dl,
+                &mut dv,
+                x.as_mut_ptr(),
+                y.as_mut_ptr(),
+                &mut ierr,
+                herr.as_mut_ptr(),
+                REFPROP_STRLEN as c_long,
+            )
+        }?;
+        self.check(ierr, &herr)?;
+        // 3 and 4 are the liquid and vapor in equilibrium with a solid
+        let phase = match kr {
+            1 | 3 => Phase::Liquid,
+            2 | 4 => Phase::Vapor,
+            _ => {
+                return Err(RefpropError::CalculationFailed(format!(
+                    "SATDdll returned an unknown phase flag kr = {kr}"
+                )));
+            }
+        };
+        let sat = SaturationProps {
+            temperature: t,
+            pressure: p,
+            density_liquid: dl,
+            density_vapor: dv,
+        };
+        Ok((sat, phase))
     }
 
     /// SATPdll wrapper.
     ///
     /// `kph`: **1** = bubble point, **2** = dew point.
     fn sat_p_inner(&self, p: f64, kph: i32) -> Result<SaturationProps> {
+        self.sat_p_xy_inner(p, kph).map(|(sat, _, _)| sat)
+    }
+
+    /// SATPdll wrapper that also returns the liquid (`x`) and vapor
+    /// (`y`) phase compositions.
+    fn sat_p_xy_inner(
+        &self,
+        p: f64,
+        kph: i32,
+    ) -> Result<(SaturationProps, Composition, Composition)> {
         let (mut t, mut dl, mut dv) = (0.0, 0.0, 0.0);
         let mut x = [0.0f64; REFPROP_NC_MAX];
         let mut y = [0.0f64; REFPROP_NC_MAX];
@@ -466,26 +1152,56 @@ impl RefpropBackend {
                 &mut ierr,
                 herr.as_mut_ptr(),
                 REFPROP_STRLEN as c_long,
-            );
-        }
-        Self::check_err(ierr, &herr)?;
-        Ok(SaturationProps {
+            )
+        }?;
+        self.check(ierr, &herr)?;
+        let sat = SaturationProps {
             temperature: t,
             pressure: p,
             density_liquid: dl,
             density_vapor: dv,
-        })
+        };
+        Ok((sat, x, y))
     }
 
     /// THERMdll: compute all thermo props from (T, D).
-    fn therm_inner(&self, t: f64, d: f64) -> ThermoProp {
+    ///
+    /// THERMdll has no error flag; a density outside the range the
+    /// equation of state can evaluate shows up as non-finite output,
+    /// which is reported as [`CalculationFailed`](RefpropError::CalculationFailed).
+    fn therm_inner(&self, t: f64, d: f64) -> Result<ThermoProp> {
+        self.therm_z_inner(t, d, &self.z)
+    }
+
+    /// [`therm_inner`](Self::therm_inner) at composition `z` instead of
+    /// the loaded one, e.g. one phase of a two-phase flash.
+    fn therm_z_inner(&self, t: f64, d: f64, z: &Composition) -> Result<ThermoProp> {
+        let props = self.therm_jt_z_inner(t, d, z).0;
+        if [props.pressure, props.enthalpy, props.entropy]
+            .iter()
+            .all(|v| v.is_finite())
+        {
+            Ok(props)
+        } else {
+            Err(RefpropError::CalculationFailed(format!(
+                "THERMdll returned non-finite properties at T = {t} K, D = {d} mol/L"
+            )))
+        }
+    }
+
+    /// THERMdll, also returning the Joule-Thomson coefficient (K/kPa).
+    fn therm_jt_inner(&self, t: f64, d: f64) -> (ThermoProp, f64) {
+        self.therm_jt_z_inner(t, d, &self.z)
+    }
+
+    fn therm_jt_z_inner(&self, t: f64, d: f64, z: &Composition) -> (ThermoProp, f64) {
         let (mut p, mut e, mut h, mut s, mut cv, mut cp, mut w, mut hjt) =
             (0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
         unsafe {
             self.lib.THERMdll(
                 &t,
                 &d,
-                self.z.as_ptr(),
+                z.as_ptr(),
                 &mut p,
                 &mut e,
                 &mut h,
@@ -496,7 +1212,7 @@ impl RefpropBackend {
                 &mut hjt,
             );
         }
-        ThermoProp {
+        let props = ThermoProp {
             temperature: t,
             pressure: p,
             density: d,
@@ -507,34 +1223,238 @@ impl RefpropBackend {
             sound_speed: w,
             quality: f64::NAN,
             internal_energy: e,
-        }
+        };
+        (props, hjt)
     }
 
-    fn transport_inner(&self, t: f64, d: f64) -> Result<TransportProps> {
-        let (mut eta, mut tcx) = (0.0, 0.0);
-        let mut ierr: i32 = 0;
-        let mut herr = [0i8; REFPROP_STRLEN];
+    /// DPDDdll: (∂P/∂D)_T straight from the equation of state.
+    fn dpdd_inner(&self, t: f64, d: f64) -> f64 {
+        let mut dpdd = 0.0;
+        unsafe { self.lib.DPDDdll(&t, &d, self.z.as_ptr(), &mut dpdd) };
+        dpdd
+    }
 
-        unsafe {
-            self.lib.TRNPRPdll(
-                &t,
-                &d,
-                self.z.as_ptr(),
-                &mut eta,
+    /// Liquid and vapor spinodal points `(T, P)` at temperature `t`.
+    ///
+    /// Starting from each saturated density, the density is stepped
+    /// into the dome until (∂P/∂D)_T turns negative, then the zero
+    /// crossing is bisected.  Pressures come from THERMdll at the
+    /// spinodal densities.
+    fn spinodal_at_inner(&self, t: f64) -> Result<((f64, f64), (f64, f64))> {
+        const SCAN_STEPS: usize = 200;
+        const BISECTIONS: usize = 60;
+
+        let sat = self.sat_t_inner(t, 1)?;
+        let (dl, dv) = (sat.density_liquid, sat.density_vapor);
+        let step = (dl - dv) / SCAN_STEPS as f64;
+
+        let root = |from: f64, dir: f64| -> Result<f64> {
+            let mut stable = from;
+            for i in 1..SCAN_STEPS {
+                let d = from + dir * step * i as f64;
+                if self.dpdd_inner(t, d) <= 0.0 {
+                    let mut unstable = d;
+                    for _ in 0..BISECTIONS {
+                        let mid = 0.5 * (stable + unstable);
+                        if self.dpdd_inner(t, mid) > 0.0 {
+                            stable = mid;
+                        } else {
+                            unstable = mid;
+                        }
+                    }
+                    return Ok(0.5 * (stable + unstable));
+                }
+                stable = d;
+            }
+            Err(RefpropError::CalculationFailed(format!(
+                "No spinodal found at T = {t:.4} K (dP/dD stays positive in the dome)"
+            )))
+        };
+
+        let d_liq = root(dl, -1.0)?;
+        let d_vap = root(dv, 1.0)?;
+        Ok((
+            (t, self.therm_inner(t, d_liq)?.pressure),
+            (t, self.therm_inner(t, d_vap)?.pressure),
+        ))
+    }
+
+    /// Joule-Thomson inversion point `(T, P)` on the isochore `d`.
+    ///
+    /// Scans down from `t_hi` (where μ_JT < 0) to `t_lo` until μ_JT
+    /// turns non-negative, then bisects.  `None` if the isochore has no
+    /// inversion in that range.
+    fn inversion_point_inner(&self, d: f64, t_lo: f64, t_hi: f64) -> Option<(f64, f64)> {
+        const SCAN_STEPS: usize = 400;
+        const BISECTIONS: usize = 60;
+
+        let jt = |t: f64| self.therm_jt_inner(t, d).1;
+        if jt(t_hi) >= 0.0 {
+            return None;
+        }
+        let step = (t_hi - t_lo) / SCAN_STEPS as f64;
+        let mut warm = t_hi;
+        for i in 1..=SCAN_STEPS {
+            let mut cold = t_hi - step * i as f64;
+            if jt(cold) >= 0.0 {
+                for _ in 0..BISECTIONS {
+                    let mid = 0.5 * (warm + cold);
+                    if jt(mid) < 0.0 {
+                        warm = mid;
+                    } else {
+                        cold = mid;
+                    }
+                }
+                let t = 0.5 * (warm + cold);
+                return self.therm_inner(t, d).ok().map(|props| (t, props.pressure));
+            }
+            warm = cold;
+        }
+        None
+    }
+
+    /// Joule-Thomson inversion curve over `n_points` isochores from the
+    /// dilute-gas limit up to three times the critical density.
+    ///
+    /// The curve stops where it meets the two-phase dome (or no
+    /// inversion is found above the triple point).
+    fn inversion_curve_inner(&self, n_points: usize) -> Result<Vec<(f64, f64)>> {
+        const MIN_REDUCED_DENSITY: f64 = 1e-6;
+        const MAX_REDUCED_DENSITY: f64 = 3.0;
+
+        if n_points < 2 {
+            return Err(RefpropError::InvalidInput(
+                "An inversion curve needs at least 2 points".into(),
+            ));
+        }
+        let crit = self.critical_inner()?;
+        let t_lo = self.info_inner(0).triple_point_temp;
+        let t_hi = 10.0 * crit.temperature;
+
+        let mut curve = Vec::with_capacity(n_points);
+        for k in 0..n_points {
+            let frac = k as f64 / (n_points - 1) as f64;
+            let reduced = MIN_REDUCED_DENSITY + (MAX_REDUCED_DENSITY - MIN_REDUCED_DENSITY) * frac;
+            let d = reduced * crit.density;
+            let Some((t, p)) = self.inversion_point_inner(d, t_lo, t_hi) else {
+                break;
+            };
+            if t < crit.temperature {
+                let sat = self.sat_t_inner(t, 1)?;
+                if d > sat.density_vapor && d < sat.density_liquid {
+                    break;
+                }
+            }
+            curve.push((t, p));
+        }
+        if curve.is_empty() {
+            return Err(RefpropError::CalculationFailed(
+                "No Joule-Thomson inversion found".into(),
+            ));
+        }
+        Ok(curve)
+    }
+
+    /// TPRHOdll wrapper: density of the requested root at (T, P).
+    ///
+    /// `kph`: **1** = liquid root, **2** = vapor root.
+    fn tprho_inner(&self, t: f64, p: f64, kph: i32) -> Result<f64> {
+        let kguess: i32 = 0;
+        let mut d = 0.0;
+        let mut ierr: i32 = 0;
+        let mut herr = [0i8; REFPROP_STRLEN];
+
+        unsafe {
+            self.lib.TPRHOdll(
+                &t,
+                &p,
+                self.z.as_ptr(),
+                &kph,
+                &kguess,
+                &mut d,
+                &mut ierr,
+                herr.as_mut_ptr(),
+                REFPROP_STRLEN as c_long,
+            )
+        }?;
+        self.check(ierr, &herr)?;
+        Ok(d)
+    }
+
+    fn transport_inner(&self, t: f64, d: f64) -> Result<TransportProps> {
+        self.transport_calls.fetch_add(1, Ordering::Relaxed);
+        let (mut eta, mut tcx) = (0.0, 0.0);
+        let mut ierr: i32 = 0;
+        let mut herr = [0i8; REFPROP_STRLEN];
+
+        unsafe {
+            self.lib.TRNPRPdll(
+                &t,
+                &d,
+                self.z.as_ptr(),
+                &mut eta,
                 &mut tcx,
                 &mut ierr,
                 herr.as_mut_ptr(),
                 REFPROP_STRLEN as c_long,
-            );
+            )
+        }?;
+        if (ierr > 0 || !(eta > 0.0 && tcx > 0.0))
+            && let Some(name) = self.component_without_transport()
+        {
+            return Err(RefpropError::TransportUnavailable(name));
         }
-        Self::check_err(ierr, &herr)?;
+        self.check(ierr, &herr)?;
         Ok(TransportProps {
             viscosity: eta,
             thermal_conductivity: tcx,
         })
     }
 
+    /// Name of the first component with no viscosity or thermal
+    /// conductivity model loaded (GETMODdll reports `NUL` or nothing).
+    /// **Caller must hold REFPROP_LOCK and call ensure_setup first.**
+    fn component_without_transport(&self) -> Option<String> {
+        let missing = |code: &str| code.is_empty() || code == "NUL";
+        (0..self.nc)
+            .find(|&i| {
+                let (eta, tcx) = self.transport_models_inner(i);
+                missing(&eta) || missing(&tcx)
+            })
+            .map(|i| self.component_names().swap_remove(i))
+    }
+
+    /// Two-phase transport at temperature `t` and quality `q` (0–1),
+    /// combining the saturated-liquid and saturated-vapor values with
+    /// the configured [`TwoPhaseViscosityModel`].  For zeotropic
+    /// mixtures the branches are the bubble liquid and dew vapor at `t`.
+    /// **Caller must hold REFPROP_LOCK and call ensure_setup first.**
+    fn two_phase_transport_inner(&self, t: f64, q: f64) -> Result<TransportProps> {
+        let (liq, vap) = self.saturation_both_inner(t)?;
+        let trn_l = self.transport_inner(t, liq.density)?;
+        let trn_v = self.transport_inner(t, vap.density)?;
+        let model = self.two_phase_viscosity;
+        let mix = |l: f64, v: f64| model.combine(q, l, v, liq.density, vap.density);
+        Ok(TransportProps {
+            viscosity: mix(trn_l.viscosity, trn_v.viscosity),
+            thermal_conductivity: mix(trn_l.thermal_conductivity, trn_v.thermal_conductivity),
+        })
+    }
+
+    /// Transport at an already flashed state.  Inside the dome the bulk
+    /// density is meaningless for `TRNPRPdll`, so two-phase states
+    /// (0 < Q < 1) go through [`two_phase_transport_inner`](Self::two_phase_transport_inner).
+    /// **Caller must hold REFPROP_LOCK and call ensure_setup first.**
+    fn state_transport_inner(&self, props: &ThermoProp) -> Result<TransportProps> {
+        if props.quality > 0.0 && props.quality < 1.0 {
+            self.two_phase_transport_inner(props.temperature, props.quality)
+        } else {
+            self.transport_inner(props.temperature, props.density)
+        }
+    }
+
     fn flash_td_inner(&self, t: f64, d_in: f64) -> Result<ThermoProp> {
+        self.flashes.fetch_add(1, Ordering::Relaxed);
         let (mut p, mut dl, mut dv) = (0.0, 0.0, 0.0);
         let mut x = [0.0f64; REFPROP_NC_MAX];
         let mut y = [0.0f64; REFPROP_NC_MAX];
@@ -563,9 +1483,9 @@ impl RefpropBackend {
                 &mut ierr,
                 herr.as_mut_ptr(),
                 REFPROP_STRLEN as c_long,
-            );
-        }
-        Self::check_err(ierr, &herr)?;
+            )
+        }?;
+        self.check(ierr, &herr)?;
         Ok(ThermoProp {
             temperature: t,
             pressure: p,
@@ -581,6 +1501,7 @@ impl RefpropBackend {
     }
 
     fn flash_pd_inner(&self, p: f64, d_in: f64) -> Result<ThermoProp> {
+        self.flashes.fetch_add(1, Ordering::Relaxed);
         let (mut t, mut dl, mut dv) = (0.0, 0.0, 0.0);
         let mut x = [0.0f64; REFPROP_NC_MAX];
         let mut y = [0.0f64; REFPROP_NC_MAX];
@@ -609,9 +1530,9 @@ impl RefpropBackend {
                 &mut ierr,
                 herr.as_mut_ptr(),
                 REFPROP_STRLEN as c_long,
-            );
-        }
-        Self::check_err(ierr, &herr)?;
+            )
+        }?;
+        self.check(ierr, &herr)?;
         Ok(ThermoProp {
             temperature: t,
             pressure: p,
@@ -627,6 +1548,7 @@ impl RefpropBackend {
     }
 
     fn flash_th_inner(&self, t: f64, h_in: f64) -> Result<ThermoProp> {
+        self.flashes.fetch_add(1, Ordering::Relaxed);
         let (mut kr, mut p, mut d, mut dl, mut dv) = (1.0, 0.0, 0.0, 0.0, 0.0);
         let mut x = [0.0f64; REFPROP_NC_MAX];
         let mut y = [0.0f64; REFPROP_NC_MAX];
@@ -655,9 +1577,9 @@ impl RefpropBackend {
                 &mut ierr,
                 herr.as_mut_ptr(),
                 REFPROP_STRLEN as c_long,
-            );
-        }
-        Self::check_err(ierr, &herr)?;
+            )
+        }?;
+        self.check(ierr, &herr)?;
         Ok(ThermoProp {
             temperature: t,
             pressure: p,
@@ -673,6 +1595,7 @@ impl RefpropBackend {
     }
 
     fn flash_ts_inner(&self, t: f64, s_in: f64) -> Result<ThermoProp> {
+        self.flashes.fetch_add(1, Ordering::Relaxed);
         let (mut kr, mut p, mut d, mut dl, mut dv) = (1.0, 0.0, 0.0, 0.0, 0.0);
         let mut x = [0.0f64; REFPROP_NC_MAX];
         let mut y = [0.0f64; REFPROP_NC_MAX];
@@ -701,9 +1624,9 @@ impl RefpropBackend {
                 &mut ierr,
                 herr.as_mut_ptr(),
                 REFPROP_STRLEN as c_long,
-            );
-        }
-        Self::check_err(ierr, &herr)?;
+            )
+        }?;
+        self.check(ierr, &herr)?;
         Ok(ThermoProp {
             temperature: t,
             pressure: p,
@@ -719,6 +1642,7 @@ impl RefpropBackend {
     }
 
     fn flash_dh_inner(&self, d_in: f64, h_in: f64) -> Result<ThermoProp> {
+        self.flashes.fetch_add(1, Ordering::Relaxed);
         let (mut t, mut p, mut dl, mut dv) = (0.0, 0.0, 0.0, 0.0);
         let mut x = [0.0f64; REFPROP_NC_MAX];
         let mut y = [0.0f64; REFPROP_NC_MAX];
@@ -746,9 +1670,9 @@ impl RefpropBackend {
                 &mut ierr,
                 herr.as_mut_ptr(),
                 REFPROP_STRLEN as c_long,
-            );
-        }
-        Self::check_err(ierr, &herr)?;
+            )
+        }?;
+        self.check(ierr, &herr)?;
         Ok(ThermoProp {
             temperature: t,
             pressure: p,
@@ -764,6 +1688,7 @@ impl RefpropBackend {
     }
 
     fn flash_ds_inner(&self, d_in: f64, s_in: f64) -> Result<ThermoProp> {
+        self.flashes.fetch_add(1, Ordering::Relaxed);
         let (mut t, mut p, mut dl, mut dv) = (0.0, 0.0, 0.0, 0.0);
         let mut x = [0.0f64; REFPROP_NC_MAX];
         let mut y = [0.0f64; REFPROP_NC_MAX];
@@ -791,9 +1716,9 @@ impl RefpropBackend {
                 &mut ierr,
                 herr.as_mut_ptr(),
                 REFPROP_STRLEN as c_long,
-            );
-        }
-        Self::check_err(ierr, &herr)?;
+            )
+        }?;
+        self.check(ierr, &herr)?;
         Ok(ThermoProp {
             temperature: t,
             pressure: p,
@@ -809,6 +1734,7 @@ impl RefpropBackend {
     }
 
     fn flash_hs_inner(&self, h_in: f64, s_in: f64) -> Result<ThermoProp> {
+        self.flashes.fetch_add(1, Ordering::Relaxed);
         let (mut t, mut p, mut d, mut dl, mut dv) = (0.0, 0.0, 0.0, 0.0, 0.0);
         let mut x = [0.0f64; REFPROP_NC_MAX];
         let mut y = [0.0f64; REFPROP_NC_MAX];
@@ -836,9 +1762,9 @@ impl RefpropBackend {
                 &mut ierr,
                 herr.as_mut_ptr(),
                 REFPROP_STRLEN as c_long,
-            );
-        }
-        Self::check_err(ierr, &herr)?;
+            )
+        }?;
+        self.check(ierr, &herr)?;
         Ok(ThermoProp {
             temperature: t,
             pressure: p,
@@ -853,12 +1779,73 @@ impl RefpropBackend {
         })
     }
 
+    /// H–S flash restricted to the requested phase.
+    ///
+    /// For single-phase hints `HSFL1dll` searches the density only on
+    /// the matching side of the critical density (liquid: `[Dc, 5·Dc]`,
+    /// vapor: `(0, Dc]`, supercritical: `(0, 5·Dc]`), which picks the
+    /// branch when (h, s) has several solutions.  The state is then
+    /// re-flashed at (T, D) and its phase checked against the hint;
+    /// a mismatch (e.g. a metastable root inside the dome) is an error.
+    fn flash_hs_phase_inner(&self, h: f64, s: f64, phase: Phase) -> Result<ThermoProp> {
+        let crit = self.critical_inner()?;
+        let props = if phase == Phase::TwoPhase {
+            self.flash_hs_inner(h, s)?
+        } else {
+            let dc = crit.density;
+            let (dmin, dmax) = match phase {
+                Phase::Liquid => (dc, 5.0 * dc),
+                Phase::Vapor => (1e-10, dc),
+                _ => (1e-10, 5.0 * dc),
+            };
+            let (mut t, mut d) = (0.0, 0.0);
+            let mut ierr: i32 = 0;
+            let mut herr = [0i8; REFPROP_STRLEN];
+            unsafe {
+                self.lib.HSFL1dll(
+                    &h,
+                    &s,
+                    self.z.as_ptr(),
+                    &dmin,
+                    &dmax,
+                    &mut t,
+                    &mut d,
+                    &mut ierr,
+                    herr.as_mut_ptr(),
+                    REFPROP_STRLEN as c_long,
+                )
+            }?;
+            self.check(ierr, &herr)?;
+            self.flash_td_inner(t, d)?
+        };
+
+        let found = Phase::classify(props.temperature, props.quality, crit.temperature);
+        if found != phase {
+            return Err(RefpropError::CalculationFailed(format!(
+                "H–S flash with a {phase} hint converged to a {found} state \
+                 (T = {:.4} K, P = {:.4} kPa)",
+                props.temperature, props.pressure
+            )));
+        }
+        Ok(props)
+    }
+
     /// T–Q flash: saturation + interpolation via THERMdll.
     ///
     /// For zeotropic mixtures the saturation curve depends on `kph`:
     /// `kph = 1` (bubble) when Q < 0.5, `kph = 2` (dew) when Q ≥ 0.5.
+    /// The switch makes properties jump at Q = 0.5 by up to the
+    /// bubble/dew difference; see [`flash_tq_kph_inner`](Self::flash_tq_kph_inner).
     fn flash_tq_inner(&self, t: f64, q: f64) -> Result<ThermoProp> {
-        let kph = if q >= 0.5 { 2 } else { 1 };
+        self.flash_tq_kph_inner(t, q, if q >= 0.5 { 2 } else { 1 })
+    }
+
+    /// T–Q flash on an explicit saturation branch: the pressure and
+    /// saturated densities come from `SATTdll` with `kph` (1 = bubble,
+    /// 2 = dew), then Q interpolates between them.
+    fn flash_tq_kph_inner(&self, t: f64, q: f64, kph: i32) -> Result<ThermoProp> {
+        Self::validate_quality(q)?;
+        self.flashes.fetch_add(1, Ordering::Relaxed);
         let sat = self.sat_t_inner(t, kph)?;
         self.interpolate_quality(t, sat.pressure, sat.density_liquid, sat.density_vapor, q)
     }
@@ -866,33 +1853,249 @@ impl RefpropBackend {
     /// P–Q flash: saturation + interpolation via THERMdll.
     ///
     /// For zeotropic mixtures the saturation curve depends on `kph`:
-    /// `kph = 1` (bubble) when Q < 0.5, `kph = 2` (dew) when Q ≥ 0.5.
+    /// `kph = 1` (bubble) when Q < 0.5, `kph = 2` (dew) when Q ≥ 0.5,
+    /// with the same jump at Q = 0.5 as [`flash_tq_inner`](Self::flash_tq_inner).
     fn flash_pq_inner(&self, p: f64, q: f64) -> Result<ThermoProp> {
-        let kph = if q >= 0.5 { 2 } else { 1 };
+        self.flash_pq_kph_inner(p, q, if q >= 0.5 { 2 } else { 1 })
+    }
+
+    /// P–Q flash on an explicit saturation branch (`SATPdll` with `kph`).
+    fn flash_pq_kph_inner(&self, p: f64, q: f64, kph: i32) -> Result<ThermoProp> {
+        Self::validate_quality(q)?;
+        self.flashes.fetch_add(1, Ordering::Relaxed);
         let sat = self.sat_p_inner(p, kph)?;
         self.interpolate_quality(sat.temperature, p, sat.density_liquid, sat.density_vapor, q)
     }
 
+    /// PQFLSHdll wrapper (molar quality) returning the equilibrium
+    /// temperature with the liquid (`x`) and vapor (`y`) compositions.
+    fn flash_pq_xy_inner(&self, p: f64, q: f64) -> Result<(f64, Composition, Composition)> {
+        let kq: i32 = 1;
+        let (mut t, mut d, mut dl, mut dv) = (0.0, 0.0, 0.0, 0.0);
+        let mut x = [0.0f64; REFPROP_NC_MAX];
+        let mut y = [0.0f64; REFPROP_NC_MAX];
+        let (mut e, mut h, mut s, mut cv, mut cp, mut w) = (0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+        let mut ierr: i32 = 0;
+        let mut herr = [0i8; REFPROP_STRLEN];
+
+        unsafe {
+            self.lib.PQFLSHdll(
+                &p,
+                &q,
+                self.z.as_ptr(),
+                &kq,
+                &mut t,
+                &mut d,
+                &mut dl,
+                &mut dv,
+                x.as_mut_ptr(),
+                y.as_mut_ptr(),
+                &mut e,
+                &mut h,
+                &mut s,
+                &mut cv,
+                &mut cp,
+                &mut w,
+                &mut ierr,
+                herr.as_mut_ptr(),
+                REFPROP_STRLEN as c_long,
+            )
+        }?;
+        self.check(ierr, &herr)?;
+        Ok((t, x, y))
+    }
+
+    /// All states at (T, P): one for a single-phase point, the liquid and
+    /// vapor roots (from TPRHOdll) for a pure fluid on its saturation
+    /// line.
+    ///
+    /// A pure-fluid point counts as saturated when P matches the
+    /// saturation pressure at T to within a relative 1e-6, since
+    /// rounding can push an exact saturation pressure either way.  A
+    /// mixture inside the two-phase region has no such pair of roots at
+    /// its bulk composition — its equilibrium phases have the
+    /// compositions `x` and `y` of `flash_separator` — and is rejected.
+    fn states_tp_inner(&self, t: f64, p: f64) -> Result<Vec<ThermoProp>> {
+        let flash = self.flash_tp_inner(t, p)?;
+        if self.nc > 1 {
+            if flash.quality > 0.0 && flash.quality < 1.0 {
+                return Err(RefpropError::InvalidInput(format!(
+                    "T = {t} K, P = {p} kPa is inside the two-phase region of the mixture; \
+                     use flash_separator for its equilibrium phases"
+                )));
+            }
+            return Ok(vec![flash]);
+        }
+        let on_sat_line = self
+            .sat_t_inner(t, 1)
+            .is_ok_and(|sat| ((p - sat.pressure) / sat.pressure).abs() < 1e-6);
+        if !on_sat_line {
+            return Ok(vec![flash]);
+        }
+
+        let mut liq = self.therm_inner(t, self.tprho_inner(t, p, 1)?)?;
+        liq.pressure = p;
+        liq.quality = 0.0;
+        let mut vap = self.therm_inner(t, self.tprho_inner(t, p, 2)?)?;
+        vap.pressure = p;
+        vap.quality = 1.0;
+        Ok(vec![liq, vap])
+    }
+
+    /// Saturated liquid (Q = 0) and saturated vapor (Q = 1) at T.
+    ///
+    /// Pure fluids need a single SATTdll call; zeotropic mixtures take
+    /// the liquid from the bubble curve and the vapor from the dew
+    /// curve, exactly like `flash_tq_inner` at Q = 0 and Q = 1.
+    fn saturation_both_inner(&self, t: f64) -> Result<(ThermoProp, ThermoProp)> {
+        let bubble = self.sat_t_inner(t, 1)?;
+        let dew = if self.nc == 1 {
+            bubble.clone()
+        } else {
+            self.sat_t_inner(t, 2)?
+        };
+        let liq = self.interpolate_quality(
+            t,
+            bubble.pressure,
+            bubble.density_liquid,
+            bubble.density_vapor,
+            0.0,
+        )?;
+        let vap = self.interpolate_quality(
+            t,
+            dew.pressure,
+            dew.density_liquid,
+            dew.density_vapor,
+            1.0,
+        )?;
+        Ok((liq, vap))
+    }
+
+    /// Slope dP/dT (kPa/K) of the bubble curve at `t`.
+    ///
+    /// Pure fluids use the analytic DPTSATKdll when the library exports
+    /// it.  Otherwise the slope is a central difference of SATTdll
+    /// pressures with a step of `1e-4·t`, one-sided (below `t`) when
+    /// the upper point is past the critical temperature.
+    fn dpdt_sat_inner(&self, t: f64) -> Result<f64> {
+        if self.nc == 1 && !self.lib.missing_symbols().contains(&"DPTSATKdll") {
+            let (icomp, kph): (i32, i32) = (1, 1);
+            let (mut p, mut rho, mut csat, mut dpdt) = (0.0, 0.0, 0.0, 0.0);
+            let mut ierr: i32 = 0;
+            let mut herr = [0i8; REFPROP_STRLEN];
+            unsafe {
+                self.lib.DPTSATKdll(
+                    &icomp,
+                    &t,
+                    &kph,
+                    &mut p,
+                    &mut rho,
+                    &mut csat,
+                    &mut dpdt,
+                    &mut ierr,
+                    herr.as_mut_ptr(),
+                    REFPROP_STRLEN as c_long,
+                )
+            }?;
+            self.check(ierr, &herr)?;
+            return Ok(dpdt);
+        }
+        let dt = 1e-4 * t;
+        let lo = self.sat_t_inner(t - dt, 1)?.pressure;
+        let (hi, t_hi) = match self.sat_t_inner(t + dt, 1) {
+            Ok(sat) => (sat.pressure, t + dt),
+            Err(_) => (self.sat_t_inner(t, 1)?.pressure, t),
+        };
+        Ok((hi - lo) / (t_hi - (t - dt)))
+    }
+
+    /// Saturated liquid (Q = 0) and saturated vapor (Q = 1) at P; the
+    /// pressure counterpart of `saturation_both_inner` (bubble liquid
+    /// and dew vapor for zeotropic mixtures).
+    fn saturation_both_p_inner(&self, p: f64) -> Result<(ThermoProp, ThermoProp)> {
+        let bubble = self.sat_p_inner(p, 1)?;
+        let dew = if self.nc == 1 {
+            bubble.clone()
+        } else {
+            self.sat_p_inner(p, 2)?
+        };
+        let liq = self.interpolate_quality(
+            bubble.temperature,
+            p,
+            bubble.density_liquid,
+            bubble.density_vapor,
+            0.0,
+        )?;
+        let vap = self.interpolate_quality(
+            dew.temperature,
+            p,
+            dew.density_liquid,
+            dew.density_vapor,
+            1.0,
+        )?;
+        Ok((liq, vap))
+    }
+
+    /// (∂H/∂P)_T and (∂D/∂P)_T by finite differences of TP flashes.
+    ///
+    /// Central differences with a step of `1e-4·p`.  For pure fluids,
+    /// when the stencil would straddle the saturation pressure at `t`,
+    /// a one-sided difference on the side of `p` is used instead so the
+    /// result never mixes liquid and vapor states.
+    fn p_derivatives_t_inner(&self, t: f64, p: f64) -> Result<(f64, f64)> {
+        let dp = 1e-4 * p.abs().max(1e-3);
+        let psat = if self.nc == 1 {
+            self.sat_t_inner(t, 1).ok().map(|sat| sat.pressure)
+        } else {
+            None
+        };
+        let (lo, hi) = match psat {
+            Some(ps) if (p - dp..=p + dp).contains(&ps) => {
+                if p >= ps {
+                    (p, p + dp)
+                } else {
+                    (p - dp, p)
+                }
+            }
+            _ => (p - dp, p + dp),
+        };
+        let a = self.flash_tp_inner(t, lo)?;
+        let b = self.flash_tp_inner(t, hi)?;
+        let span = hi - lo;
+        Ok((
+            (b.enthalpy - a.enthalpy) / span,
+            (b.density - a.density) / span,
+        ))
+    }
+
     /// Interpolate between saturated liquid and vapor using quality.
     ///
     /// For zeotropic mixtures, THERMdll may recompute a pressure that
     /// differs from the saturation pressure returned by SATTdll/SATPdll.
     /// We therefore always use the saturation pressure `p` directly.
+    ///
+    /// `q` must already be within [0, 1] (see `validate_quality`):
+    /// exactly 0 returns the saturated liquid and exactly 1 the
+    /// saturated vapor, both evaluated directly rather than interpolated.
+    ///
+    /// The sound speed is the homogeneous-equilibrium value from Wood's
+    /// equation ([`SoundModel::HomogeneousEquilibrium`]), not an average
+    /// of the two branches.
     fn interpolate_quality(&self, t: f64, p: f64, dl: f64, dv: f64, q: f64) -> Result<ThermoProp> {
         if q <= 0.0 {
-            let mut props = self.therm_inner(t, dl);
+            let mut props = self.therm_inner(t, dl)?;
             props.quality = 0.0;
             props.pressure = p;
             return Ok(props);
         }
         if q >= 1.0 {
-            let mut props = self.therm_inner(t, dv);
+            let mut props = self.therm_inner(t, dv)?;
             props.quality = 1.0;
             props.pressure = p;
             return Ok(props);
         }
-        let liq = self.therm_inner(t, dl);
-        let vap = self.therm_inner(t, dv);
+        let liq = self.therm_inner(t, dl)?;
+        let vap = self.therm_inner(t, dv)?;
 
         let d = 1.0 / ((1.0 - q) / dl + q / dv);
         let lerp = |a: f64, b: f64| a * (1.0 - q) + b * q;
@@ -905,7 +2108,13 @@ impl RefpropBackend {
             entropy: lerp(liq.entropy, vap.entropy),
             cv: lerp(liq.cv, vap.cv),
             cp: lerp(liq.cp, vap.cp),
-            sound_speed: lerp(liq.sound_speed, vap.sound_speed),
+            sound_speed: SoundModel::HomogeneousEquilibrium.combine(
+                q,
+                liq.sound_speed,
+                vap.sound_speed,
+                dl,
+                dv,
+            ),
             quality: q,
             internal_energy: lerp(liq.internal_energy, vap.internal_energy),
         })
@@ -923,6 +2132,14 @@ impl RefpropBackend {
         self.flash_tp_inner(t, p)
     }
 
+    pub fn states_tp(&self, t: f64, p: f64) -> Result<Vec<ThermoProp>> {
+        Self::validate_finite("temperature", t)?;
+        Self::validate_finite("pressure", p)?;
+        let mut cid = Self::lock_refprop()?;
+        self.ensure_setup(&mut cid)?;
+        self.states_tp_inner(t, p)
+    }
+
     pub fn props_ph(&self, p: f64, h: f64) -> Result<ThermoProp> {
         Self::validate_finite("pressure", p)?;
         Self::validate_finite("enthalpy", h)?;
@@ -941,7 +2158,7 @@ impl RefpropBackend {
 
     pub fn props_tq(&self, t: f64, q: f64) -> Result<ThermoProp> {
         Self::validate_finite("temperature", t)?;
-        Self::validate_finite("quality", q)?;
+        Self::validate_quality(q)?;
         let mut cid = Self::lock_refprop()?;
         self.ensure_setup(&mut cid)?;
         self.flash_tq_inner(t, q)
@@ -949,12 +2166,33 @@ impl RefpropBackend {
 
     pub fn props_pq(&self, p: f64, q: f64) -> Result<ThermoProp> {
         Self::validate_finite("pressure", p)?;
-        Self::validate_finite("quality", q)?;
+        Self::validate_quality(q)?;
         let mut cid = Self::lock_refprop()?;
         self.ensure_setup(&mut cid)?;
         self.flash_pq_inner(p, q)
     }
 
+    /// [`props_tq`](Self::props_tq) on the saturation branch `kph`
+    /// (1 = bubble, 2 = dew) instead of the Q ≥ 0.5 switch.
+    pub fn props_tq_kph(&self, t: f64, q: f64, kph: i32) -> Result<ThermoProp> {
+        Self::validate_finite("temperature", t)?;
+        Self::validate_quality(q)?;
+        Self::validate_kph(kph)?;
+        let mut cid = Self::lock_refprop()?;
+        self.ensure_setup(&mut cid)?;
+        self.flash_tq_kph_inner(t, q, kph)
+    }
+
+    /// [`props_pq`](Self::props_pq) on the saturation branch `kph`.
+    pub fn props_pq_kph(&self, p: f64, q: f64, kph: i32) -> Result<ThermoProp> {
+        Self::validate_finite("pressure", p)?;
+        Self::validate_quality(q)?;
+        Self::validate_kph(kph)?;
+        let mut cid = Self::lock_refprop()?;
+        self.ensure_setup(&mut cid)?;
+        self.flash_pq_kph_inner(p, q, kph)
+    }
+
     pub fn props_th(&self, t: f64, h: f64) -> Result<ThermoProp> {
         Self::validate_finite("temperature", t)?;
         Self::validate_finite("enthalpy", h)?;
@@ -979,6 +2217,26 @@ impl RefpropBackend {
         self.flash_td_inner(t, d)
     }
 
+    /// Direct THERMdll evaluation at (T, D) — no flash, no phase check.
+    pub fn props_td_direct(&self, t: f64, d: f64) -> Result<ThermoProp> {
+        Self::validate_finite("temperature", t)?;
+        Self::validate_finite("density", d)?;
+        let mut cid = Self::lock_refprop()?;
+        self.ensure_setup(&mut cid)?;
+        self.therm_inner(t, d)
+    }
+
+    /// PRESSdll: EOS pressure (kPa) at (T, D), no flash, no phase check.
+    pub fn pressure_td(&self, t: f64, d: f64) -> Result<f64> {
+        Self::validate_finite("temperature", t)?;
+        Self::validate_finite("density", d)?;
+        let mut cid = Self::lock_refprop()?;
+        self.ensure_setup(&mut cid)?;
+        let mut p = 0.0;
+        unsafe { self.lib.PRESSdll(&t, &d, self.z.as_ptr(), &mut p) }?;
+        Ok(p)
+    }
+
     pub fn props_pd(&self, p: f64, d: f64) -> Result<ThermoProp> {
         Self::validate_finite("pressure", p)?;
         Self::validate_finite("density", d)?;
@@ -1011,6 +2269,14 @@ impl RefpropBackend {
         self.flash_hs_inner(h, s)
     }
 
+    pub fn props_hs_hint(&self, h: f64, s: f64, phase: Phase) -> Result<ThermoProp> {
+        Self::validate_finite("enthalpy", h)?;
+        Self::validate_finite("entropy", s)?;
+        let mut cid = Self::lock_refprop()?;
+        self.ensure_setup(&mut cid)?;
+        self.flash_hs_phase_inner(h, s, phase)
+    }
+
     pub fn saturation_p(&self, p: f64) -> Result<SaturationProps> {
         Self::validate_finite("pressure", p)?;
         let mut cid = Self::lock_refprop()?;
@@ -1018,6 +2284,15 @@ impl RefpropBackend {
         self.sat_p_inner(p, 1) // kph=1 → bubble point
     }
 
+    /// Saturation state at density `d` (mol/L) and which branch `d`
+    /// lies on.
+    pub fn saturation_d(&self, d: f64) -> Result<(SaturationProps, Phase)> {
+        Self::validate_finite("density", d)?;
+        let mut cid = Self::lock_refprop()?;
+        self.ensure_setup(&mut cid)?;
+        self.sat_d_inner(d)
+    }
+
     pub fn saturation_t(&self, t: f64) -> Result<SaturationProps> {
         Self::validate_finite("temperature", t)?;
         let mut cid = Self::lock_refprop()?;
@@ -1025,47 +2300,447 @@ impl RefpropBackend {
         self.sat_t_inner(t, 1) // kph=1 → bubble point
     }
 
-    pub fn transport(&self, t: f64, d: f64) -> Result<TransportProps> {
-        Self::validate_finite("temperature", t)?;
-        Self::validate_finite("density", d)?;
+    /// Bubble-point saturation at each temperature, under one lock.
+    pub fn saturation_t_many(&self, temps: &[f64]) -> Result<Vec<SaturationProps>> {
+        for &t in temps {
+            Self::validate_finite("temperature", t)?;
+        }
         let mut cid = Self::lock_refprop()?;
         self.ensure_setup(&mut cid)?;
-        self.transport_inner(t, d)
+        temps.iter().map(|&t| self.sat_t_inner(t, 1)).collect()
     }
 
-    pub fn critical_point(&self) -> Result<CriticalProps> {
+    /// Saturated liquid and vapor at `t` with the slope dP/dT (kPa/K)
+    /// of the bubble curve, under one lock.  REFPROP units throughout.
+    pub fn saturation_t_ext(&self, t: f64) -> Result<SaturationPropsExt> {
+        Self::validate_finite("temperature", t)?;
         let mut cid = Self::lock_refprop()?;
         self.ensure_setup(&mut cid)?;
+        let (liq, vap) = self.saturation_both_inner(t)?;
+        let dpdt_sat = self.dpdt_sat_inner(t)?;
+        Ok(SaturationPropsExt {
+            temperature: t,
+            pressure: liq.pressure,
+            density_liquid: liq.density,
+            density_vapor: vap.density,
+            enthalpy_liquid: liq.enthalpy,
+            enthalpy_vapor: vap.enthalpy,
+            dpdt_sat,
+        })
+    }
 
-        let (mut tc, mut pc, mut dc) = (0.0, 0.0, 0.0);
-        let mut ierr: i32 = 0;
-        let mut herr = [0i8; REFPROP_STRLEN];
+    pub fn saturation_both(&self, t: f64) -> Result<(ThermoProp, ThermoProp)> {
+        Self::validate_finite("temperature", t)?;
+        let mut cid = Self::lock_refprop()?;
+        self.ensure_setup(&mut cid)?;
+        self.saturation_both_inner(t)
+    }
 
-        unsafe {
-            self.lib.CRITPdll(
-                self.z.as_ptr(),
-                &mut tc,
-                &mut pc,
-                &mut dc,
-                &mut ierr,
-                herr.as_mut_ptr(),
-                REFPROP_STRLEN as c_long,
-            );
+    /// [`saturation_both`](Self::saturation_both) at every temperature,
+    /// under one lock.
+    pub fn saturation_both_many(&self, temps: &[f64]) -> Result<Vec<(ThermoProp, ThermoProp)>> {
+        for &t in temps {
+            Self::validate_finite("temperature", t)?;
         }
-        Self::check_err(ierr, &herr)?;
-        Ok(CriticalProps {
-            temperature: tc,
-            pressure: pc,
-            density: dc,
-        })
+        let mut cid = Self::lock_refprop()?;
+        self.ensure_setup(&mut cid)?;
+        temps
+            .iter()
+            .map(|&t| self.saturation_both_inner(t))
+            .collect()
     }
 
-    pub fn fluid_info(&self) -> Result<FluidInfo> {
+    /// Numerical (∂H/∂P)_T and (∂D/∂P)_T, in J/mol/kPa and mol/L/kPa.
+    pub fn p_derivatives_t(&self, t: f64, p: f64) -> Result<(f64, f64)> {
+        Self::validate_finite("temperature", t)?;
+        Self::validate_finite("pressure", p)?;
         let mut cid = Self::lock_refprop()?;
         self.ensure_setup(&mut cid)?;
+        self.p_derivatives_t_inner(t, p)
+    }
 
-        let icomp: i32 = 1;
-        let (mut wmm, mut ttrp, mut tnbpt) = (0.0, 0.0, 0.0);
+    /// Isothermal and isentropic bulk moduli `(K_T, K_s)` at (T, P), in
+    /// kPa: K_T = D·(∂P/∂D)_T from `DPDDdll`, K_s = ρ·w² from the flash.
+    pub fn bulk_moduli(&self, t: f64, p: f64) -> Result<(f64, f64)> {
+        Self::validate_finite("temperature", t)?;
+        Self::validate_finite("pressure", p)?;
+        let mut cid = Self::lock_refprop()?;
+        self.ensure_setup(&mut cid)?;
+        let props = self.flash_tp_inner(t, p)?;
+        if props.quality > 0.0 && props.quality < 1.0 {
+            return Err(RefpropError::InvalidInput(format!(
+                "Bulk modulus is undefined in the two-phase region (T = {t} K, P = {p} kPa)"
+            )));
+        }
+        let d = props.density;
+        let k_t = d * self.dpdd_inner(t, d);
+        // mol/L × g/mol = kg/m³; kg/m³ × (m/s)² = Pa
+        let k_s = d * self.molar_mass_inner() * props.sound_speed.powi(2) / 1000.0;
+        Ok((k_t, k_s))
+    }
+
+    /// Joule-Thomson inversion curve `(T, P)`, see
+    /// [`inversion_curve_inner`](Self::inversion_curve_inner).
+    pub fn jt_inversion_curve(&self, n_points: usize) -> Result<Vec<(f64, f64)>> {
+        let mut cid = Self::lock_refprop()?;
+        self.ensure_setup(&mut cid)?;
+        self.inversion_curve_inner(n_points)
+    }
+
+    /// Maximum inversion temperature and pressure (K, kPa) over a
+    /// 200-isochore inversion curve.  The curve is computed once; later
+    /// calls return the cached maxima.
+    pub fn jt_inversion_maximum(&self) -> Result<(f64, f64)> {
+        if let Some(&max) = self.jt_inversion_max.get() {
+            return Ok(max);
+        }
+        let curve = self.jt_inversion_curve(INVERSION_CURVE_POINTS)?;
+        let max = curve
+            .iter()
+            .fold((f64::MIN, f64::MIN), |(t_max, p_max), &(t, p)| {
+                (t_max.max(t), p_max.max(p))
+            });
+        Ok(*self.jt_inversion_max.get_or_init(|| max))
+    }
+
+    /// Spinodal points at each temperature, under one lock.
+    pub fn spinodal(&self, temps: &[f64]) -> Result<Spinodal> {
+        for &t in temps {
+            Self::validate_finite("temperature", t)?;
+        }
+        let mut cid = Self::lock_refprop()?;
+        self.ensure_setup(&mut cid)?;
+        let mut spinodal = Spinodal {
+            liquid: Vec::with_capacity(temps.len()),
+            vapor: Vec::with_capacity(temps.len()),
+        };
+        for &t in temps {
+            let (liq, vap) = self.spinodal_at_inner(t)?;
+            spinodal.liquid.push(liq);
+            spinodal.vapor.push(vap);
+        }
+        Ok(spinodal)
+    }
+
+    /// Saturation at P on the requested branch, together with the
+    /// composition of the incipient phase (vapor `y` at the bubble
+    /// point, liquid `x` at the dew point).
+    pub fn saturation_p_incipient(&self, p: f64, kph: i32) -> Result<(SaturationProps, Vec<f64>)> {
+        Self::validate_finite("pressure", p)?;
+        let mut cid = Self::lock_refprop()?;
+        self.ensure_setup(&mut cid)?;
+        let (sat, x, y) = self.sat_p_xy_inner(p, kph)?;
+        let incipient = if kph == 1 { y } else { x };
+        Ok((sat, incipient[..self.nc].to_vec()))
+    }
+
+    /// Equilibrium temperature and phase compositions at each molar
+    /// quality in `qualities` (fractions, 0–1) along the isobar `p`,
+    /// all under one lock.
+    pub fn condensation_path(&self, p: f64, qualities: &[f64]) -> Result<Vec<CondensationStep>> {
+        Self::validate_finite("pressure", p)?;
+        for &q in qualities {
+            Self::validate_quality(q)?;
+        }
+        let mut cid = Self::lock_refprop()?;
+        self.ensure_setup(&mut cid)?;
+        qualities
+            .iter()
+            .map(|&q| {
+                let (t, x, y) = self.flash_pq_xy_inner(p, q)?;
+                Ok(CondensationStep {
+                    quality: q,
+                    temperature: t,
+                    liquid_composition: x[..self.nc].to_vec(),
+                    vapor_composition: y[..self.nc].to_vec(),
+                })
+            })
+            .collect()
+    }
+
+    /// TPFLSHdll at (T, P) split into its phases: inside the dome each
+    /// phase is evaluated by THERMdll at its own density and
+    /// composition.  Also returns the molar masses (g/mol) of the liquid
+    /// and vapor streams, needed to convert them to mass units.
+    pub fn flash_separator(&self, t: f64, p: f64) -> Result<(SeparatorResult, (f64, f64))> {
+        Self::validate_finite("temperature", t)?;
+        Self::validate_finite("pressure", p)?;
+        let mut cid = Self::lock_refprop()?;
+        self.ensure_setup(&mut cid)?;
+        let (bulk, dl, dv, x, y) = self.flash_tp_xy_inner(t, p)?;
+        let q = bulk.quality;
+        if !(q > 0.0 && q < 1.0) {
+            // Single phase: supercritical states count as vapor
+            let m = self.molar_mass_inner();
+            let z = self.composition();
+            let liquid = q <= 0.0;
+            let result = SeparatorResult {
+                vapor_fraction: if liquid { 0.0 } else { 1.0 },
+                liquid: liquid.then(|| bulk.clone()),
+                vapor: (!liquid).then_some(bulk),
+                liquid_composition: if liquid { z.clone() } else { Vec::new() },
+                vapor_composition: if liquid { Vec::new() } else { z },
+            };
+            return Ok((result, (m, m)));
+        }
+
+        let phase = |d: f64, comp: &Composition, q_phase: f64| -> Result<ThermoProp> {
+            let mut props = self.therm_z_inner(t, d, comp)?;
+            props.pressure = p;
+            props.quality = q_phase;
+            Ok(props)
+        };
+        let result = SeparatorResult {
+            vapor_fraction: q,
+            liquid: Some(phase(dl, &x, 0.0)?),
+            vapor: Some(phase(dv, &y, 1.0)?),
+            liquid_composition: x[..self.nc].to_vec(),
+            vapor_composition: y[..self.nc].to_vec(),
+        };
+        Ok((
+            result,
+            (self.molar_mass_z_inner(&x), self.molar_mass_z_inner(&y)),
+        ))
+    }
+
+    pub fn transport(&self, t: f64, d: f64) -> Result<TransportProps> {
+        Self::validate_finite("temperature", t)?;
+        Self::validate_finite("density", d)?;
+        let mut cid = Self::lock_refprop()?;
+        self.ensure_setup(&mut cid)?;
+        self.transport_inner(t, d)
+    }
+
+    /// Transport at (T, Q): the two-phase estimate for 0 < Q < 1,
+    /// TRNPRPdll on the saturated branch otherwise, under one lock.
+    pub fn transport_tq(&self, t: f64, q: f64) -> Result<TransportProps> {
+        Self::validate_finite("temperature", t)?;
+        Self::validate_quality(q)?;
+        let mut cid = Self::lock_refprop()?;
+        self.ensure_setup(&mut cid)?;
+        if q > 0.0 && q < 1.0 {
+            return self.two_phase_transport_inner(t, q);
+        }
+        let props = self.flash_tq_inner(t, q)?;
+        self.transport_inner(t, props.density)
+    }
+
+    /// Two-phase sound speed (m/s) at temperature `t` and quality `q`
+    /// (0–1) from the saturated branches at `t` (bubble liquid and dew
+    /// vapor for zeotropes).
+    pub fn two_phase_sound_speed(&self, t: f64, q: f64, model: SoundModel) -> Result<f64> {
+        Self::validate_finite("temperature", t)?;
+        Self::validate_quality(q)?;
+        let mut cid = Self::lock_refprop()?;
+        self.ensure_setup(&mut cid)?;
+        let (liq, vap) = self.saturation_both_inner(t)?;
+        Ok(model.combine(
+            q,
+            liq.sound_speed,
+            vap.sound_speed,
+            liq.density,
+            vap.density,
+        ))
+    }
+
+    /// (T, P) flash followed by TRNPRPdll at the resulting density
+    /// (the two-phase model inside the dome), under one lock.
+    pub fn transport_tp(&self, t: f64, p: f64) -> Result<(ThermoProp, TransportProps)> {
+        Self::validate_finite("temperature", t)?;
+        Self::validate_finite("pressure", p)?;
+        let mut cid = Self::lock_refprop()?;
+        self.ensure_setup(&mut cid)?;
+        let props = self.flash_tp_inner(t, p)?;
+        let trn = self.state_transport_inner(&props)?;
+        Ok((props, trn))
+    }
+
+    pub fn critical_point(&self) -> Result<CriticalProps> {
+        let mut cid = Self::lock_refprop()?;
+        self.ensure_setup(&mut cid)?;
+        self.critical_inner()
+    }
+
+    /// [`critical_point`](Self::critical_point), falling back for
+    /// mixtures to CRTPNTdll (REFPROP 10+) when CRITPdll fails, as it
+    /// can at intermediate compositions.  The CRITPdll error is returned
+    /// if the fallback fails too.
+    pub fn critical_point_with_fallback(&self) -> Result<CriticalProps> {
+        let mut cid = Self::lock_refprop()?;
+        self.ensure_setup(&mut cid)?;
+        match self.critical_inner() {
+            Err(e) if self.nc > 1 => self.crtpnt_inner().map_err(|_| e),
+            other => other,
+        }
+    }
+
+    /// Whether (T, P) lies in a retrograde region of the phase
+    /// envelope, under one lock: two-phase by TPFLSHdll, a mixture, and
+    /// above the critical temperature or pressure.  The critical point
+    /// comes from CRTPNTdll when available, CRITPdll otherwise.
+    pub fn is_retrograde(&self, t: f64, p: f64) -> Result<bool> {
+        Self::validate_finite("temperature", t)?;
+        Self::validate_finite("pressure", p)?;
+        let mut cid = Self::lock_refprop()?;
+        self.ensure_setup(&mut cid)?;
+        if self.nc == 1 {
+            return Ok(false);
+        }
+        let flash = self.flash_tp_inner(t, p)?;
+        if !(flash.quality > 0.0 && flash.quality < 1.0) {
+            return Ok(false);
+        }
+        let crit = self.crtpnt_inner().or_else(|_| self.critical_inner())?;
+        Ok(t > crit.temperature || p > crit.pressure)
+    }
+
+    /// CRTPNTdll wrapper.  **Caller must hold REFPROP_LOCK.**
+    fn crtpnt_inner(&self) -> Result<CriticalProps> {
+        let (mut tc, mut pc, mut dc) = (0.0, 0.0, 0.0);
+        let mut ierr: i32 = 0;
+        let mut herr = [0i8; REFPROP_STRLEN];
+
+        unsafe {
+            self.lib.CRTPNTdll(
+                self.z.as_ptr(),
+                &mut tc,
+                &mut pc,
+                &mut dc,
+                &mut ierr,
+                herr.as_mut_ptr(),
+                REFPROP_STRLEN as c_long,
+            )
+        }?;
+        self.check(ierr, &herr)?;
+        Ok(CriticalProps {
+            temperature: tc,
+            pressure: pc,
+            density: dc,
+        })
+    }
+
+    /// CRITPdll wrapper.  **Caller must hold REFPROP_LOCK.**
+    fn critical_inner(&self) -> Result<CriticalProps> {
+        let (mut tc, mut pc, mut dc) = (0.0, 0.0, 0.0);
+        let mut ierr: i32 = 0;
+        let mut herr = [0i8; REFPROP_STRLEN];
+
+        unsafe {
+            self.lib.CRITPdll(
+                self.z.as_ptr(),
+                &mut tc,
+                &mut pc,
+                &mut dc,
+                &mut ierr,
+                herr.as_mut_ptr(),
+                REFPROP_STRLEN as c_long,
+            )
+        }?;
+        self.check(ierr, &herr)?;
+        Ok(CriticalProps {
+            temperature: tc,
+            pressure: pc,
+            density: dc,
+        })
+    }
+
+    pub fn fluid_info(&self) -> Result<FluidInfo> {
+        self.component_info(0)
+    }
+
+    /// New pure-fluid backend for the component at `index`, loaded
+    /// from the same shared library and fluids directory as `self`.
+    pub fn component_backend(&self, index: usize) -> Result<Self> {
+        let names = self.component_names();
+        let name = names.get(index).ok_or_else(|| {
+            RefpropError::InvalidInput(format!(
+                "Component index {index} out of range (fluid has {} components)",
+                self.nc
+            ))
+        })?;
+        let dll_dir = self.lib.path().parent().unwrap_or(&self.refprop_path);
+        Self::new_with_paths(
+            name,
+            &dll_dir.to_string_lossy(),
+            &self.refprop_path.to_string_lossy(),
+        )
+    }
+
+    /// `INFOdll` for the component at `index` (0-based, REFPROP order).
+    pub fn component_info(&self, index: usize) -> Result<FluidInfo> {
+        if index >= self.nc {
+            return Err(RefpropError::InvalidInput(format!(
+                "Component index {index} out of range (fluid has {} components)",
+                self.nc
+            )));
+        }
+        let mut cid = Self::lock_refprop()?;
+        self.ensure_setup(&mut cid)?;
+        Ok(self.info_inner(index))
+    }
+
+    /// Second virial coefficient Bᵢⱼ (L/mol) between components `i`
+    /// and `j` at `t`.
+    ///
+    /// `B12dll` only returns the mixture value Σ zₖ·zₗ·Bₖₗ, so the
+    /// cross term is recovered from three evaluations:
+    /// Bᵢⱼ = 2·B(½, ½) − ½·(Bᵢᵢ + Bⱼⱼ).  `i == j` gives Bᵢᵢ.
+    pub fn cross_virial(&self, t: f64, i: usize, j: usize) -> Result<f64> {
+        Self::validate_finite("temperature", t)?;
+        if self.nc < 2 {
+            return Err(RefpropError::InvalidInput(
+                "Cross virial coefficients need a mixture (nc ≥ 2)".into(),
+            ));
+        }
+        for index in [i, j] {
+            if index >= self.nc {
+                return Err(RefpropError::InvalidInput(format!(
+                    "Component index {index} out of range (fluid has {} components)",
+                    self.nc
+                )));
+            }
+        }
+        let mut cid = Self::lock_refprop()?;
+        self.ensure_setup(&mut cid)?;
+
+        let b = |fractions: &[(usize, f64)]| -> Result<f64> {
+            let mut z = [0.0f64; REFPROP_NC_MAX];
+            for &(k, x) in fractions {
+                z[k] += x;
+            }
+            let mut b = 0.0;
+            unsafe { self.lib.B12dll(&t, z.as_ptr(), &mut b) }?;
+            Ok(b)
+        };
+        let b_ii = b(&[(i, 1.0)])?;
+        if i == j {
+            return Ok(b_ii);
+        }
+        let b_jj = b(&[(j, 1.0)])?;
+        let b_mix = b(&[(i, 0.5), (j, 0.5)])?;
+        Ok(2.0 * b_mix - 0.5 * (b_ii + b_jj))
+    }
+
+    /// Ideal-gas heat-capacity ratio γ⁰ = cp⁰ / (cp⁰ − R) at `t` (K),
+    /// from `CP0dll` and the mixture gas constant R = Σ zᵢ·Rᵢ of the
+    /// components' `INFOdll` values.
+    pub fn ideal_gas_gamma(&self, t: f64) -> Result<f64> {
+        Self::validate_finite("temperature", t)?;
+        let mut cid = Self::lock_refprop()?;
+        self.ensure_setup(&mut cid)?;
+        let mut cp0 = 0.0;
+        unsafe { self.lib.CP0dll(&t, self.z.as_ptr(), &mut cp0) }?;
+        let r: f64 = (0..self.nc)
+            .map(|i| self.z[i] * self.info_inner(i).gas_constant)
+            .sum();
+        Ok(cp0 / (cp0 - r))
+    }
+
+    /// INFOdll for a 0-based component index.
+    /// **Caller must hold REFPROP_LOCK and call ensure_setup first.**
+    fn info_inner(&self, index: usize) -> FluidInfo {
+        let icomp: i32 = (index + 1) as i32;
+        let (mut wmm, mut ttrp, mut tnbpt) = (0.0, 0.0, 0.0);
         let (mut tc, mut pc, mut dc) = (0.0, 0.0, 0.0);
         let (mut zc, mut acf, mut dip, mut rgas) = (0.0, 0.0, 0.0, 0.0);
 
@@ -1075,7 +2750,7 @@ impl RefpropBackend {
                 &mut acf, &mut dip, &mut rgas,
             );
         }
-        Ok(FluidInfo {
+        FluidInfo {
             molar_mass: wmm,
             triple_point_temp: ttrp,
             normal_boiling_point: tnbpt,
@@ -1086,9 +2761,427 @@ impl RefpropBackend {
             acentric_factor: acf,
             dipole_moment: dip,
             gas_constant: rgas,
+        }
+    }
+
+    // ================================================================
+    //  Transport models
+    // ================================================================
+
+    /// Viscosity and thermal-conductivity model codes (`GETMODdll`
+    /// with `"ETA"` / `"TCX"`) of the component at `index`, e.g.
+    /// `("VS1", "TC1")` for dedicated correlations or `("ECS", "ECS")`
+    /// for extended corresponding states.
+    pub fn transport_models(&self, index: usize) -> Result<(String, String)> {
+        if index >= self.nc {
+            return Err(RefpropError::InvalidInput(format!(
+                "Component index {index} out of range (fluid has {} components)",
+                self.nc
+            )));
+        }
+        let mut cid = Self::lock_refprop()?;
+        self.ensure_setup(&mut cid)?;
+        Ok(self.transport_models_inner(index))
+    }
+
+    /// GETMODdll model codes for a 0-based component index.
+    /// **Caller must hold REFPROP_LOCK and call ensure_setup first.**
+    fn transport_models_inner(&self, index: usize) -> (String, String) {
+        let icomp: i32 = (index + 1) as i32;
+        let code = |htype: &str| {
+            // One extra byte keeps the 3-char Fortran strings NUL-terminated
+            let htype_c = to_c_string(htype, 4);
+            let mut hcode = [0i8; 4];
+            let mut hcite = [0i8; REFPROP_STRLEN];
+            unsafe {
+                self.lib.GETMODdll(
+                    &icomp,
+                    htype_c.as_ptr(),
+                    hcode.as_mut_ptr(),
+                    hcite.as_mut_ptr(),
+                    3,
+                    3,
+                    REFPROP_STRLEN as c_long,
+                );
+            }
+            from_c_string(&hcode).trim().to_uppercase()
+        };
+        (code("ETA"), code("TCX"))
+    }
+
+    /// Reference fluid of the ECS transport model of a pure fluid.
+    ///
+    /// Returns the fluid's own name when both viscosity and thermal
+    /// conductivity use dedicated correlations.  For ECS the reference
+    /// is read from the `#TRN` section of the `.FLD` file, since
+    /// REFPROP does not report it through the DLL.
+    pub fn transport_reference(&self) -> Result<String> {
+        if self.nc != 1 {
+            return Err(RefpropError::InvalidInput(
+                "transport_reference is only defined for pure fluids".into(),
+            ));
+        }
+        let name = self.component_names().remove(0);
+        let (eta, tcx) = self.transport_models(0)?;
+        if eta != "ECS" && tcx != "ECS" {
+            return Ok(name);
+        }
+
+        let (path, contents) = self.fld_contents(0)?;
+        Self::ecs_reference_fluid(&contents).ok_or_else(|| {
+            RefpropError::CalculationFailed(format!(
+                "No ECS reference fluid found in {}",
+                path.display()
+            ))
+        })
+    }
+
+    /// Path and text of the `.FLD` file of the component at `index`.
+    fn fld_contents(&self, index: usize) -> Result<(PathBuf, String)> {
+        let entry = self
+            .hfld_str
+            .split('|')
+            .nth(index)
+            .unwrap_or_default()
+            .trim();
+        let path = [PathBuf::from(entry)]
+            .into_iter()
+            .chain(
+                ["fluids", "FLUIDS"]
+                    .iter()
+                    .map(|d| self.refprop_path.join(d).join(entry)),
+            )
+            .find(|p| p.is_file())
+            .ok_or_else(|| RefpropError::FluidNotFound(entry.to_string()))?;
+        let bytes = std::fs::read(&path).map_err(|e| {
+            RefpropError::CalculationFailed(format!("Cannot read {}: {e}", path.display()))
+        })?;
+        // Some .FLD files carry Latin-1 comments
+        Ok((path, String::from_utf8_lossy(&bytes).into_owned()))
+    }
+
+    /// Stem of the first `*.FLD` file named in the ECS `#TRN` / `@TRN`
+    /// section of a fluid file, e.g. `"R134A"`.
+    fn ecs_reference_fluid(contents: &str) -> Option<String> {
+        let mut lines = contents.lines().map(str::trim);
+        while let Some(line) = lines.next() {
+            let upper = line.to_uppercase();
+            if !(upper.starts_with("#TRN") || upper.starts_with("@TRN")) {
+                continue;
+            }
+            let is_ecs = lines
+                .next()
+                .is_some_and(|model| model.to_uppercase().starts_with("ECS"));
+            if !is_ecs {
+                continue;
+            }
+            for line in lines.by_ref() {
+                if line.starts_with('#') || line.starts_with('@') {
+                    break;
+                }
+                let found = line
+                    .split_whitespace()
+                    .map(str::to_uppercase)
+                    .find_map(|token| token.strip_suffix(".FLD").map(str::to_string));
+                if found.is_some() {
+                    return found;
+                }
+            }
+        }
+        None
+    }
+
+    // ================================================================
+    //  Stated uncertainties
+    // ================================================================
+
+    /// Relative uncertainty of property `key` (`"D"`, `"CP"`, `"W"`, …)
+    /// as stated in the equation-of-state description of a pure fluid's
+    /// `.FLD` file, for the phase of the state (T, P).
+    ///
+    /// The DLL has no uncertainty routine, so this reads the free-text
+    /// `?` comment lines of the `#EOS` section: among the sentences
+    /// naming the property, those that also name the phase are
+    /// preferred, and the largest percentage found is returned as a
+    /// fraction (0.001 = 0.1 %).
+    pub fn property_uncertainty(&self, key: &str, t: f64, p: f64) -> Result<f64> {
+        Self::validate_finite("temperature", t)?;
+        Self::validate_finite("pressure", p)?;
+        if self.nc != 1 {
+            return Err(RefpropError::InvalidInput(
+                "property_uncertainty is only defined for pure fluids".into(),
+            ));
+        }
+        let keywords: &[&str] = match key.to_uppercase().as_str() {
+            "D" | "RHO" => &["density", "densities"],
+            "CP" | "CV" => &["heat capacit"],
+            "W" | "A" => &["speed of sound", "sound speed"],
+            "P" => &["vapor pressure"],
+            "H" => &["enthalp"],
+            _ => {
+                return Err(RefpropError::InvalidInput(format!(
+                    "No stated uncertainty for \"{key}\". Supported: D CP CV W P H"
+                )));
+            }
+        };
+
+        let phase = {
+            let mut cid = Self::lock_refprop()?;
+            self.ensure_setup(&mut cid)?;
+            let props = self.flash_tp_inner(t, p)?;
+            let crit = self.critical_inner()?;
+            Phase::classify(t, props.quality, crit.temperature)
+        };
+        let phase_words: &[&str] = match phase {
+            Phase::Liquid => &["liquid"],
+            Phase::Vapor => &["vapor", "gas"],
+            Phase::Supercritical => &["supercritical"],
+            Phase::TwoPhase => &[],
+        };
+
+        let (path, contents) = self.fld_contents(0)?;
+        Self::stated_uncertainty(&contents, keywords, phase_words).ok_or_else(|| {
+            RefpropError::CalculationFailed(format!(
+                "No {key} uncertainty stated in {}",
+                path.display()
+            ))
         })
     }
 
+    /// Largest percentage (as a fraction) in the `#EOS` description
+    /// sentences that mention one of `keywords`, restricted to those
+    /// also mentioning one of `phase_words` when any do.
+    fn stated_uncertainty(contents: &str, keywords: &[&str], phase_words: &[&str]) -> Option<f64> {
+        let mut text = String::new();
+        let mut in_eos = false;
+        for line in contents.lines().map(str::trim) {
+            let upper = line.to_uppercase();
+            if upper.starts_with("#EOS") {
+                in_eos = true;
+            } else if in_eos && (line.starts_with('#') || line.starts_with('@')) {
+                break;
+            } else if in_eos && let Some(comment) = line.strip_prefix('?') {
+                text.push(' ');
+                text.push_str(comment.trim_end_matches('\\'));
+            }
+        }
+        let text = text.to_lowercase();
+
+        let sentences: Vec<&str> = text
+            .split(". ")
+            .filter(|s| keywords.iter().any(|k| s.contains(k)))
+            .collect();
+        let in_phase: Vec<&str> = sentences
+            .iter()
+            .copied()
+            .filter(|s| phase_words.iter().any(|w| s.contains(w)))
+            .collect();
+        let candidates = if in_phase.is_empty() {
+            sentences
+        } else {
+            in_phase
+        };
+
+        candidates
+            .iter()
+            .flat_map(|s| s.split_whitespace())
+            .filter_map(|token| {
+                let (number, _) = token.trim_start_matches(['(', '±', '+']).split_once('%')?;
+                number.parse::<f64>().ok()
+            })
+            .filter(|pct| *pct > 0.0)
+            .fold(None, |max: Option<f64>, pct| {
+                Some(max.map_or(pct, |m| m.max(pct)))
+            })
+            .map(|pct| pct / 100.0)
+    }
+
+    // ================================================================
+    //  Library identification
+    // ================================================================
+
+    /// Version string reported by `RPVersion` (REFPROP 10+).
+    pub fn refprop_version(&self) -> Result<String> {
+        let _guard = Self::lock_refprop()?;
+        let mut hv = [0i8; REFPROP_STRLEN];
+        unsafe {
+            self.lib
+                .RPVersion(hv.as_mut_ptr(), REFPROP_STRLEN as c_long)
+        }?;
+        Ok(from_c_string(&hv).trim().to_string())
+    }
+
+    /// Number of flash calculations run by this backend so far.
+    pub fn flash_count(&self) -> usize {
+        self.flashes.load(Ordering::Relaxed)
+    }
+
+    /// Number of `TRNPRPdll` calls run by this backend so far.
+    pub fn transport_count(&self) -> usize {
+        self.transport_calls.load(Ordering::Relaxed)
+    }
+
+    /// Number of REFPROP warnings logged by this backend's calculations.
+    pub fn warning_count(&self) -> usize {
+        self.warnings.load(Ordering::Relaxed)
+    }
+
+    /// Standard REFPROP message for error code `code` (`ERRMSGdll`,
+    /// REFPROP 10+).
+    pub fn error_message(&self, code: i32) -> Result<String> {
+        let _guard = Self::lock_refprop()?;
+        let mut herr = [0i8; REFPROP_STRLEN];
+        unsafe {
+            self.lib
+                .ERRMSGdll(&code, herr.as_mut_ptr(), REFPROP_STRLEN as c_long)
+        }?;
+        Ok(from_c_string(&herr).trim().to_string())
+    }
+
+    /// `GETENUMdll` code of the property name `name` (REFPROP 10+).
+    pub fn property_enum(&self, name: &str) -> Result<i32> {
+        const PROPERTIES_ONLY: i32 = 2;
+
+        let _guard = Self::lock_refprop()?;
+        let henum = to_c_string(name, REFPROP_STRLEN);
+        let mut ienum: i32 = 0;
+        let mut ierr: i32 = 0;
+        let mut herr = [0i8; REFPROP_STRLEN];
+        unsafe {
+            self.lib.GETENUMdll(
+                &PROPERTIES_ONLY,
+                henum.as_ptr(),
+                &mut ienum,
+                &mut ierr,
+                herr.as_mut_ptr(),
+                REFPROP_STRLEN as c_long,
+                REFPROP_STRLEN as c_long,
+            )
+        }?;
+        if ierr > 0 {
+            return Err(RefpropError::InvalidInput(format!(
+                "REFPROP does not know the property \"{name}\": {}",
+                from_c_string(&herr).trim()
+            )));
+        }
+        Ok(ienum)
+    }
+
+    /// Path of the loaded REFPROP shared library.
+    pub fn library_path(&self) -> &Path {
+        self.lib.path()
+    }
+
+    /// Bound REFPROP routines the loaded library does not export.
+    pub fn missing_symbols(&self) -> &[&'static str] {
+        self.lib.missing_symbols()
+    }
+
+    /// Run `f` on the loaded library while holding REFPROP_LOCK, after
+    /// this backend's fluid has been set up.
+    pub fn with_raw_library<F, R>(&self, f: F) -> Result<R>
+    where
+        F: FnOnce(&RefpropLibrary) -> R,
+    {
+        let mut cid = Self::lock_refprop()?;
+        self.ensure_setup(&mut cid)?;
+        Ok(f(&self.lib))
+    }
+
+    /// Stable FNV-1a hash (hex) of the names and contents of every file
+    /// in the fluids directory, in sorted order.
+    pub fn fluids_fingerprint(&self) -> Result<String> {
+        let dir = ["fluids", "FLUIDS"]
+            .iter()
+            .map(|d| self.refprop_path.join(d))
+            .find(|d| d.is_dir())
+            .ok_or_else(|| {
+                RefpropError::FluidNotFound(format!(
+                    "no fluids/ directory in {}",
+                    self.refprop_path.display()
+                ))
+            })?;
+        let io_err = |e: std::io::Error| {
+            RefpropError::CalculationFailed(format!("Cannot read {}: {e}", dir.display()))
+        };
+        let mut files: Vec<PathBuf> = std::fs::read_dir(&dir)
+            .map_err(io_err)?
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.is_file())
+            .collect();
+        files.sort();
+
+        const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0100_0000_01b3;
+        let mut hash = FNV_OFFSET;
+        for path in files {
+            let name = path.file_name().unwrap_or_default().to_string_lossy();
+            let contents = std::fs::read(&path).map_err(io_err)?;
+            for byte in name.bytes().chain(contents) {
+                hash ^= u64::from(byte);
+                hash = hash.wrapping_mul(FNV_PRIME);
+            }
+        }
+        Ok(format!("{hash:016x}"))
+    }
+
+    // ================================================================
+    //  Composition
+    // ================================================================
+
+    /// Component names, in REFPROP order (file stems without `.FLD`).
+    pub fn component_names(&self) -> Vec<String> {
+        self.hfld_str
+            .split('|')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(|s| {
+                let file = s.rsplit(['/', '\\']).next().unwrap_or(s);
+                let upper = file.to_uppercase();
+                upper.strip_suffix(".FLD").unwrap_or(&upper).to_string()
+            })
+            .collect()
+    }
+
+    /// Molar composition (mole fractions, one per component).
+    pub fn composition(&self) -> Vec<f64> {
+        self.z[..self.nc].to_vec()
+    }
+
+    /// Replace the molar composition of the loaded components without
+    /// calling `SETUPdll` again, and return the new mixture molar mass.
+    ///
+    /// `z` must hold one finite, non-negative fraction per component
+    /// with a positive sum; it is normalized to sum to exactly 1.
+    pub fn set_composition(&mut self, z: &[f64]) -> Result<f64> {
+        if z.len() != self.nc {
+            return Err(RefpropError::InvalidInput(format!(
+                "Composition must have {} fractions (one per component), got {}",
+                self.nc,
+                z.len()
+            )));
+        }
+        if let Some(x) = z.iter().find(|x| !(x.is_finite() && **x >= 0.0)) {
+            return Err(RefpropError::InvalidInput(format!(
+                "Mole fractions must be finite and non-negative, got {x}"
+            )));
+        }
+        let mut new_z = [0.0f64; REFPROP_NC_MAX];
+        new_z[..self.nc].copy_from_slice(z);
+        if Self::normalize_composition(&mut new_z[..self.nc]) <= 0.0 {
+            return Err(RefpropError::InvalidInput(
+                "Mole fractions must not all be zero".into(),
+            ));
+        }
+
+        let mut cid = Self::lock_refprop()?;
+        self.ensure_setup(&mut cid)?;
+        self.z = new_z;
+        self.jt_inversion_max.take();
+        Ok(self.molar_mass_inner())
+    }
+
     // ================================================================
     //  Molar mass (mixture-averaged)
     // ================================================================
@@ -1100,21 +3193,20 @@ impl RefpropBackend {
     pub fn molar_mass_mix(&self) -> Result<f64> {
         let mut cid = Self::lock_refprop()?;
         self.ensure_setup(&mut cid)?;
+        Ok(self.molar_mass_inner())
+    }
 
-        let mut m_mix = 0.0;
-        for i in 0..self.nc {
-            let icomp: i32 = (i + 1) as i32;
-            let (mut wmm, mut d1, mut d2, mut d3, mut d4) = (0.0, 0.0, 0.0, 0.0, 0.0);
-            let (mut d5, mut d6, mut d7, mut d8, mut d9) = (0.0, 0.0, 0.0, 0.0, 0.0);
-            unsafe {
-                self.lib.INFOdll(
-                    &icomp, &mut wmm, &mut d1, &mut d2, &mut d3, &mut d4, &mut d5, &mut d6,
-                    &mut d7, &mut d8, &mut d9,
-                );
-            }
-            m_mix += self.z[i] * wmm;
-        }
-        Ok(m_mix)
+    /// Σ z_i · M_i.  **Caller must hold REFPROP_LOCK and call
+    /// ensure_setup first.**
+    fn molar_mass_inner(&self) -> f64 {
+        self.molar_mass_z_inner(&self.z)
+    }
+
+    /// Σ z_i · M_i for an arbitrary composition of the loaded components.
+    fn molar_mass_z_inner(&self, z: &Composition) -> f64 {
+        (0..self.nc)
+            .map(|i| z[i] * self.info_inner(i).molar_mass)
+            .sum()
     }
 
     // ================================================================
@@ -1131,85 +3223,336 @@ impl RefpropBackend {
     ///
     /// Supported input pairs: **(T,P) (T,D) (T,H) (T,S) (T,Q) (P,D) (P,H) (P,S) (P,Q) (D,H) (D,S) (H,S)**.
     /// Keys are **case-insensitive**.
+    ///
+    /// `"TSAT"` and `"PSAT"` skip the flash: they only use the `"P"`
+    /// (resp. `"T"`) input, on the bubble branch for mixtures.
     pub fn get(&self, output: &str, key1: &str, val1: f64, key2: &str, val2: f64) -> Result<f64> {
         Self::validate_finite(key1, val1)?;
         Self::validate_finite(key2, val2)?;
 
         let mut cid = Self::lock_refprop()?;
         self.ensure_setup(&mut cid)?;
+        self.get_inner(output, key1, val1, key2, val2)
+    }
 
-        let k1 = key1.to_uppercase();
-        let k2 = key2.to_uppercase();
-
-        let props = match (k1.as_str(), k2.as_str()) {
-            ("T", "P") => self.flash_tp_inner(val1, val2)?,
-            ("P", "T") => self.flash_tp_inner(val2, val1)?,
-
-            ("P", "H") => self.flash_ph_inner(val1, val2)?,
-            ("H", "P") => self.flash_ph_inner(val2, val1)?,
-
-            ("P", "S") => self.flash_ps_inner(val1, val2)?,
-            ("S", "P") => self.flash_ps_inner(val2, val1)?,
-
-            ("T", "Q") => self.flash_tq_inner(val1, val2)?,
-            ("Q", "T") => self.flash_tq_inner(val2, val1)?,
+    /// [`get`](Self::get) at every `(val1, val2)` point under one lock.
+    /// The first failing point aborts the batch.
+    pub fn get_many(
+        &self,
+        output: &str,
+        key1: &str,
+        key2: &str,
+        points: &[(f64, f64)],
+    ) -> Result<Vec<f64>> {
+        for &(v1, v2) in points {
+            Self::validate_finite(key1, v1)?;
+            Self::validate_finite(key2, v2)?;
+        }
+        let mut cid = Self::lock_refprop()?;
+        self.ensure_setup(&mut cid)?;
+        points
+            .iter()
+            .map(|&(v1, v2)| self.get_inner(output, key1, v1, key2, v2))
+            .collect()
+    }
 
-            ("P", "Q") => self.flash_pq_inner(val1, val2)?,
-            ("Q", "P") => self.flash_pq_inner(val2, val1)?,
+    /// Body of [`get`](Self::get).
+    /// **Caller must hold REFPROP_LOCK and call ensure_setup first.**
+    fn get_inner(&self, output: &str, key1: &str, val1: f64, key2: &str, val2: f64) -> Result<f64> {
+        let out = output.to_uppercase();
+        if out == "TSAT" || out == "PSAT" {
+            let wanted = if out == "TSAT" { "P" } else { "T" };
+            let (key, val) = [(key1, val1), (key2, val2)]
+                .into_iter()
+                .find(|(k, _)| k.eq_ignore_ascii_case(wanted))
+                .ok_or_else(|| {
+                    RefpropError::UnsupportedInputPair(key1.to_uppercase(), key2.to_uppercase())
+                })?;
+            return self.get1_inner(&out, key, val);
+        }
 
-            ("T", "D") | ("T", "RHO") => self.flash_td_inner(val1, val2)?,
-            ("D", "T") | ("RHO", "T") => self.flash_td_inner(val2, val1)?,
+        let props = self.flash_pair_inner(key1, val1, key2, val2)?;
+        self.output_inner(output, &props, &mut None)
+    }
 
-            ("T", "H") => self.flash_th_inner(val1, val2)?,
-            ("H", "T") => self.flash_th_inner(val2, val1)?,
+    /// Several outputs of [`get`](Self::get) at one state, under one
+    /// lock: the state is flashed once and, if any transport key is
+    /// asked for, transport is evaluated once at the flashed state.
+    pub fn get_outputs(
+        &self,
+        outputs: &[&str],
+        key1: &str,
+        val1: f64,
+        key2: &str,
+        val2: f64,
+    ) -> Result<Vec<f64>> {
+        Self::validate_finite(key1, val1)?;
+        Self::validate_finite(key2, val2)?;
 
-            ("T", "S") => self.flash_ts_inner(val1, val2)?,
-            ("S", "T") => self.flash_ts_inner(val2, val1)?,
+        let mut cid = Self::lock_refprop()?;
+        self.ensure_setup(&mut cid)?;
+        let mut props: Option<ThermoProp> = None;
+        let mut trn = None;
+        let mut values = Vec::with_capacity(outputs.len());
+        for output in outputs {
+            let out = output.to_uppercase();
+            if out == "TSAT" || out == "PSAT" {
+                values.push(self.get_inner(output, key1, val1, key2, val2)?);
+                continue;
+            }
+            let state = match props.take() {
+                Some(state) => state,
+                None => self.flash_pair_inner(key1, val1, key2, val2)?,
+            };
+            let state = props.insert(state);
+            values.push(self.output_inner(output, state, &mut trn)?);
+        }
+        Ok(values)
+    }
 
-            ("P", "D") | ("P", "RHO") => self.flash_pd_inner(val1, val2)?,
-            ("D", "P") | ("RHO", "P") => self.flash_pd_inner(val2, val1)?,
+    /// Value of `output` (a key of [`OUTPUT_KEYS`] other than TSAT/PSAT)
+    /// at an already flashed state; transport keys call TRNPRPdll,
+    /// unless `trn` already holds the transport of this state.
+    /// **Caller must hold REFPROP_LOCK and call ensure_setup first.**
+    fn output_inner(
+        &self,
+        output: &str,
+        props: &ThermoProp,
+        trn: &mut Option<TransportProps>,
+    ) -> Result<f64> {
+        match output.to_uppercase().as_str() {
+            "T" => Ok(props.temperature),
+            "P" => Ok(props.pressure),
+            "D" | "RHO" | "DMOLAR" => Ok(props.density),
+            "H" | "HMOLAR" => Ok(props.enthalpy),
+            "S" | "SMOLAR" => Ok(props.entropy),
+            // Single-phase and supercritical states carry a sentinel
+            "Q" if (0.0..=1.0).contains(&props.quality) => Ok(props.quality),
+            "Q" => Ok(f64::NAN),
+            "CV" | "CVMOLAR" => Ok(props.cv),
+            "CP" | "CPMOLAR" => Ok(props.cp),
+            "W" | "A" => Ok(props.sound_speed),
+            "E" | "U" | "UMOLAR" => Ok(props.internal_energy),
+            "ETA" | "V" | "VIS" => Ok(self.cached_transport_inner(props, trn)?.viscosity),
+            "TCX" | "L" | "LAMBDA" => Ok(self
+                .cached_transport_inner(props, trn)?
+                .thermal_conductivity),
+            _ => Err(RefpropError::UnknownOutputKey(output.to_string())),
+        }
+    }
 
-            ("D", "H") | ("RHO", "H") => self.flash_dh_inner(val1, val2)?,
-            ("H", "D") | ("H", "RHO") => self.flash_dh_inner(val2, val1)?,
+    /// Transport at `props`, computed on the first call and read from
+    /// `cache` afterwards.
+    /// **Caller must hold REFPROP_LOCK and call ensure_setup first.**
+    fn cached_transport_inner<'c>(
+        &self,
+        props: &ThermoProp,
+        cache: &'c mut Option<TransportProps>,
+    ) -> Result<&'c TransportProps> {
+        let trn = match cache.take() {
+            Some(trn) => trn,
+            None => self.state_transport_inner(props)?,
+        };
+        Ok(cache.insert(trn))
+    }
 
-            ("D", "S") | ("RHO", "S") => self.flash_ds_inner(val1, val2)?,
-            ("S", "D") | ("S", "RHO") => self.flash_ds_inner(val2, val1)?,
+    /// `output` on the saturated-liquid and saturated-vapor branches at
+    /// `key` = `val` (`"T"` or `"P"`), from one saturation solve under
+    /// one lock.
+    pub fn get_saturation(&self, output: &str, key: &str, val: f64) -> Result<(f64, f64)> {
+        Self::validate_finite(key, val)?;
+        let mut cid = Self::lock_refprop()?;
+        self.ensure_setup(&mut cid)?;
+        let (liq, vap) = match key.to_uppercase().as_str() {
+            "T" => self.saturation_both_inner(val)?,
+            "P" => self.saturation_both_p_inner(val)?,
+            _ => {
+                return Err(RefpropError::InvalidInput(format!(
+                    "get_saturation needs a \"T\" or \"P\" input, got \"{key}\""
+                )));
+            }
+        };
+        Ok((
+            self.output_inner(output, &liq, &mut None)?,
+            self.output_inner(output, &vap, &mut None)?,
+        ))
+    }
 
-            ("H", "S") => self.flash_hs_inner(val1, val2)?,
-            ("S", "H") => self.flash_hs_inner(val2, val1)?,
+    /// Single-input lookup: saturation values at one `"T"` or `"P"`
+    /// input, or fluid constants that ignore the input.
+    ///
+    /// | Output  | Input    | Value                                  |
+    /// |---------|----------|----------------------------------------|
+    /// | `PSAT`  | `T`      | saturation (bubble) pressure           |
+    /// | `TSAT`  | `P`      | saturation (bubble) temperature        |
+    /// | `DLIQ`  | `T`, `P` | saturated-liquid (bubble) density      |
+    /// | `DVAP`  | `T`, `P` | saturated-vapor (dew) density          |
+    /// | `TCRIT`, `PCRIT`, `DCRIT` | – | critical point (`CRITPdll`) |
+    /// | `M`     | –        | molar mass, Σ zᵢ·Mᵢ (g/mol)            |
+    /// | `TTRP`, `TNBP` | – | triple point, normal boiling point (first component) |
+    pub fn get1(&self, output: &str, key: &str, val: f64) -> Result<f64> {
+        let mut cid = Self::lock_refprop()?;
+        self.ensure_setup(&mut cid)?;
+        self.get1_inner(&output.to_uppercase(), key, val)
+    }
 
+    /// [`get1`](Self::get1) with an upper-case `output`.
+    /// **Caller must hold REFPROP_LOCK and call ensure_setup first.**
+    fn get1_inner(&self, output: &str, key: &str, val: f64) -> Result<f64> {
+        match output {
+            "TCRIT" => return Ok(self.critical_inner()?.temperature),
+            "PCRIT" => return Ok(self.critical_inner()?.pressure),
+            "DCRIT" => return Ok(self.critical_inner()?.density),
+            "M" => return Ok(self.molar_mass_inner()),
+            "TTRP" => return Ok(self.info_inner(0).triple_point_temp),
+            "TNBP" => return Ok(self.info_inner(0).normal_boiling_point),
+            "PSAT" | "TSAT" | "DLIQ" | "DVAP" => {}
             _ => {
                 return Err(RefpropError::InvalidInput(format!(
-                    "Unsupported input pair ({k1}, {k2}). \
-                     Supported: (T,P) (T,D) (T,H) (T,S) (T,Q) (P,D) (P,H) (P,S) (P,Q) (D,H) (D,S) (H,S)"
+                    "Unknown get1 output \"{output}\". Supported: {}",
+                    GET1_OUTPUTS.join(" ")
                 )));
             }
+        }
+
+        Self::validate_finite(key, val)?;
+        let k = key.to_uppercase();
+        let sat = |kph: i32| match k.as_str() {
+            "T" => self.sat_t_inner(val, kph),
+            "P" => self.sat_p_inner(val, kph),
+            _ => Err(RefpropError::InvalidInput(format!(
+                "{output} needs a T or P input, got {k}"
+            ))),
+        };
+        match (output, k.as_str()) {
+            ("PSAT", "T") => Ok(sat(1)?.pressure),
+            ("TSAT", "P") => Ok(sat(1)?.temperature),
+            ("DLIQ", _) => Ok(sat(1)?.density_liquid),
+            ("DVAP", _) => Ok(sat(2)?.density_vapor),
+            _ => Err(RefpropError::InvalidInput(format!(
+                "{output} needs a {} input, got {k}",
+                if output == "PSAT" { "T" } else { "P" }
+            ))),
+        }
+    }
+
+    /// Flash every (key1, val1, key2, val2) point under one lock.
+    pub fn evaluate_path(&self, points: &[(String, f64, String, f64)]) -> Result<Vec<ThermoProp>> {
+        for (k1, v1, k2, v2) in points {
+            Self::validate_finite(k1, *v1)?;
+            Self::validate_finite(k2, *v2)?;
+        }
+        let mut cid = Self::lock_refprop()?;
+        self.ensure_setup(&mut cid)?;
+        points
+            .iter()
+            .map(|(k1, v1, k2, v2)| self.flash_pair_inner(k1, *v1, k2, *v2))
+            .collect()
+    }
+
+    /// Temperature (K) on the isobar `p` where `output` equals `target`
+    /// (REFPROP units), see
+    /// [`solve_temperature_inner`](Self::solve_temperature_inner).
+    pub fn solve_temperature(&self, p: f64, output: &str, target: f64) -> Result<f64> {
+        Self::validate_finite("pressure", p)?;
+        Self::validate_finite(output, target)?;
+        let mut cid = Self::lock_refprop()?;
+        self.ensure_setup(&mut cid)?;
+        self.solve_temperature_inner(p, output, target)
+    }
+
+    /// TP flashes are scanned from the triple point up to three times
+    /// the critical temperature; the first sign change of
+    /// `output − target` is bisected, so the lowest crossing wins when
+    /// the property is not monotonic along the isobar.  Nodes where the
+    /// flash fails are skipped.  A sign change across a jump of the
+    /// property (the phase change of a pure fluid) is reported as an
+    /// error rather than returned as a root.
+    /// **Caller must hold REFPROP_LOCK and call ensure_setup first.**
+    fn solve_temperature_inner(&self, p: f64, output: &str, target: f64) -> Result<f64> {
+        const SCAN_STEPS: usize = 400;
+        const BISECTIONS: usize = 60;
+        const MAX_REDUCED_TEMPERATURE: f64 = 3.0;
+        const JUMP_TOLERANCE: f64 = 1e-6;
+
+        let t_lo = self.info_inner(0).triple_point_temp;
+        let t_hi = MAX_REDUCED_TEMPERATURE * self.critical_inner()?.temperature;
+        let residual = |t: f64| -> Result<f64> {
+            let props = self.flash_tp_inner(t, p)?;
+            Ok(self.output_inner(output, &props, &mut None)? - target)
         };
 
-        let out = output.to_uppercase();
-        match out.as_str() {
-            "T" => Ok(props.temperature),
-            "P" => Ok(props.pressure),
-            "D" | "RHO" => Ok(props.density),
-            "H" => Ok(props.enthalpy),
-            "S" => Ok(props.entropy),
-            "Q" => Ok(props.quality),
-            "CV" => Ok(props.cv),
-            "CP" => Ok(props.cp),
-            "W" | "A" => Ok(props.sound_speed),
-            "E" | "U" => Ok(props.internal_energy),
-            "ETA" | "V" | "VIS" => {
-                let trn = self.transport_inner(props.temperature, props.density)?;
-                Ok(trn.viscosity)
+        let step = (t_hi - t_lo) / SCAN_STEPS as f64;
+        let mut prev: Option<(f64, f64)> = None;
+        for i in 0..=SCAN_STEPS {
+            let t = t_lo + step * i as f64;
+            let Ok(r) = residual(t) else {
+                prev = None;
+                continue;
+            };
+            if r == 0.0 {
+                return Ok(t);
             }
-            "TCX" | "L" | "LAMBDA" => {
-                let trn = self.transport_inner(props.temperature, props.density)?;
-                Ok(trn.thermal_conductivity)
+            if let Some((t_prev, r_prev)) = prev
+                && r_prev.signum() != r.signum()
+            {
+                let (mut a, mut b) = (t_prev, t);
+                for _ in 0..BISECTIONS {
+                    let mid = 0.5 * (a + b);
+                    if residual(mid)?.signum() == r_prev.signum() {
+                        a = mid;
+                    } else {
+                        b = mid;
+                    }
+                }
+                let root = 0.5 * (a + b);
+                let scale = r_prev.abs().max(r.abs());
+                if residual(root)?.abs() > JUMP_TOLERANCE * scale {
+                    return Err(RefpropError::CalculationFailed(format!(
+                        "{output} = {target} falls in a jump of the property at T = {root:.4} K \
+                         on the isobar P = {p} kPa (phase change)"
+                    )));
+                }
+                return Ok(root);
             }
-            _ => Err(RefpropError::InvalidInput(format!(
-                "Unknown output property \"{output}\". \
-                 Supported: T P D H S Q Cv Cp W E ETA TCX"
-            ))),
+            prev = Some((t, r));
+        }
+        Err(RefpropError::CalculationFailed(format!(
+            "{output} = {target} is not bracketed on the isobar P = {p} kPa \
+             between {t_lo:.2} K and {t_hi:.2} K"
+        )))
+    }
+
+    /// Dispatch a (key1, key2) input pair to the matching flash.
+    /// **Caller must hold REFPROP_LOCK and call ensure_setup first.**
+    fn flash_pair_inner(&self, key1: &str, val1: f64, key2: &str, val2: f64) -> Result<ThermoProp> {
+        let k1 = key1.to_uppercase();
+        let k2 = key2.to_uppercase();
+        let canonical = |k: &str| if k == "RHO" { "D" } else { k }.to_string();
+        let (c1, c2) = (canonical(&k1), canonical(&k2));
+
+        let (a, b, va, vb) = if INPUT_PAIRS.contains(&(c1.as_str(), c2.as_str())) {
+            (c1, c2, val1, val2)
+        } else if INPUT_PAIRS.contains(&(c2.as_str(), c1.as_str())) {
+            (c2, c1, val2, val1)
+        } else {
+            return Err(RefpropError::UnsupportedInputPair(k1, k2));
+        };
+
+        match (a.as_str(), b.as_str()) {
+            ("T", "P") => self.flash_tp_inner(va, vb),
+            ("P", "H") => self.flash_ph_inner(va, vb),
+            ("P", "S") => self.flash_ps_inner(va, vb),
+            ("T", "Q") => self.flash_tq_inner(va, vb),
+            ("P", "Q") => self.flash_pq_inner(va, vb),
+            ("T", "D") => self.flash_td_inner(va, vb),
+            ("T", "H") => self.flash_th_inner(va, vb),
+            ("T", "S") => self.flash_ts_inner(va, vb),
+            ("P", "D") => self.flash_pd_inner(va, vb),
+            ("D", "H") => self.flash_dh_inner(va, vb),
+            ("D", "S") => self.flash_ds_inner(va, vb),
+            ("H", "S") => self.flash_hs_inner(va, vb),
+            _ => Err(RefpropError::UnsupportedInputPair(k1, k2)),
         }
     }
 
@@ -1217,6 +3560,25 @@ impl RefpropBackend {
     //  Helpers
     // ================================================================
 
+    /// Check the REFPROP error code with this backend's settings.
+    ///
+    /// When extrapolation is allowed, the out-of-range warnings listed
+    /// in [`EXTRAPOLATION_WARNINGS`] are accepted silently, and so are
+    /// the codes set with [`set_ignored_warnings`](Self::set_ignored_warnings);
+    /// everything else goes through [`check_err`](Self::check_err).
+    fn check(&self, ierr: i32, herr: &[i8]) -> Result<()> {
+        if self.allow_extrapolation && EXTRAPOLATION_WARNINGS.contains(&ierr) {
+            return Ok(());
+        }
+        if ierr < 0 {
+            if self.ignored_warnings.binary_search(&ierr).is_ok() {
+                return Ok(());
+            }
+            self.warnings.fetch_add(1, Ordering::Relaxed);
+        }
+        Self::check_err(ierr, herr)
+    }
+
     /// Check the REFPROP error code.
     ///
     /// - `ierr > 0`: hard error → returns `Err(RefpropError::Refprop)`
@@ -1236,3 +3598,78 @@ impl RefpropBackend {
         Ok(())
     }
 }
+
+impl Drop for RefpropBackend {
+    /// Forget REFPROP's loaded state if it belongs to this backend, so
+    /// the next call from any backend is forced through `SETUPdll`.
+    ///
+    /// Never waits for the lock: it may be held by this very thread
+    /// (a `Fluid` dropped inside `with_raw_library`).  Skipping the
+    /// reset is harmless, since backend ids are never reused and a
+    /// stale id can match no live backend.
+    fn drop(&mut self) {
+        let guard = match REFPROP_LOCK.try_lock() {
+            Ok(guard) => Some(guard),
+            Err(TryLockError::Poisoned(e)) => Some(e.into_inner()),
+            Err(TryLockError::WouldBlock) => None,
+        };
+        if let Some(mut current_id) = guard
+            && *current_id == self.id
+        {
+            *current_id = 0;
+        }
+        if let Some(dir) = &self.temp_dir {
+            let _ = std::fs::remove_dir_all(dir);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn definition_directories_are_never_reused() {
+        let base = std::env::temp_dir();
+        let first = RefpropBackend::write_definition(&base, b"R134A\n").unwrap();
+        let second = RefpropBackend::write_definition(&base, b"R134A\n").unwrap();
+        assert_ne!(first, second, "Each definition needs its own directory");
+        assert_eq!(
+            std::fs::read(first.join(DEFINITION_FILE)).unwrap(),
+            b"R134A\n"
+        );
+        let _ = std::fs::remove_dir_all(&first);
+        let _ = std::fs::remove_dir_all(&second);
+    }
+
+    // Fluid passe les titres par Converter::q_to_rp, qui rejette déjà
+    // tout ce qui sort de 0–100 % : la garde du backend sur la fraction
+    // molaire ne se vérifie qu'ici.  Le mock suffit, la garde passe
+    // avant tout appel à REFPROP ; les bornes 0 et 1 acceptées sont
+    // vérifiées dans tests/flash_calculations.rs.
+    #[cfg(feature = "mock")]
+    #[test]
+    fn fractional_quality_outside_unit_range_is_rejected() {
+        let dir = std::env::temp_dir().join(format!("refprop-mock-q-{}", std::process::id()));
+        let lib = Arc::new(RefpropLibrary::mock(&dir).unwrap());
+        let backend = RefpropBackend::from_library(lib, "R134A");
+        let _ = std::fs::remove_dir_all(&dir);
+        let backend = backend.unwrap();
+        for q in [-0.1, 1.1] {
+            assert!(
+                matches!(
+                    backend.props_tq(273.15, q),
+                    Err(RefpropError::InvalidInput(_))
+                ),
+                "props_tq should reject Q = {q}"
+            );
+            assert!(
+                matches!(
+                    backend.props_pq(300.0, q),
+                    Err(RefpropError::InvalidInput(_))
+                ),
+                "props_pq should reject Q = {q}"
+            );
+        }
+    }
+}
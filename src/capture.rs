@@ -0,0 +1,224 @@
+//! Capture of REFPROP's console output.
+//!
+//! The REFPROP library writes some diagnostics (banners, convergence
+//! messages) straight to the process's standard output from Fortran,
+//! bypassing Rust's `std::io`.  During fluid setup they can be switched
+//! off with REFPROP's own console flag, see [`set_quiet_setup`].  For
+//! everything else the only reliable way to keep them out of an
+//! application's output is to redirect file descriptor 1 while REFPROP
+//! runs, which is what [`OutputCapture`] does.
+//!
+//! **Limitations:**
+//!
+//! * Redirection is process-wide.  A capture holds the lock of
+//!   [`std::io::stdout`] while it is active, so `print!` and friends
+//!   on other threads wait for it to end instead of landing in it;
+//!   C or Fortran code writing to descriptor 1 from another thread is
+//!   still captured.
+//! * Only one capture can be active at a time.
+//! * Text still sitting in the Fortran runtime's own buffer when the
+//!   capture ends may be flushed later to the real stdout.
+//! * On non-Unix platforms capturing is a no-op and
+//!   [`finish`](OutputCapture::finish) returns an empty string.
+
+use crate::error::{RefpropError, Result};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// `true` while an [`OutputCapture`] owns the stdout redirection.
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+
+/// When set, REFPROP's console output is switched off for every
+/// `SETUPdll` / `SETMIXdll` call.
+static QUIET_SETUP: AtomicBool = AtomicBool::new(false);
+
+/// Globally silence REFPROP's console output during fluid setup.
+///
+/// Affects every `Fluid` constructed afterwards (and re-setups when
+/// switching between fluids).  Before each setup REFPROP's
+/// "write to console" flag is cleared through `FLAGSdll`; with a DLL
+/// that lacks `FLAGSdll` or the flag, the setup runs inside a
+/// throw-away [`OutputCapture`] instead (unless a capture is already
+/// active).
+pub fn set_quiet_setup(quiet: bool) {
+    QUIET_SETUP.store(quiet, Ordering::Relaxed);
+}
+
+/// Whether [`set_quiet_setup`] is on.
+pub(crate) fn quiet_setup() -> bool {
+    QUIET_SETUP.load(Ordering::Relaxed)
+}
+
+/// Discarding capture, if no user capture is already active.
+pub(crate) fn discard_stdout() -> Option<OutputCapture> {
+    OutputCapture::start().ok()
+}
+
+/// RAII guard redirecting standard output into a buffer.
+///
+/// Output is restored when the guard is dropped or
+/// [`finish`](Self::finish)ed.  The guard holds the `std::io::stdout`
+/// lock, so it must be finished on the thread that started it.
+///
+/// ```no_run
+/// use refprop::Fluid;
+///
+/// let capture = Fluid::capture_output()?;
+/// let fluid = Fluid::new("R134A")?;
+/// let log = capture.finish();
+/// # Ok::<(), refprop::RefpropError>(())
+/// ```
+pub struct OutputCapture {
+    done: bool,
+    #[cfg(unix)]
+    redirect: Option<imp::Redirect>,
+}
+
+impl OutputCapture {
+    /// Start redirecting standard output.
+    ///
+    /// Fails if another capture is already active (a quiet-setup
+    /// capture only lasts for the duration of a `SETUPdll` call).
+    pub fn start() -> Result<Self> {
+        if ACTIVE.swap(true, Ordering::AcqRel) {
+            return Err(RefpropError::InvalidInput(
+                "An output capture is already active".into(),
+            ));
+        }
+        #[cfg(unix)]
+        {
+            match imp::Redirect::start() {
+                Ok(redirect) => Ok(Self {
+                    done: false,
+                    redirect: Some(redirect),
+                }),
+                Err(e) => {
+                    ACTIVE.store(false, Ordering::Release);
+                    Err(RefpropError::CalculationFailed(format!(
+                        "Cannot redirect stdout: {e}"
+                    )))
+                }
+            }
+        }
+        #[cfg(not(unix))]
+        {
+            Ok(Self { done: false })
+        }
+    }
+
+    /// Restore standard output and return everything written to it
+    /// since the capture started.
+    pub fn finish(mut self) -> String {
+        self.stop()
+    }
+
+    fn stop(&mut self) -> String {
+        #[cfg(unix)]
+        let text = self
+            .redirect
+            .take()
+            .map(imp::Redirect::stop)
+            .unwrap_or_default();
+        #[cfg(not(unix))]
+        let text = String::new();
+        self.done = true;
+        ACTIVE.store(false, Ordering::Release);
+        text
+    }
+}
+
+impl Drop for OutputCapture {
+    fn drop(&mut self) {
+        if !self.done {
+            self.stop();
+        }
+    }
+}
+
+#[cfg(unix)]
+mod imp {
+    use std::ffi::{c_int, c_void};
+    use std::fs::File;
+    use std::io::{self, Read, Seek, SeekFrom, StdoutLock, Write};
+    use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+    use std::path::PathBuf;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    unsafe extern "C" {
+        fn dup(fd: c_int) -> c_int;
+        fn dup2(src: c_int, dst: c_int) -> c_int;
+        fn fflush(stream: *mut c_void) -> c_int;
+    }
+
+    const STDOUT_FD: c_int = 1;
+
+    static NEXT_FILE: AtomicUsize = AtomicUsize::new(0);
+
+    pub struct Redirect {
+        saved: OwnedFd,
+        file: File,
+        path: PathBuf,
+        // Keeps every other `std::io::stdout` writer out of the
+        // redirected descriptor; released after fd 1 is restored.
+        stdout: StdoutLock<'static>,
+    }
+
+    impl Redirect {
+        pub fn start() -> io::Result<Self> {
+            // `create_new` never opens a file (or symlink) that is already
+            // there; a taken name moves on to the next `n`.
+            let (file, path) = loop {
+                let n = NEXT_FILE.fetch_add(1, Ordering::Relaxed);
+                let path = std::env::temp_dir()
+                    .join(format!("refprop-capture-{}-{n}.log", std::process::id()));
+                match File::options()
+                    .read(true)
+                    .write(true)
+                    .create_new(true)
+                    .open(&path)
+                {
+                    Ok(file) => break (file, path),
+                    Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+                    Err(e) => return Err(e),
+                }
+            };
+
+            let mut stdout = io::stdout().lock();
+            stdout.flush()?;
+            // SAFETY: plain descriptor syscalls; `saved` takes ownership
+            // of the duplicated descriptor.
+            let saved = unsafe {
+                let fd = dup(STDOUT_FD);
+                if fd < 0 {
+                    return Err(io::Error::last_os_error());
+                }
+                let saved = OwnedFd::from_raw_fd(fd);
+                if dup2(file.as_raw_fd(), STDOUT_FD) < 0 {
+                    return Err(io::Error::last_os_error());
+                }
+                saved
+            };
+            Ok(Self {
+                saved,
+                file,
+                path,
+                stdout,
+            })
+        }
+
+        pub fn stop(mut self) -> String {
+            let _ = self.stdout.flush();
+            // SAFETY: flushing all C streams and restoring fd 1 from
+            // the descriptor saved in `start`.
+            unsafe {
+                fflush(std::ptr::null_mut());
+                dup2(self.saved.as_raw_fd(), STDOUT_FD);
+            }
+            let mut text = String::new();
+            if self.file.seek(SeekFrom::Start(0)).is_ok() {
+                let _ = self.file.read_to_string(&mut text);
+            }
+            let _ = std::fs::remove_file(&self.path);
+            text
+        }
+    }
+}
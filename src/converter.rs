@@ -13,6 +13,11 @@
 //! | `engineering()` | °C  | bar | kg/m³ | kJ/kg  | kJ/(kg·K) |
 //! | `si()`          | K   | Pa  | kg/m³ | J/kg   | J/(kg·K)  |
 //!
+//! # Mass or molar basis
+//!
+//! [`UnitSystem::basis`] pins density, energy and entropy to one basis
+//! so that e.g. kg/m³ cannot be combined with J/mol by accident.
+//!
 //! # Builder
 //!
 //! ```
@@ -22,11 +27,45 @@
 //!     .temperature(TempUnit::Celsius)
 //!     .pressure(PressUnit::Bar);
 //! ```
+//!
+//! # Without REFPROP
+//!
+//! Nothing in this module calls into the REFPROP library: a
+//! [`Converter`] only needs a [`UnitSystem`] and the fluid's molar
+//! mass, so it also works where no DLL is installed (e.g. to convert
+//! values received from a server).  The molar mass can come from
+//! [`FluidInfo::molar_mass`](crate::FluidInfo::molar_mass) recorded
+//! elsewhere.  Built with `default-features = false`, the crate drops
+//! the `refprop` feature and with it `Fluid`, the FFI layer and the
+//! `libloading` dependency, leaving this module and the property types.
+//!
+//! ```
+//! use refprop::{Converter, UnitSystem};
+//!
+//! // R134A, 102.032 g/mol
+//! let conv = Converter::for_fluid(102.032).with_units(UnitSystem::engineering());
+//! let t_k = conv.t_to_rp(25.0);        // °C → K
+//! let d = conv.d_from_rp(12.0);        // mol/L → kg/m³
+//! assert!((t_k - 298.15).abs() < 1e-12);
+//! assert!((d - 1224.384).abs() < 1e-9);
+//! ```
 
 use serde::{Deserialize, Serialize};
 
 use crate::error::{RefpropError, Result};
 
+/// Avogadro constant (1/mol).
+pub const AVOGADRO: f64 = 6.022_140_76e23;
+
+/// Ideal-gas molar density at 0 °C and 1 atm (mol/L), i.e. one amagat.
+pub const AMAGAT_MOL_PER_L: f64 = 0.044_615;
+
+/// One debye in coulomb-metres.
+pub const DEBYE_C_M: f64 = 3.335_64e-30;
+
+/// One avoirdupois pound-mass in kilograms.
+pub const LBM_KG: f64 = 0.453_592_37;
+
 // ────────────────────────────────────────────────────────────────────
 //  Unit enums
 // ────────────────────────────────────────────────────────────────────
@@ -64,8 +103,15 @@ pub enum PressUnit {
 pub enum DensityUnit {
     /// mol/L (REFPROP native)
     MolPerL,
+    /// mol/m³ (SI molar)
+    MolPerM3,
     /// kg/m³ (requires molar mass)
     KgPerM3,
+    /// Amagat: density relative to an ideal gas at 0 °C and 1 atm
+    /// (1 amagat ≈ 44.615 mol/m³)
+    Amagat,
+    /// Number density, particles per m³ (via Avogadro's constant)
+    PerM3,
 }
 
 /// Energy / enthalpy unit.
@@ -79,7 +125,8 @@ pub enum EnergyUnit {
     JPerKg,
 }
 
-/// Entropy / heat-capacity unit (energy per temperature).
+/// Entropy unit (energy per temperature); also used for Cv and Cp
+/// unless a [`HeatCapacityUnit`] is set.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum EntropyUnit {
     /// J/(mol·K) (REFPROP native)
@@ -90,6 +137,20 @@ pub enum EntropyUnit {
     JPerKgK,
 }
 
+/// Heat-capacity (Cv, Cp) unit, when it should differ from the
+/// [`EntropyUnit`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HeatCapacityUnit {
+    /// J/(mol·K) (REFPROP native)
+    JPerMolK,
+    /// kJ/(kg·K) (requires molar mass)
+    KJPerKgK,
+    /// J/(kg·K) (requires molar mass)
+    JPerKgK,
+    /// J/(g·K), numerically equal to kJ/(kg·K) (requires molar mass)
+    JPerGK,
+}
+
 /// Dynamic viscosity unit.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ViscosityUnit {
@@ -110,6 +171,293 @@ pub enum ConductivityUnit {
     MilliWPerMK,
 }
 
+/// Dipole-moment unit.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DipoleUnit {
+    /// Debye (REFPROP native)
+    #[default]
+    Debye,
+    /// Coulomb-metre (SI, 1 D ≈ 3.33564e-30 C·m)
+    CoulombMeter,
+}
+
+/// Mass-flow unit, for stream inputs
+/// ([`Fluid::stream_tp`](crate::Fluid::stream_tp)).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MassFlowUnit {
+    /// kg/s
+    #[default]
+    KgPerS,
+    /// kg/h
+    KgPerH,
+    /// lbm/h
+    LbmPerH,
+}
+
+/// Basis of the extensive-property units (density, energy, entropy).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Basis {
+    /// Per kilogram: kg/m³, kJ/kg or J/kg, kJ/(kg·K) or J/(kg·K)
+    Mass,
+    /// Per mole (or per particle): mol/L, mol/m³, amagat, 1/m³, J/mol,
+    /// J/(mol·K)
+    Molar,
+}
+
+impl DensityUnit {
+    /// Mass or molar basis of this unit.
+    pub fn basis(self) -> Basis {
+        match self {
+            Self::KgPerM3 => Basis::Mass,
+            Self::MolPerL | Self::MolPerM3 | Self::Amagat | Self::PerM3 => Basis::Molar,
+        }
+    }
+}
+
+impl EnergyUnit {
+    /// Mass or molar basis of this unit.
+    pub fn basis(self) -> Basis {
+        match self {
+            Self::KJPerKg | Self::JPerKg => Basis::Mass,
+            Self::JPerMol => Basis::Molar,
+        }
+    }
+}
+
+impl EntropyUnit {
+    /// Mass or molar basis of this unit.
+    pub fn basis(self) -> Basis {
+        match self {
+            Self::KJPerKgK | Self::JPerKgK => Basis::Mass,
+            Self::JPerMolK => Basis::Molar,
+        }
+    }
+}
+
+impl HeatCapacityUnit {
+    /// Mass or molar basis of this unit.
+    pub fn basis(self) -> Basis {
+        match self {
+            Self::KJPerKgK | Self::JPerKgK | Self::JPerGK => Basis::Mass,
+            Self::JPerMolK => Basis::Molar,
+        }
+    }
+}
+
+impl From<EntropyUnit> for HeatCapacityUnit {
+    /// The heat-capacity unit spelled like `u`.
+    fn from(u: EntropyUnit) -> Self {
+        match u {
+            EntropyUnit::JPerMolK => Self::JPerMolK,
+            EntropyUnit::KJPerKgK => Self::KJPerKgK,
+            EntropyUnit::JPerKgK => Self::JPerKgK,
+        }
+    }
+}
+
+// ── Unit symbols (for labels and reports) ──────────────────────────
+
+impl TempUnit {
+    /// Short display symbol, e.g. `"°C"`.
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Self::Kelvin => "K",
+            Self::Celsius => "°C",
+            Self::Fahrenheit => "°F",
+        }
+    }
+}
+
+impl PressUnit {
+    /// Short display symbol, e.g. `"bar"`.
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Self::KPa => "kPa",
+            Self::Bar => "bar",
+            Self::MPa => "MPa",
+            Self::Pa => "Pa",
+            Self::Atm => "atm",
+            Self::Psi => "psi",
+        }
+    }
+}
+
+impl DensityUnit {
+    /// Short display symbol, e.g. `"kg/m³"`.
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Self::MolPerL => "mol/L",
+            Self::MolPerM3 => "mol/m³",
+            Self::KgPerM3 => "kg/m³",
+            Self::Amagat => "amg",
+            Self::PerM3 => "1/m³",
+        }
+    }
+}
+
+impl EnergyUnit {
+    /// Short display symbol, e.g. `"kJ/kg"`.
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Self::JPerMol => "J/mol",
+            Self::KJPerKg => "kJ/kg",
+            Self::JPerKg => "J/kg",
+        }
+    }
+}
+
+impl EntropyUnit {
+    /// Short display symbol, e.g. `"kJ/(kg·K)"`.
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Self::JPerMolK => "J/(mol·K)",
+            Self::KJPerKgK => "kJ/(kg·K)",
+            Self::JPerKgK => "J/(kg·K)",
+        }
+    }
+}
+
+impl HeatCapacityUnit {
+    /// Short display symbol, e.g. `"J/(g·K)"`.
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Self::JPerMolK => "J/(mol·K)",
+            Self::KJPerKgK => "kJ/(kg·K)",
+            Self::JPerKgK => "J/(kg·K)",
+            Self::JPerGK => "J/(g·K)",
+        }
+    }
+}
+
+impl ViscosityUnit {
+    /// Short display symbol, e.g. `"µPa·s"`.
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Self::MicroPaS => "µPa·s",
+            Self::MilliPaS => "mPa·s",
+            Self::PaS => "Pa·s",
+        }
+    }
+}
+
+impl ConductivityUnit {
+    /// Short display symbol, e.g. `"W/(m·K)"`.
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Self::WPerMK => "W/(m·K)",
+            Self::MilliWPerMK => "mW/(m·K)",
+        }
+    }
+}
+
+impl DipoleUnit {
+    /// Short display symbol, e.g. `"D"`.
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Self::Debye => "D",
+            Self::CoulombMeter => "C·m",
+        }
+    }
+}
+
+impl MassFlowUnit {
+    /// Short display symbol, e.g. `"kg/s"`.
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Self::KgPerS => "kg/s",
+            Self::KgPerH => "kg/h",
+            Self::LbmPerH => "lbm/h",
+        }
+    }
+}
+
+// ── Unit names (for parsing) ───────────────────────────────────────
+// Each unit with the ASCII spellings accepted besides its symbol and
+// variant name (see `lookup_unit`).
+
+const TEMP_UNITS: [(TempUnit, &[&str]); 3] = [
+    (TempUnit::Kelvin, &[]),
+    (TempUnit::Celsius, &["degC", "C"]),
+    (TempUnit::Fahrenheit, &["degF", "F"]),
+];
+
+const PRESS_UNITS: [(PressUnit, &[&str]); 6] = [
+    (PressUnit::KPa, &[]),
+    (PressUnit::Bar, &[]),
+    (PressUnit::MPa, &[]),
+    (PressUnit::Pa, &[]),
+    (PressUnit::Atm, &[]),
+    (PressUnit::Psi, &["psia"]),
+];
+
+const DENSITY_UNITS: [(DensityUnit, &[&str]); 5] = [
+    (DensityUnit::MolPerL, &[]),
+    (DensityUnit::MolPerM3, &["mol/m3"]),
+    (DensityUnit::KgPerM3, &["kg/m3"]),
+    (DensityUnit::Amagat, &[]),
+    (DensityUnit::PerM3, &["1/m3"]),
+];
+
+const ENERGY_UNITS: [(EnergyUnit, &[&str]); 3] = [
+    (EnergyUnit::JPerMol, &[]),
+    (EnergyUnit::KJPerKg, &[]),
+    (EnergyUnit::JPerKg, &[]),
+];
+
+const ENTROPY_UNITS: [(EntropyUnit, &[&str]); 3] = [
+    (EntropyUnit::JPerMolK, &["J/mol/K"]),
+    (EntropyUnit::KJPerKgK, &["kJ/kg/K"]),
+    (EntropyUnit::JPerKgK, &["J/kg/K"]),
+];
+
+const HEAT_CAPACITY_UNITS: [(HeatCapacityUnit, &[&str]); 4] = [
+    (HeatCapacityUnit::JPerMolK, &["J/mol/K"]),
+    (HeatCapacityUnit::KJPerKgK, &["kJ/kg/K"]),
+    (HeatCapacityUnit::JPerKgK, &["J/kg/K"]),
+    (HeatCapacityUnit::JPerGK, &["J/g/K"]),
+];
+
+const VISCOSITY_UNITS: [(ViscosityUnit, &[&str]); 3] = [
+    (ViscosityUnit::MicroPaS, &["uPa.s", "uPa*s"]),
+    (ViscosityUnit::MilliPaS, &["mPa.s", "mPa*s", "cP"]),
+    (ViscosityUnit::PaS, &["Pa.s", "Pa*s"]),
+];
+
+const CONDUCTIVITY_UNITS: [(ConductivityUnit, &[&str]); 2] = [
+    (ConductivityUnit::WPerMK, &["W/m/K"]),
+    (ConductivityUnit::MilliWPerMK, &["mW/m/K"]),
+];
+
+const DIPOLE_UNITS: [(DipoleUnit, &[&str]); 2] = [
+    (DipoleUnit::Debye, &["debye"]),
+    (DipoleUnit::CoulombMeter, &["C*m", "C.m"]),
+];
+
+const MASS_FLOW_UNITS: [(MassFlowUnit, &[&str]); 3] = [
+    (MassFlowUnit::KgPerS, &[]),
+    (MassFlowUnit::KgPerH, &[]),
+    (MassFlowUnit::LbmPerH, &["lb/h"]),
+];
+
+/// Find the unit whose symbol, variant name or ASCII alias matches
+/// `text`, ignoring case.
+fn lookup_unit<U: Copy + std::fmt::Debug>(
+    table: &[(U, &[&str])],
+    symbol: fn(U) -> &'static str,
+    text: &str,
+) -> Option<U> {
+    let text = text.trim().to_lowercase();
+    table
+        .iter()
+        .find(|&&(u, aliases)| {
+            [symbol(u).to_string(), format!("{u:?}")]
+                .into_iter()
+                .chain(aliases.iter().map(|a| a.to_string()))
+                .any(|name| name.to_lowercase() == text)
+        })
+        .map(|&(u, _)| u)
+}
+
 // ────────────────────────────────────────────────────────────────────
 //  UnitSystem — user configuration (no molar mass needed yet)
 // ────────────────────────────────────────────────────────────────────
@@ -117,16 +465,35 @@ pub enum ConductivityUnit {
 /// Describes the set of units the user wants to work in.
 ///
 /// Create one with a preset (`refprop()`, `engineering()`, `si()`) or
-/// customise individual properties with the builder methods.
+/// customise individual properties with the builder methods.  New unit
+/// fields may be added, so the struct cannot be built with a literal
+/// outside this crate.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct UnitSystem {
     pub temperature: TempUnit,
     pub pressure: PressUnit,
     pub density: DensityUnit,
     pub energy: EnergyUnit,
     pub entropy: EntropyUnit,
+    /// Unit of Cv and Cp; `None` follows [`entropy`](Self::entropy)
+    /// (see [`heat_capacity_unit`](Self::heat_capacity_unit)).
+    #[serde(default)]
+    pub heat_capacity: Option<HeatCapacityUnit>,
     pub viscosity: ViscosityUnit,
     pub conductivity: ConductivityUnit,
+    /// Only used for per-component data
+    /// ([`Fluid::component_info`](crate::Fluid::component_info)).
+    #[serde(default)]
+    pub dipole: DipoleUnit,
+    /// Only used for stream inputs
+    /// ([`Fluid::stream_tp`](crate::Fluid::stream_tp)).
+    #[serde(default)]
+    pub mass_flow: MassFlowUnit,
+    /// When set, density, energy and entropy units must all share this
+    /// basis (see [`basis`](Self::basis) and [`validate`](Self::validate)).
+    #[serde(default)]
+    pub basis: Option<Basis>,
 }
 
 impl UnitSystem {
@@ -146,8 +513,12 @@ impl UnitSystem {
             density: DensityUnit::MolPerL,
             energy: EnergyUnit::JPerMol,
             entropy: EntropyUnit::JPerMolK,
+            heat_capacity: None,
             viscosity: ViscosityUnit::MicroPaS,
             conductivity: ConductivityUnit::WPerMK,
+            dipole: DipoleUnit::Debye,
+            mass_flow: MassFlowUnit::KgPerS,
+            basis: None,
         }
     }
 
@@ -159,12 +530,16 @@ impl UnitSystem {
             density: DensityUnit::KgPerM3,
             energy: EnergyUnit::KJPerKg,
             entropy: EntropyUnit::KJPerKgK,
+            heat_capacity: None,
             viscosity: ViscosityUnit::MicroPaS,
             conductivity: ConductivityUnit::WPerMK,
+            dipole: DipoleUnit::Debye,
+            mass_flow: MassFlowUnit::KgPerS,
+            basis: None,
         }
     }
 
-    /// Strict SI: K, Pa, kg/m³, J/kg, J/(kg·K), Pa·s.
+    /// Strict SI, mass basis: K, Pa, kg/m³, J/kg, J/(kg·K), Pa·s.
     pub fn si() -> Self {
         Self {
             temperature: TempUnit::Kelvin,
@@ -172,8 +547,29 @@ impl UnitSystem {
             density: DensityUnit::KgPerM3,
             energy: EnergyUnit::JPerKg,
             entropy: EntropyUnit::JPerKgK,
+            heat_capacity: None,
             viscosity: ViscosityUnit::PaS,
             conductivity: ConductivityUnit::WPerMK,
+            dipole: DipoleUnit::Debye,
+            mass_flow: MassFlowUnit::KgPerS,
+            basis: None,
+        }
+    }
+
+    /// Strict SI, molar basis: K, Pa, mol/m³, J/mol, J/(mol·K), Pa·s.
+    pub fn si_molar() -> Self {
+        Self {
+            temperature: TempUnit::Kelvin,
+            pressure: PressUnit::Pa,
+            density: DensityUnit::MolPerM3,
+            energy: EnergyUnit::JPerMol,
+            entropy: EntropyUnit::JPerMolK,
+            heat_capacity: None,
+            viscosity: ViscosityUnit::PaS,
+            conductivity: ConductivityUnit::WPerMK,
+            dipole: DipoleUnit::Debye,
+            mass_flow: MassFlowUnit::KgPerS,
+            basis: None,
         }
     }
 
@@ -199,6 +595,11 @@ impl UnitSystem {
         self.entropy = u;
         self
     }
+    /// Give Cv and Cp their own unit instead of the entropy unit.
+    pub fn heat_capacity(mut self, u: HeatCapacityUnit) -> Self {
+        self.heat_capacity = Some(u);
+        self
+    }
     pub fn viscosity(mut self, u: ViscosityUnit) -> Self {
         self.viscosity = u;
         self
@@ -207,6 +608,215 @@ impl UnitSystem {
         self.conductivity = u;
         self
     }
+    pub fn dipole(mut self, u: DipoleUnit) -> Self {
+        self.dipole = u;
+        self
+    }
+    pub fn mass_flow(mut self, u: MassFlowUnit) -> Self {
+        self.mass_flow = u;
+        self
+    }
+
+    /// Pin density, energy and entropy to one basis.
+    ///
+    /// Units already on that basis are kept (e.g. J/kg stays J/kg);
+    /// the others are replaced by kg/m³, kJ/kg, kJ/(kg·K) for
+    /// [`Basis::Mass`] or mol/L, J/mol, J/(mol·K) for [`Basis::Molar`].
+    /// Units changed afterwards with the fine-grained builders must stay
+    /// on the same basis, otherwise [`validate`](Self::validate) (and
+    /// thus `Fluid` construction) fails.
+    ///
+    /// ```
+    /// use refprop::{Basis, DensityUnit, EnergyUnit, UnitSystem};
+    ///
+    /// let units = UnitSystem::new().basis(Basis::Mass);
+    /// assert_eq!(units.density, DensityUnit::KgPerM3);
+    /// assert_eq!(units.energy, EnergyUnit::KJPerKg);
+    /// ```
+    pub fn basis(mut self, basis: Basis) -> Self {
+        if self.density.basis() != basis {
+            self.density = match basis {
+                Basis::Mass => DensityUnit::KgPerM3,
+                Basis::Molar => DensityUnit::MolPerL,
+            };
+        }
+        if self.energy.basis() != basis {
+            self.energy = match basis {
+                Basis::Mass => EnergyUnit::KJPerKg,
+                Basis::Molar => EnergyUnit::JPerMol,
+            };
+        }
+        if self.entropy.basis() != basis {
+            self.entropy = match basis {
+                Basis::Mass => EntropyUnit::KJPerKgK,
+                Basis::Molar => EntropyUnit::JPerMolK,
+            };
+        }
+        if self.heat_capacity.is_some_and(|u| u.basis() != basis) {
+            self.heat_capacity = None;
+        }
+        self.basis = Some(basis);
+        self
+    }
+
+    /// Unit of Cv and Cp: [`heat_capacity`](Self::heat_capacity) if set,
+    /// otherwise the entropy unit.
+    pub fn heat_capacity_unit(&self) -> HeatCapacityUnit {
+        self.heat_capacity
+            .unwrap_or_else(|| HeatCapacityUnit::from(self.entropy))
+    }
+
+    /// Check that density, energy, entropy and heat-capacity units agree
+    /// with the pinned [`basis`](Self::basis), if any.
+    pub fn validate(&self) -> Result<()> {
+        let Some(basis) = self.basis else {
+            return Ok(());
+        };
+        let mismatched: Vec<&str> = [
+            (self.density.basis(), self.density.symbol()),
+            (self.energy.basis(), self.energy.symbol()),
+            (self.entropy.basis(), self.entropy.symbol()),
+        ]
+        .into_iter()
+        .chain(self.heat_capacity.map(|u| (u.basis(), u.symbol())))
+        .filter(|&(b, _)| b != basis)
+        .map(|(_, symbol)| symbol)
+        .collect();
+        if mismatched.is_empty() {
+            Ok(())
+        } else {
+            Err(RefpropError::InvalidInput(format!(
+                "Units {} are inconsistent with the {basis:?} basis",
+                mismatched.join(", ")
+            )))
+        }
+    }
+}
+
+impl UnitSystem {
+    /// Build a unit system from a deserialized [`UnitConfig`].
+    ///
+    /// `basis`, when set, is applied first (see [`basis`](Self::basis))
+    /// and the explicit units on top of it; the result is then
+    /// [`validate`](Self::validate)d.  An unknown string fails with
+    /// [`InvalidInput`](RefpropError::InvalidInput) naming the field.
+    /// The `reference` field is not part of the unit system, see
+    /// [`Fluid::with_config`](crate::Fluid::with_config).
+    ///
+    /// ```
+    /// use refprop::{PressUnit, TempUnit, UnitConfig, UnitSystem};
+    ///
+    /// let config = UnitConfig {
+    ///     temperature: Some("degC".into()),
+    ///     pressure: Some("bar".into()),
+    ///     ..UnitConfig::default()
+    /// };
+    /// let units = UnitSystem::from_config(&config)?;
+    /// assert_eq!(units.temperature, TempUnit::Celsius);
+    /// assert_eq!(units.pressure, PressUnit::Bar);
+    /// # Ok::<(), refprop::RefpropError>(())
+    /// ```
+    pub fn from_config(config: &UnitConfig) -> Result<Self> {
+        fn field<U: Copy + std::fmt::Debug>(
+            name: &str,
+            value: &Option<String>,
+            table: &[(U, &[&str])],
+            symbol: fn(U) -> &'static str,
+        ) -> Result<Option<U>> {
+            let Some(text) = value else {
+                return Ok(None);
+            };
+            lookup_unit(table, symbol, text).map(Some).ok_or_else(|| {
+                RefpropError::InvalidInput(format!("Unknown unit \"{text}\" in field `{name}`"))
+            })
+        }
+
+        let mut units = Self::refprop();
+        if let Some(text) = &config.basis {
+            let basis = match text.trim().to_lowercase().as_str() {
+                "mass" => Basis::Mass,
+                "molar" | "mole" => Basis::Molar,
+                _ => {
+                    return Err(RefpropError::InvalidInput(format!(
+                        "Unknown basis \"{text}\" in field `basis` (expected mass or molar)"
+                    )));
+                }
+            };
+            units = units.basis(basis);
+        }
+        if let Some(u) = field(
+            "temperature",
+            &config.temperature,
+            &TEMP_UNITS,
+            TempUnit::symbol,
+        )? {
+            units.temperature = u;
+        }
+        if let Some(u) = field(
+            "pressure",
+            &config.pressure,
+            &PRESS_UNITS,
+            PressUnit::symbol,
+        )? {
+            units.pressure = u;
+        }
+        if let Some(u) = field(
+            "density",
+            &config.density,
+            &DENSITY_UNITS,
+            DensityUnit::symbol,
+        )? {
+            units.density = u;
+        }
+        if let Some(u) = field("energy", &config.energy, &ENERGY_UNITS, EnergyUnit::symbol)? {
+            units.energy = u;
+        }
+        if let Some(u) = field(
+            "entropy",
+            &config.entropy,
+            &ENTROPY_UNITS,
+            EntropyUnit::symbol,
+        )? {
+            units.entropy = u;
+        }
+        if let Some(u) = field(
+            "heat_capacity",
+            &config.heat_capacity,
+            &HEAT_CAPACITY_UNITS,
+            HeatCapacityUnit::symbol,
+        )? {
+            units.heat_capacity = Some(u);
+        }
+        if let Some(u) = field(
+            "viscosity",
+            &config.viscosity,
+            &VISCOSITY_UNITS,
+            ViscosityUnit::symbol,
+        )? {
+            units.viscosity = u;
+        }
+        if let Some(u) = field(
+            "conductivity",
+            &config.conductivity,
+            &CONDUCTIVITY_UNITS,
+            ConductivityUnit::symbol,
+        )? {
+            units.conductivity = u;
+        }
+        if let Some(u) = field("dipole", &config.dipole, &DIPOLE_UNITS, DipoleUnit::symbol)? {
+            units.dipole = u;
+        }
+        if let Some(u) = field(
+            "mass_flow",
+            &config.mass_flow,
+            &MASS_FLOW_UNITS,
+            MassFlowUnit::symbol,
+        )? {
+            units.mass_flow = u;
+        }
+        units.validate()?;
+        Ok(units)
+    }
 }
 
 impl Default for UnitSystem {
@@ -215,6 +825,157 @@ impl Default for UnitSystem {
     }
 }
 
+// ────────────────────────────────────────────────────────────────────
+//  UnitConfig — persisted preferences (strings, serde)
+// ────────────────────────────────────────────────────────────────────
+
+/// Unit and reference-state preferences as plain strings, for loading
+/// from a JSON or TOML settings file.
+///
+/// Every field is optional: missing units keep the REFPROP default
+/// ([`UnitSystem::refprop`]), a missing `reference` is
+/// [`ReferenceState::Default`](crate::ReferenceState::Default).  Unit
+/// strings are matched like in
+/// [`Converter::parse_input_to_rp`] (symbol, variant name or ASCII
+/// spelling, any case); `basis` is `"mass"` or `"molar"` and
+/// `reference` a REFPROP code (`"DEF"`, `"NBP"`, `"ASH"`, `"IIR"`).
+///
+/// ```toml
+/// temperature = "degC"
+/// pressure = "bar"
+/// basis = "mass"
+/// reference = "ASH"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UnitConfig {
+    pub temperature: Option<String>,
+    pub pressure: Option<String>,
+    pub density: Option<String>,
+    pub energy: Option<String>,
+    pub entropy: Option<String>,
+    pub heat_capacity: Option<String>,
+    pub viscosity: Option<String>,
+    pub conductivity: Option<String>,
+    pub dipole: Option<String>,
+    pub mass_flow: Option<String>,
+    pub basis: Option<String>,
+    pub reference: Option<String>,
+}
+
+impl UnitConfig {
+    /// The configured reference state, [`ReferenceState::Default`](crate::ReferenceState::Default)
+    /// when unset.
+    pub fn reference_state(&self) -> Result<crate::ReferenceState> {
+        match &self.reference {
+            Some(code) => code.parse().map_err(|_| {
+                RefpropError::InvalidInput(format!(
+                    "Unknown reference state \"{code}\" in field `reference` \
+                     (expected DEF, NBP, ASH or IIR)"
+                ))
+            }),
+            None => Ok(crate::ReferenceState::Default),
+        }
+    }
+}
+
+// ────────────────────────────────────────────────────────────────────
+//  Property — typed keys for the key-based conversions
+// ────────────────────────────────────────────────────────────────────
+
+/// A convertible property, the typed counterpart of the string keys
+/// accepted by [`Converter::input_to_rp`] and
+/// [`Converter::output_from_rp`].
+///
+/// ```
+/// use refprop::{Converter, Property, UnitSystem};
+///
+/// let conv = Converter::new(UnitSystem::engineering(), 102.032);
+/// assert_eq!(conv.to_rp(Property::Temperature, 25.0)?, 298.15);
+/// assert_eq!("rho".parse::<Property>()?, Property::Density);
+/// # Ok::<(), refprop::RefpropError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Property {
+    /// `"T"`
+    Temperature,
+    /// `"P"`
+    Pressure,
+    /// `"D"` (alias `"RHO"`)
+    Density,
+    /// `"H"`
+    Enthalpy,
+    /// `"S"`
+    Entropy,
+    /// `"U"` (alias `"E"`)
+    InternalEnergy,
+    /// `"CP"`
+    Cp,
+    /// `"CV"`
+    Cv,
+    /// `"ETA"` (aliases `"V"`, `"VIS"`)
+    Viscosity,
+    /// `"TCX"` (aliases `"L"`, `"LAMBDA"`)
+    ThermalConductivity,
+    /// `"W"` (alias `"A"`), always m/s
+    SoundSpeed,
+    /// `"Q"`, in percent on the user side
+    Quality,
+}
+
+impl Property {
+    /// Every property, in declaration order.
+    pub const ALL: [Property; 12] = [
+        Self::Temperature,
+        Self::Pressure,
+        Self::Density,
+        Self::Enthalpy,
+        Self::Entropy,
+        Self::InternalEnergy,
+        Self::Cp,
+        Self::Cv,
+        Self::Viscosity,
+        Self::ThermalConductivity,
+        Self::SoundSpeed,
+        Self::Quality,
+    ];
+
+    /// Canonical string key, e.g. `"ETA"`.
+    pub fn key(self) -> &'static str {
+        self.keys()[0]
+    }
+
+    /// Canonical key followed by its aliases.
+    fn keys(self) -> &'static [&'static str] {
+        match self {
+            Self::Temperature => &["T"],
+            Self::Pressure => &["P"],
+            Self::Density => &["D", "RHO"],
+            Self::Enthalpy => &["H"],
+            Self::Entropy => &["S"],
+            Self::InternalEnergy => &["U", "E"],
+            Self::Cp => &["CP"],
+            Self::Cv => &["CV"],
+            Self::Viscosity => &["ETA", "V", "VIS"],
+            Self::ThermalConductivity => &["TCX", "L", "LAMBDA"],
+            Self::SoundSpeed => &["W", "A"],
+            Self::Quality => &["Q"],
+        }
+    }
+}
+
+impl std::str::FromStr for Property {
+    type Err = RefpropError;
+
+    /// Parse a string key or alias, case-insensitively.
+    fn from_str(s: &str) -> Result<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|p| p.keys().iter().any(|k| k.eq_ignore_ascii_case(s)))
+            .ok_or_else(|| RefpropError::InvalidInput(format!("Unknown property key \"{s}\"")))
+    }
+}
+
 // ────────────────────────────────────────────────────────────────────
 //  Converter — UnitSystem + molar mass → ready to convert
 // ────────────────────────────────────────────────────────────────────
@@ -228,20 +989,47 @@ pub struct Converter {
     pub units: UnitSystem,
     /// Molar mass in g/mol (mixture-averaged for mixtures).
     pub molar_mass: f64,
+    /// REFPROP enthalpy (J/mol) shown as zero by
+    /// [`enthalpy_from_rp`](Self::enthalpy_from_rp); see
+    /// [`Fluid::rezero_reference`](crate::Fluid::rezero_reference).
+    pub h_offset: f64,
+    /// REFPROP entropy (J/(mol·K)) shown as zero by
+    /// [`entropy_from_rp`](Self::entropy_from_rp).
+    pub s_offset: f64,
 }
 
 impl Converter {
     pub fn new(units: UnitSystem, molar_mass: f64) -> Self {
-        Self { units, molar_mass }
+        Self {
+            units,
+            molar_mass,
+            h_offset: 0.0,
+            s_offset: 0.0,
+        }
     }
 
     /// Identity converter — no conversion at all (REFPROP native units,
     /// molar mass = 1 so mass-based formulas still work formally).
     pub fn identity() -> Self {
-        Self {
-            units: UnitSystem::refprop(),
-            molar_mass: 1.0,
-        }
+        Self::new(UnitSystem::refprop(), 1.0)
+    }
+
+    /// Converter for a fluid of molar mass `molar_mass` (g/mol), in
+    /// REFPROP-native units until [`with_units`](Self::with_units) is
+    /// called.  Needs no REFPROP library.
+    pub fn for_fluid(molar_mass: f64) -> Self {
+        Self::new(UnitSystem::refprop(), molar_mass)
+    }
+
+    /// Same converter with the user units replaced by `units`.
+    pub fn with_units(mut self, units: UnitSystem) -> Self {
+        self.units = units;
+        self
+    }
+
+    /// Units this converter translates to and from.
+    pub fn unit_system(&self) -> &UnitSystem {
+        &self.units
     }
 
     // ── Temperature ─────────────────────────────────────────────────
@@ -296,7 +1084,10 @@ impl Converter {
     pub fn d_to_rp(&self, d: f64) -> f64 {
         match self.units.density {
             DensityUnit::MolPerL => d,
+            DensityUnit::MolPerM3 => d / 1000.0,
             DensityUnit::KgPerM3 => d / self.molar_mass,
+            DensityUnit::Amagat => d * AMAGAT_MOL_PER_L,
+            DensityUnit::PerM3 => d / (AVOGADRO * 1000.0),
         }
     }
 
@@ -304,7 +1095,10 @@ impl Converter {
     pub fn d_from_rp(&self, d: f64) -> f64 {
         match self.units.density {
             DensityUnit::MolPerL => d,
+            DensityUnit::MolPerM3 => d * 1000.0,
             DensityUnit::KgPerM3 => d * self.molar_mass,
+            DensityUnit::Amagat => d / AMAGAT_MOL_PER_L,
+            DensityUnit::PerM3 => d * AVOGADRO * 1000.0,
         }
     }
 
@@ -328,6 +1122,20 @@ impl Converter {
         }
     }
 
+    /// Absolute enthalpy (or internal energy), user → REFPROP: like
+    /// [`h_to_rp`](Self::h_to_rp) with [`h_offset`](Self::h_offset)
+    /// added back.  Use `h_to_rp` for enthalpy differences.
+    pub fn enthalpy_to_rp(&self, h: f64) -> f64 {
+        self.h_to_rp(h) + self.h_offset
+    }
+
+    /// Absolute enthalpy (or internal energy), REFPROP → user: like
+    /// [`h_from_rp`](Self::h_from_rp) with [`h_offset`](Self::h_offset)
+    /// subtracted first.  Use `h_from_rp` for enthalpy differences.
+    pub fn enthalpy_from_rp(&self, h: f64) -> f64 {
+        self.h_from_rp(h - self.h_offset)
+    }
+
     // ── Entropy / Cv / Cp ───────────────────────────────────────────
 
     /// User → REFPROP (J/(mol·K))
@@ -348,6 +1156,41 @@ impl Converter {
         }
     }
 
+    /// Absolute entropy, user → REFPROP, with
+    /// [`s_offset`](Self::s_offset) added back.  Use
+    /// [`s_to_rp`](Self::s_to_rp) for Cv, Cp and entropy differences.
+    pub fn entropy_to_rp(&self, s: f64) -> f64 {
+        self.s_to_rp(s) + self.s_offset
+    }
+
+    /// Absolute entropy, REFPROP → user, with
+    /// [`s_offset`](Self::s_offset) subtracted first.  Use
+    /// [`s_from_rp`](Self::s_from_rp) for Cv, Cp and entropy
+    /// differences.
+    pub fn entropy_from_rp(&self, s: f64) -> f64 {
+        self.s_from_rp(s - self.s_offset)
+    }
+
+    /// Cv or Cp, user → REFPROP (J/(mol·K)), in the
+    /// [heat-capacity unit](UnitSystem::heat_capacity_unit).
+    pub fn cp_to_rp(&self, c: f64) -> f64 {
+        match self.units.heat_capacity_unit() {
+            HeatCapacityUnit::JPerMolK => c,
+            HeatCapacityUnit::KJPerKgK | HeatCapacityUnit::JPerGK => c * self.molar_mass,
+            HeatCapacityUnit::JPerKgK => c * self.molar_mass / 1000.0,
+        }
+    }
+
+    /// Cv or Cp, REFPROP (J/(mol·K)) → user, in the
+    /// [heat-capacity unit](UnitSystem::heat_capacity_unit).
+    pub fn cp_from_rp(&self, c: f64) -> f64 {
+        match self.units.heat_capacity_unit() {
+            HeatCapacityUnit::JPerMolK => c,
+            HeatCapacityUnit::KJPerKgK | HeatCapacityUnit::JPerGK => c / self.molar_mass,
+            HeatCapacityUnit::JPerKgK => c * 1000.0 / self.molar_mass,
+        }
+    }
+
     // ── Viscosity ───────────────────────────────────────────────────
 
     /// REFPROP (µPa·s) → User
@@ -386,6 +1229,27 @@ impl Converter {
         }
     }
 
+    // ── Dipole moment ───────────────────────────────────────────────
+
+    /// REFPROP (debye) → User
+    pub fn dipole_from_rp(&self, dip: f64) -> f64 {
+        match self.units.dipole {
+            DipoleUnit::Debye => dip,
+            DipoleUnit::CoulombMeter => dip * DEBYE_C_M,
+        }
+    }
+
+    // ── Mass flow ───────────────────────────────────────────────────
+
+    /// User → kg/s
+    pub fn mass_flow_to_kg_s(&self, m: f64) -> f64 {
+        match self.units.mass_flow {
+            MassFlowUnit::KgPerS => m,
+            MassFlowUnit::KgPerH => m / 3600.0,
+            MassFlowUnit::LbmPerH => m * LBM_KG / 3600.0,
+        }
+    }
+
     // ── Quality (vapour fraction) ────────────────────────────────────
 
     /// User (0–100 %) → REFPROP (0–1 molar fraction).
@@ -416,19 +1280,104 @@ impl Converter {
     /// to the REFPROP molar fraction (0–1).  Values outside 0–100 yield
     /// an [`InvalidInput`](RefpropError::InvalidInput) error.
     pub fn input_to_rp(&self, key: &str, val: f64) -> Result<f64> {
-        match key.to_uppercase().as_str() {
-            "T" => Ok(self.t_to_rp(val)),
-            "P" => Ok(self.p_to_rp(val)),
-            "D" | "RHO" => Ok(self.d_to_rp(val)),
-            "H" => Ok(self.h_to_rp(val)),
-            "S" => Ok(self.s_to_rp(val)),
-            "E" | "U" => Ok(self.h_to_rp(val)),
-            "CV" | "CP" => Ok(self.s_to_rp(val)),
-            "ETA" | "V" | "VIS" => Ok(self.eta_to_rp(val)),
-            "TCX" | "L" | "LAMBDA" => Ok(self.tcx_to_rp(val)),
-            "Q" => self.q_to_rp(val),
-            _ => Ok(val), // W, etc.
+        match key.parse::<Property>() {
+            Ok(prop) => self.to_rp(prop, val),
+            Err(_) => Ok(val),
+        }
+    }
+
+    /// Typed form of [`input_to_rp`](Self::input_to_rp): user units →
+    /// REFPROP units for `prop`.  Absolute enthalpy, internal energy
+    /// and entropy include the rezero offsets.
+    pub fn to_rp(&self, prop: Property, val: f64) -> Result<f64> {
+        Ok(match prop {
+            Property::Temperature => self.t_to_rp(val),
+            Property::Pressure => self.p_to_rp(val),
+            Property::Density => self.d_to_rp(val),
+            Property::Enthalpy | Property::InternalEnergy => self.enthalpy_to_rp(val),
+            Property::Entropy => self.entropy_to_rp(val),
+            Property::Cp | Property::Cv => self.cp_to_rp(val),
+            Property::Viscosity => self.eta_to_rp(val),
+            Property::ThermalConductivity => self.tcx_to_rp(val),
+            Property::SoundSpeed => val,
+            Property::Quality => self.q_to_rp(val)?,
+        })
+    }
+
+    /// Typed form of [`output_from_rp`](Self::output_from_rp): REFPROP
+    /// units → user units for `prop`.
+    pub fn from_rp(&self, prop: Property, val: f64) -> f64 {
+        match prop {
+            Property::Temperature => self.t_from_rp(val),
+            Property::Pressure => self.p_from_rp(val),
+            Property::Density => self.d_from_rp(val),
+            Property::Enthalpy | Property::InternalEnergy => self.enthalpy_from_rp(val),
+            Property::Entropy => self.entropy_from_rp(val),
+            Property::Cp | Property::Cv => self.cp_from_rp(val),
+            Property::Viscosity => self.eta_from_rp(val),
+            Property::ThermalConductivity => self.tcx_from_rp(val),
+            Property::SoundSpeed => val,
+            Property::Quality => self.q_from_rp(val),
+        }
+    }
+
+    /// Convert a user-provided input written as text — a number,
+    /// optionally followed by a unit (`"25 degC"`, `"5bar"`,
+    /// `"1.2 kg/m3"`) — to REFPROP units for property `key`.
+    ///
+    /// A unit overrides the configured one for this value only; without
+    /// a unit the text behaves like [`input_to_rp`](Self::input_to_rp).
+    /// Recognised units are the [`symbol()`](TempUnit::symbol) or the
+    /// variant name of every temperature, pressure, density, energy and
+    /// entropy unit (case does not matter) plus ASCII spellings such as
+    /// `degC`, `F`, `psia`, `kg/m3` or `kJ/kg/K`; `%` is accepted for
+    /// `"Q"`.
+    ///
+    /// Returns [`InvalidInput`](RefpropError::InvalidInput) when the
+    /// number cannot be parsed or the unit does not fit `key`.
+    pub fn parse_input_to_rp(&self, key: &str, text: &str) -> Result<f64> {
+        let text = text.trim();
+        let split = (1..=text.len())
+            .rev()
+            .filter(|&i| text.is_char_boundary(i))
+            .find(|&i| text[..i].trim_end().parse::<f64>().is_ok())
+            .ok_or_else(|| {
+                RefpropError::InvalidInput(format!("Cannot parse a number from \"{text}\""))
+            })?;
+        let val: f64 = text[..split].trim_end().parse().unwrap_or_default();
+        let unit = text[split..].trim();
+        if unit.is_empty() {
+            return self.input_to_rp(key, val);
+        }
+
+        let mut units = self.units.clone();
+        let found = match key.to_uppercase().as_str() {
+            "T" => lookup_unit(&TEMP_UNITS, TempUnit::symbol, unit).map(|u| units.temperature = u),
+            "P" => lookup_unit(&PRESS_UNITS, PressUnit::symbol, unit).map(|u| units.pressure = u),
+            "D" | "RHO" => {
+                lookup_unit(&DENSITY_UNITS, DensityUnit::symbol, unit).map(|u| units.density = u)
+            }
+            "H" | "E" | "U" => {
+                lookup_unit(&ENERGY_UNITS, EnergyUnit::symbol, unit).map(|u| units.energy = u)
+            }
+            "S" => {
+                lookup_unit(&ENTROPY_UNITS, EntropyUnit::symbol, unit).map(|u| units.entropy = u)
+            }
+            "CV" | "CP" => lookup_unit(&HEAT_CAPACITY_UNITS, HeatCapacityUnit::symbol, unit)
+                .map(|u| units.heat_capacity = Some(u)),
+            "Q" => (unit == "%").then_some(()),
+            _ => None,
+        };
+        if found.is_none() {
+            return Err(RefpropError::InvalidInput(format!(
+                "Unit \"{unit}\" is not valid for input {key}"
+            )));
+        }
+        Converter {
+            units,
+            ..self.clone()
         }
+        .input_to_rp(key, val)
     }
 
     /// Convert a REFPROP output value to user units.
@@ -437,17 +1386,16 @@ impl Converter {
     /// the REFPROP molar fraction (0–1).
     pub fn output_from_rp(&self, key: &str, val: f64) -> f64 {
         match key.to_uppercase().as_str() {
-            "T" => self.t_from_rp(val),
-            "P" => self.p_from_rp(val),
-            "D" | "RHO" => self.d_from_rp(val),
-            "H" => self.h_from_rp(val),
-            "S" => self.s_from_rp(val),
-            "E" | "U" => self.h_from_rp(val),
-            "CV" | "CP" => self.s_from_rp(val),
-            "ETA" | "V" | "VIS" => self.eta_from_rp(val),
-            "TCX" | "L" | "LAMBDA" => self.tcx_from_rp(val),
-            "Q" => self.q_from_rp(val),
-            _ => val, // W, etc.
+            "TSAT" | "TCRIT" | "TTRP" | "TNBP" => self.t_from_rp(val),
+            "PSAT" | "PCRIT" => self.p_from_rp(val),
+            "DLIQ" | "DVAP" | "DCRIT" => self.d_from_rp(val),
+            // REFPROP molar units; only the rezero shift applies
+            "HMOLAR" | "UMOLAR" => val - self.h_offset,
+            "SMOLAR" => val - self.s_offset,
+            k => match k.parse::<Property>() {
+                Ok(prop) => self.from_rp(prop, val),
+                Err(_) => val, // M (g/mol), DMOLAR, CPMOLAR, CVMOLAR, etc.
+            },
         }
     }
 }
@@ -0,0 +1,94 @@
+//! Number formatting for the unit-aware text output of [`Fluid`].
+//!
+//! The plain `Display` impls on the property structs keep their fixed
+//! precision.  [`Fluid::describe`] and [`Fluid::saturation_report`]
+//! instead follow the [`DisplayConfig`] attached with
+//! [`Fluid::set_display_config`].
+//!
+//! ```
+//! use refprop::DisplayConfig;
+//!
+//! let cfg = DisplayConfig::new().pressure(2).density(1);
+//! assert_eq!(cfg.pressure, 2);
+//! ```
+//!
+//! [`Fluid`]: crate::Fluid
+//! [`Fluid::describe`]: crate::Fluid::describe
+//! [`Fluid::saturation_report`]: crate::Fluid::saturation_report
+//! [`Fluid::set_display_config`]: crate::Fluid::set_display_config
+
+use serde::{Deserialize, Serialize};
+
+/// Number of decimal places used for each kind of property.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DisplayConfig {
+    pub temperature: usize,
+    pub pressure: usize,
+    pub density: usize,
+    /// Enthalpy and internal energy.
+    pub energy: usize,
+    /// Entropy, Cv and Cp.
+    pub entropy: usize,
+    pub sound_speed: usize,
+    /// Quality (percent).
+    pub quality: usize,
+    /// Mole fractions.
+    pub composition: usize,
+}
+
+impl DisplayConfig {
+    /// Default precision, matching the struct `Display` impls.
+    pub fn new() -> Self {
+        Self {
+            temperature: 4,
+            pressure: 4,
+            density: 6,
+            energy: 4,
+            entropy: 4,
+            sound_speed: 4,
+            quality: 4,
+            composition: 6,
+        }
+    }
+
+    // ── Builder methods ─────────────────────────────────────────────
+
+    pub fn temperature(mut self, decimals: usize) -> Self {
+        self.temperature = decimals;
+        self
+    }
+    pub fn pressure(mut self, decimals: usize) -> Self {
+        self.pressure = decimals;
+        self
+    }
+    pub fn density(mut self, decimals: usize) -> Self {
+        self.density = decimals;
+        self
+    }
+    pub fn energy(mut self, decimals: usize) -> Self {
+        self.energy = decimals;
+        self
+    }
+    pub fn entropy(mut self, decimals: usize) -> Self {
+        self.entropy = decimals;
+        self
+    }
+    pub fn sound_speed(mut self, decimals: usize) -> Self {
+        self.sound_speed = decimals;
+        self
+    }
+    pub fn quality(mut self, decimals: usize) -> Self {
+        self.quality = decimals;
+        self
+    }
+    pub fn composition(mut self, decimals: usize) -> Self {
+        self.composition = decimals;
+        self
+    }
+}
+
+impl Default for DisplayConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
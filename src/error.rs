@@ -1,8 +1,22 @@
 use thiserror::Error;
 
+#[cfg(feature = "refprop")]
+use crate::backend::refprop::{INPUT_PAIRS, OUTPUT_KEYS};
+#[cfg(feature = "refprop")]
+use crate::sys::RefpropSysError;
+
+/// Errors of every fallible operation of the crate.
+///
+/// [`Sys`](Self::Sys), [`UnknownOutputKey`](Self::UnknownOutputKey) and
+/// [`UnsupportedInputPair`](Self::UnsupportedInputPair) come from the
+/// REFPROP library or `Fluid::get` and exist only with the `refprop`
+/// feature.
 #[derive(Error, Debug)]
 pub enum RefpropError {
     /// Error returned by a REFPROP routine (ierr > 0).
+    ///
+    /// `message` is the routine's `herr` output; the standard text for
+    /// `code` is available from [`Fluid::error_message`](crate::Fluid::error_message).
     #[error("REFPROP error {code}: {message}")]
     Refprop { code: i32, message: String },
 
@@ -11,10 +25,22 @@ pub enum RefpropError {
     #[error("REFPROP warning {code}: {message}")]
     Warning { code: i32, message: String },
 
-    /// The REFPROP DLL/so could not be loaded.
+    /// The REFPROP directory could not be located or does not exist.
     #[error("REFPROP library not found: {0}")]
     LibraryNotFound(String),
 
+    /// The REFPROP library failed to load or lacks a routine.
+    ///
+    /// The [`RefpropSysError`] is kept as the error's
+    /// [`source`](std::error::Error::source):
+    /// [`LibraryLoadFailed`](RefpropSysError::LibraryLoadFailed) means no
+    /// usable DLL was found, [`SymbolNotFound`](RefpropSysError::SymbolNotFound)
+    /// that the DLL is too old or not REFPROP.  The message itself is
+    /// fixed so that error-chain reporters do not print the cause twice.
+    #[cfg(feature = "refprop")]
+    #[error("REFPROP library unusable")]
+    Sys(#[from] RefpropSysError),
+
     /// A fluid `.FLD` file was not found in the fluids directory.
     #[error("Fluid file not found: {0}")]
     FluidNotFound(String),
@@ -23,9 +49,40 @@ pub enum RefpropError {
     #[error("Invalid input: {0}")]
     InvalidInput(String),
 
+    /// Output key not recognised by `get` (e.g. `"X"`).
+    #[cfg(feature = "refprop")]
+    #[error("Unknown output property \"{0}\". Supported: {keys}", keys = OUTPUT_KEYS.join(" "))]
+    UnknownOutputKey(String),
+
+    /// Input pair not supported by `get` (e.g. `("H", "Q")`).
+    #[cfg(feature = "refprop")]
+    #[error("Unsupported input pair ({0}, {1}). Supported: {pairs}", pairs = input_pairs())]
+    UnsupportedInputPair(String, String),
+
+    /// The fluid (or the named mixture component) has no viscosity or
+    /// thermal-conductivity model, so transport properties cannot be
+    /// computed; thermodynamic properties are unaffected.
+    #[error("Transport data unavailable for {0}")]
+    TransportUnavailable(String),
+
+    /// Writing an export (e.g. a CSV table) failed; the
+    /// [`std::io::Error`] is kept as the error's source.
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
     /// Catch-all for calculation failures.
     #[error("Calculation failed: {0}")]
     CalculationFailed(String),
 }
 
+/// [`INPUT_PAIRS`] as `(T,P) (P,H) …`.
+#[cfg(feature = "refprop")]
+fn input_pairs() -> String {
+    INPUT_PAIRS
+        .iter()
+        .map(|(a, b)| format!("({a},{b})"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
 pub type Result<T> = std::result::Result<T, RefpropError>;
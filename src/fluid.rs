@@ -1,11 +1,19 @@
-use crate::converter::{Converter, UnitSystem};
+use crate::converter::{Converter, Property, UnitConfig, UnitSystem};
 
-use crate::backend::refprop::RefpropBackend;
+use crate::backend::refprop::{INPUT_PAIRS, OUTPUT_KEYS, RefpropBackend};
+use crate::capture::OutputCapture;
+use crate::display::DisplayConfig;
 use crate::error::*;
 use crate::properties::*;
+use crate::state::StatePoint;
+use crate::sys::RefpropLibrary;
+use crate::table::PropertyTable;
+use std::collections::BTreeMap;
 use std::env;
+use std::io::Write;
+use std::ops::RangeInclusive;
 use std::path::Path;
-use std::sync::Once;
+use std::sync::{Arc, Once};
 
 /// High-level entry point for REFPROP calculations.
 ///
@@ -23,9 +31,31 @@ use std::sync::Once;
 /// println!("density = {d:.2} kg/m³");
 /// # Ok::<(), refprop::RefpropError>(())
 /// ```
+///
+/// # Sharing between threads
+///
+/// `Fluid` is `Send + Sync + 'static`: every call into REFPROP goes
+/// through the global lock and re-runs `SETUPdll` when another fluid
+/// was loaded in between, so one instance can be shared by reference,
+/// in an `Arc`, or in a `static`.
+///
+/// ```no_run
+/// use std::sync::OnceLock;
+/// use refprop::{Fluid, UnitSystem};
+///
+/// static R134A: OnceLock<Fluid> = OnceLock::new();
+///
+/// fn r134a() -> &'static Fluid {
+///     R134A.get_or_init(|| Fluid::with_units("R134A", UnitSystem::engineering()).unwrap())
+/// }
+///
+/// let p = r134a().get("P", "T", 0.0, "Q", 0.0)?;
+/// # Ok::<(), refprop::RefpropError>(())
+/// ```
 pub struct Fluid {
     backend: RefpropBackend,
     conv: Converter,
+    display: DisplayConfig,
 }
 
 impl Fluid {
@@ -37,6 +67,110 @@ impl Fluid {
         Self::with_units(fluid_name, UnitSystem::refprop())
     }
 
+    /// Create a `Fluid` with the REFPROP shared library and the fluid
+    /// files in **separate directories**.
+    ///
+    /// The library is loaded from `dll_dir`; `fluids_dir` is the data
+    /// root containing the `fluids/` and `mixtures/` folders (as a
+    /// REFPROP installation does) and is what `SETPATHdll` receives.
+    /// Useful when the DLL is installed system-wide but the fluid data
+    /// lives on a separate (e.g. read-only) mount.  `REFPROP_PATH` is
+    /// not consulted.
+    pub fn with_paths(
+        fluid_name: &str,
+        units: UnitSystem,
+        dll_dir: &str,
+        fluids_dir: &str,
+    ) -> Result<Self> {
+        units.validate()?;
+        let backend = RefpropBackend::new_with_paths(fluid_name, dll_dir, fluids_dir)?;
+        let mm = backend.molar_mass_mix()?;
+        let conv = Converter::new(units, mm);
+        Ok(Self {
+            backend,
+            conv,
+            display: DisplayConfig::default(),
+        })
+    }
+
+    /// Create a `Fluid` around a REFPROP library the caller has already
+    /// loaded, skipping path discovery and library loading.
+    ///
+    /// The fluid files are looked up in the directory the library was
+    /// loaded from, and a missing `.FLD`/`.MIX` file is reported as
+    /// [`FluidNotFound`](RefpropError::FluidNotFound).  One loaded library
+    /// can back any number of fluids; REFPROP calls from all of them
+    /// still go through the global lock.  For a library and fluid files
+    /// in separate directories use [`with_paths`](Self::with_paths).
+    ///
+    /// ```no_run
+    /// use std::path::Path;
+    /// use std::sync::Arc;
+    /// use refprop::sys::RefpropLibrary;
+    /// use refprop::{Fluid, UnitSystem};
+    ///
+    /// let lib = Arc::new(RefpropLibrary::load_from_dir(Path::new("/opt/refprop"))?);
+    /// let water = Fluid::from_library(Arc::clone(&lib), "WATER", UnitSystem::si())?;
+    /// let co2 = Fluid::from_library(lib, "CO2", UnitSystem::si())?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_library(
+        lib: Arc<RefpropLibrary>,
+        fluid_name: &str,
+        units: UnitSystem,
+    ) -> Result<Self> {
+        units.validate()?;
+        let backend = RefpropBackend::from_library(lib, fluid_name)?;
+        let mm = backend.molar_mass_mix()?;
+        let conv = Converter::new(units, mm);
+        Ok(Self {
+            backend,
+            conv,
+            display: DisplayConfig::default(),
+        })
+    }
+
+    /// Like [`new`](Self::new), but fails right away if the fluid cannot
+    /// actually be evaluated (see [`validated`](Self::validated)).
+    ///
+    /// Intended for catalog loaders that want to reject unusable fluid
+    /// files up front.
+    pub fn new_validated(fluid_name: &str) -> Result<Self> {
+        Self::new(fluid_name)?.validated()
+    }
+
+    /// Run a reference flash and return `self` only if it succeeds.
+    ///
+    /// `SETUPdll` accepts some fluid files whose equation of state is
+    /// incomplete, which then only fails at the first flash.  This
+    /// performs a saturated-liquid flash halfway between the triple
+    /// point and the critical temperature (rather than at the normal
+    /// boiling point, which lies below the triple point for fluids such
+    /// as CO₂) and checks that it returns finite values.  The extra cost
+    /// is one `CRITPdll` call and one saturation flash.
+    pub fn validated(self) -> Result<Self> {
+        let info = self.backend.fluid_info()?;
+        let crit = self.backend.critical_point()?;
+        let t = 0.5 * (info.triple_point_temp + crit.temperature);
+        let fail = |reason: String| {
+            RefpropError::CalculationFailed(format!(
+                "Reference flash for {} at T = {t:.2} K failed: {reason}",
+                self.backend.component_names().join("|")
+            ))
+        };
+        let props = self
+            .backend
+            .props_tq(t, 0.0)
+            .map_err(|e| fail(e.to_string()))?;
+        if ![props.pressure, props.density, props.enthalpy, props.entropy]
+            .iter()
+            .all(|v| v.is_finite())
+        {
+            return Err(fail("non-finite properties".into()));
+        }
+        Ok(self)
+    }
+
     /// Create a `Fluid` with a **custom unit system**.
     ///
     /// ```no_run
@@ -47,12 +181,76 @@ impl Fluid {
     /// # Ok::<(), refprop::RefpropError>(())
     /// ```
     pub fn with_units(fluid_name: &str, units: UnitSystem) -> Result<Self> {
+        units.validate()?;
         Self::load_dotenv();
         let refprop_path = Self::find_refprop_path()?;
         let backend = RefpropBackend::new(fluid_name, &refprop_path)?;
         let mm = backend.molar_mass_mix()?;
         let conv = Converter::new(units, mm);
-        Ok(Self { backend, conv })
+        Ok(Self {
+            backend,
+            conv,
+            display: DisplayConfig::default(),
+        })
+    }
+
+    /// Create a `Fluid` from persisted preferences: units from
+    /// [`UnitSystem::from_config`] and the reference state from
+    /// [`UnitConfig::reference_state`].
+    ///
+    /// ```no_run
+    /// use refprop::{Fluid, UnitConfig};
+    ///
+    /// let config = UnitConfig {
+    ///     temperature: Some("degC".into()),
+    ///     basis: Some("mass".into()),
+    ///     reference: Some("ASH".into()),
+    ///     ..UnitConfig::default()
+    /// };
+    /// let r134a = Fluid::with_config("R134A", &config)?;
+    /// # Ok::<(), refprop::RefpropError>(())
+    /// ```
+    pub fn with_config(fluid_name: &str, config: &UnitConfig) -> Result<Self> {
+        let units = UnitSystem::from_config(config)?;
+        let reference = config.reference_state()?;
+        let mut fluid = Self::with_units(fluid_name, units)?;
+        if reference != ReferenceState::Default {
+            fluid.set_reference_state(reference)?;
+        }
+        Ok(fluid)
+    }
+
+    /// Create a pure `Fluid` from the **contents** of a `.FLD` file,
+    /// e.g. one embedded with `include_str!`.
+    ///
+    /// Decouples results from whatever fluid files are installed: the
+    /// definition is written to a private temporary directory (removed
+    /// when the `Fluid` is dropped) and loaded by full path.  The
+    /// REFPROP library itself is still located as usual.
+    ///
+    /// The contents are taken as bytes, so `.FLD` files with Latin-1
+    /// comments load unchanged from `std::fs::read` or `include_bytes!`.
+    /// Failing to write them is reported as [`Io`](RefpropError::Io).
+    ///
+    /// ```no_run
+    /// use refprop::{Fluid, UnitSystem};
+    ///
+    /// const R134A_FLD: &str = "…contents of R134A.FLD…";
+    /// let r134a = Fluid::from_definition(R134A_FLD, UnitSystem::engineering())?;
+    /// # Ok::<(), refprop::RefpropError>(())
+    /// ```
+    pub fn from_definition(fld_contents: impl AsRef<[u8]>, units: UnitSystem) -> Result<Self> {
+        units.validate()?;
+        Self::load_dotenv();
+        let refprop_path = Self::find_refprop_path()?;
+        let backend = RefpropBackend::new_from_definition(fld_contents.as_ref(), &refprop_path)?;
+        let mm = backend.molar_mass_mix()?;
+        let conv = Converter::new(units, mm);
+        Ok(Self {
+            backend,
+            conv,
+            display: DisplayConfig::default(),
+        })
     }
 
     /// Create a **custom mixture** with REFPROP-native units.
@@ -71,13 +269,87 @@ impl Fluid {
     /// )?;
     /// # Ok::<(), refprop::RefpropError>(())
     /// ```
+    ///
+    /// A component without a `.FLD` file fails with
+    /// [`FluidNotFound`](RefpropError::FluidNotFound) naming that
+    /// component, before REFPROP is set up.
     pub fn mixture_with_units(components: &[(&str, f64)], units: UnitSystem) -> Result<Self> {
+        units.validate()?;
         Self::load_dotenv();
         let refprop_path = Self::find_refprop_path()?;
         let backend = RefpropBackend::new_mixture(components, &refprop_path)?;
         let mm = backend.molar_mass_mix()?;
         let conv = Converter::new(units, mm);
-        Ok(Self { backend, conv })
+        Ok(Self {
+            backend,
+            conv,
+            display: DisplayConfig::default(),
+        })
+    }
+
+    /// Create a **custom mixture** from a name → mole-fraction map.
+    ///
+    /// Components are loaded in the map's (alphabetical) key order, so
+    /// the result is deterministic regardless of how the map was built.
+    /// Names must be non-empty and fractions strictly positive.
+    ///
+    /// ```no_run
+    /// use std::collections::BTreeMap;
+    /// use refprop::{Fluid, UnitSystem};
+    ///
+    /// let comp = BTreeMap::from([("R32".to_string(), 0.215), ("R1234YF".to_string(), 0.785)]);
+    /// let r454c = Fluid::mixture_map(&comp, UnitSystem::engineering())?;
+    /// # Ok::<(), refprop::RefpropError>(())
+    /// ```
+    pub fn mixture_map(components: &BTreeMap<String, f64>, units: UnitSystem) -> Result<Self> {
+        for (name, frac) in components {
+            if name.trim().is_empty() {
+                return Err(RefpropError::InvalidInput(
+                    "Component name must not be empty".into(),
+                ));
+            }
+            if !(frac.is_finite() && *frac > 0.0) {
+                return Err(RefpropError::InvalidInput(format!(
+                    "Fraction of {name} must be positive, got {frac}"
+                )));
+            }
+        }
+        let pairs: Vec<(&str, f64)> = components
+            .iter()
+            .map(|(name, frac)| (name.as_str(), *frac))
+            .collect();
+        Self::mixture_with_units(&pairs, units)
+    }
+
+    /// Register a process-wide fluid-name alias.
+    ///
+    /// After `Fluid::with_alias("MYGAS", "NITROGEN")`, `Fluid::new("MYGAS")`
+    /// loads `NITROGEN.FLD`.  Registered aliases take precedence over
+    /// file names and the built-in table (`R290` → `PROPANE`,
+    /// `R600A` → `ISOBUTAN`, `R744` → `CO2`, …).  CAS numbers such as
+    /// `"74-98-6"` are resolved by scanning the `.FLD` headers.
+    pub fn with_alias(name: &str, fld: &str) {
+        crate::backend::alias::register(name, fld);
+    }
+
+    /// Start capturing standard output, including REFPROP's own
+    /// console writes, until the returned guard is finished or dropped.
+    ///
+    /// See [`capture`](crate::capture) for the limitations.  To silence
+    /// only fluid setup globally, use
+    /// [`capture::set_quiet_setup`](crate::capture::set_quiet_setup).
+    pub fn capture_output() -> Result<OutputCapture> {
+        OutputCapture::start()
+    }
+
+    /// Release this fluid and its handle on the REFPROP library.
+    ///
+    /// Equivalent to dropping the `Fluid`: if REFPROP's global state was
+    /// last set up for this fluid it is marked stale, so the next call
+    /// from any other `Fluid` re-runs the setup instead of relying on
+    /// leftovers.  Useful for deterministic test isolation.
+    pub fn close(self) {
+        drop(self);
     }
 
     // ── .env loading (once) ──────────────────────────────────────────
@@ -108,6 +380,29 @@ impl Fluid {
 
     // ── Path discovery ───────────────────────────────────────────────
 
+    /// REFPROP directory baked in at build time from the
+    /// `REFPROP_BUILD_PATH` environment variable, if it was set when
+    /// this crate was compiled.
+    pub fn baked_refprop_path() -> Option<&'static str> {
+        option_env!("REFPROP_BUILD_PATH")
+    }
+
+    /// The REFPROP directory the constructors will use.
+    ///
+    /// Precedence, first existing directory wins:
+    ///
+    /// 1. explicit directories ([`with_paths`](Self::with_paths), which
+    ///    bypasses discovery altogether);
+    /// 2. the `REFPROP_PATH` environment variable (or `.env` file) at
+    ///    run time;
+    /// 3. the path baked in at build time, see
+    ///    [`baked_refprop_path`](Self::baked_refprop_path);
+    /// 4. the platform's standard install locations.
+    pub fn discover_refprop_path() -> Result<String> {
+        Self::load_dotenv();
+        Self::find_refprop_path()
+    }
+
     fn find_refprop_path() -> Result<String> {
         let mut tried = Vec::<String>::new();
 
@@ -118,6 +413,15 @@ impl Fluid {
             tried.push(format!("REFPROP_PATH={path} (directory does not exist)"));
         }
 
+        if let Some(path) = Self::baked_refprop_path() {
+            if Path::new(path).exists() {
+                return Ok(path.to_string());
+            }
+            tried.push(format!(
+                "REFPROP_BUILD_PATH={path} (baked in, does not exist)"
+            ));
+        }
+
         #[cfg(target_os = "windows")]
         let standard_paths = [
             r"C:\Program Files (x86)\REFPROP",
@@ -147,13 +451,38 @@ impl Fluid {
     /// **Generic property lookup** — CoolProp-style.
     ///
     /// All values are in the unit system configured at construction.
+    /// Unrecognised keys yield
+    /// [`UnknownOutputKey`](RefpropError::UnknownOutputKey) or
+    /// [`UnsupportedInputPair`](RefpropError::UnsupportedInputPair).
     ///
     /// ```no_run
     /// # use refprop::{Fluid, UnitSystem};
     /// let f = Fluid::with_units("R134A", UnitSystem::engineering())?;
     /// let d = f.get("D", "T", 0.0, "Q", 100.0)?;  // 0 °C → kg/m³
+    /// let t = f.get("TSAT", "P", 5.0, "Q", 0.0)?; // 5 bar → °C
     /// # Ok::<(), refprop::RefpropError>(())
     /// ```
+    ///
+    /// The saturation outputs `"TSAT"` (at the `"P"` input) and
+    /// `"PSAT"` (at the `"T"` input) ignore the other input, which only
+    /// has to be finite.  Mixtures use the bubble branch; use
+    /// `get("T", "P", p, "Q", 100.0)` or
+    /// [`saturation_report`](Self::saturation_report) for dew-point
+    /// values.
+    ///
+    /// `"DMOLAR"`, `"HMOLAR"`, `"SMOLAR"`, `"UMOLAR"`, `"CVMOLAR"` and
+    /// `"CPMOLAR"` return the molar value in REFPROP units (mol/L,
+    /// J/mol, J/(mol·K)) whatever the configured density, energy and
+    /// entropy units, e.g. for a molar enthalpy while working in kJ/kg.
+    ///
+    /// At two-phase states `"ETA"` and `"TCX"` combine the
+    /// saturated-liquid and vapor values with the
+    /// [two-phase rule](Self::set_two_phase_transport_model) instead of
+    /// evaluating transport at the bulk two-phase density.
+    ///
+    /// `"Q"` is `NaN` at single-phase and supercritical states, where
+    /// REFPROP only reports a sentinel; see
+    /// [`get_quality`](Self::get_quality) for an `Option`.
     pub fn get(&self, output: &str, key1: &str, val1: f64, key2: &str, val2: f64) -> Result<f64> {
         let v1 = self.conv.input_to_rp(key1, val1)?;
         let v2 = self.conv.input_to_rp(key2, val2)?;
@@ -161,6 +490,361 @@ impl Fluid {
         Ok(self.conv.output_from_rp(output, raw))
     }
 
+    /// Several outputs of [`get`](Self::get) at the same state, in the
+    /// order of `outputs`.
+    ///
+    /// The state is flashed once under a single lock acquisition, and
+    /// transport (`"ETA"`, `"TCX"`) is evaluated at most once, at the
+    /// flashed (T, ρ), however many transport keys are listed.
+    ///
+    /// ```no_run
+    /// # use refprop::{Fluid, UnitSystem};
+    /// let r134a = Fluid::with_units("R134A", UnitSystem::engineering())?;
+    /// let v = r134a.get_outputs(&["D", "H", "ETA", "TCX"], "T", 25.0, "P", 1.0)?;
+    /// let (d, h, eta, tcx) = (v[0], v[1], v[2], v[3]);
+    /// # Ok::<(), refprop::RefpropError>(())
+    /// ```
+    pub fn get_outputs(
+        &self,
+        outputs: &[&str],
+        key1: &str,
+        val1: f64,
+        key2: &str,
+        val2: f64,
+    ) -> Result<Vec<f64>> {
+        let v1 = self.conv.input_to_rp(key1, val1)?;
+        let v2 = self.conv.input_to_rp(key2, val2)?;
+        let raw = self.backend.get_outputs(outputs, key1, v1, key2, v2)?;
+        Ok(outputs
+            .iter()
+            .zip(raw)
+            .map(|(output, value)| self.conv.output_from_rp(output, value))
+            .collect())
+    }
+
+    /// Vapor quality (percent) at the state given by two inputs, as for
+    /// [`get`](Self::get): `Some(q)` inside the two-phase dome,
+    /// saturation lines included, and `None` for a single-phase or
+    /// supercritical state.
+    ///
+    /// ```no_run
+    /// # use refprop::{Fluid, UnitSystem};
+    /// let co2 = Fluid::with_units("CO2", UnitSystem::engineering())?;
+    /// assert_eq!(co2.get_quality("T", 50.0, "P", 100.0)?, None); // supercritical
+    /// # Ok::<(), refprop::RefpropError>(())
+    /// ```
+    pub fn get_quality(&self, key1: &str, val1: f64, key2: &str, val2: f64) -> Result<Option<f64>> {
+        let q = self.get("Q", key1, val1, key2, val2)?;
+        Ok((!q.is_nan()).then_some(q))
+    }
+
+    /// [`get`](Self::get) with inputs given as text, each optionally
+    /// carrying its own unit: `get_str("H", "T", "25 degC", "P", "5 bar")`.
+    ///
+    /// A unit in the text overrides the configured input unit for that
+    /// value only; the output is still in the configured units.  See
+    /// [`Converter::parse_input_to_rp`] for the accepted units.
+    ///
+    /// ```no_run
+    /// # use refprop::{Fluid, UnitSystem};
+    /// let f = Fluid::with_units("R134A", UnitSystem::refprop())?;
+    /// let h = f.get_str("H", "T", "25 degC", "P", "5 bar")?; // J/mol
+    /// # Ok::<(), refprop::RefpropError>(())
+    /// ```
+    pub fn get_str(
+        &self,
+        output: &str,
+        key1: &str,
+        val1: &str,
+        key2: &str,
+        val2: &str,
+    ) -> Result<f64> {
+        let v1 = self.conv.parse_input_to_rp(key1, val1)?;
+        let v2 = self.conv.parse_input_to_rp(key2, val2)?;
+        let raw = self.backend.get(output, key1, v1, key2, v2)?;
+        Ok(self.conv.output_from_rp(output, raw))
+    }
+
+    /// `output` on both sides of the dome at saturation temperature
+    /// (`key = "T"`) or pressure (`key = "P"`), returned as
+    /// `(liquid, vapor)` in user units.
+    ///
+    /// Equivalent to `get(output, key, val, "Q", 0.0)` and
+    /// `get(output, key, val, "Q", 100.0)` but with a single saturation
+    /// solve; handy for drawing the saturation dome.  Any output of
+    /// [`get`](Self::get) except `"TSAT"`/`"PSAT"` is accepted.  For
+    /// zeotropic mixtures the liquid is the bubble point and the vapor
+    /// the dew point.
+    ///
+    /// ```no_run
+    /// # use refprop::{Fluid, UnitSystem};
+    /// let f = Fluid::with_units("R134A", UnitSystem::engineering())?;
+    /// let (h_liq, h_vap) = f.get_saturation("H", "T", 0.0)?;
+    /// # Ok::<(), refprop::RefpropError>(())
+    /// ```
+    pub fn get_saturation(&self, output: &str, key: &str, val: f64) -> Result<(f64, f64)> {
+        let v = self.conv.input_to_rp(key, val)?;
+        let (liq, vap) = self.backend.get_saturation(output, key, v)?;
+        Ok((
+            self.conv.output_from_rp(output, liq),
+            self.conv.output_from_rp(output, vap),
+        ))
+    }
+
+    /// [`get`](Self::get) at each point `(vals1[i], vals2[i])`, under a
+    /// single lock acquisition.
+    ///
+    /// Both slices must have the same length.  The first failing point
+    /// aborts the whole batch.
+    pub fn get_batch(
+        &self,
+        output: &str,
+        key1: &str,
+        vals1: &[f64],
+        key2: &str,
+        vals2: &[f64],
+    ) -> Result<Vec<f64>> {
+        if vals1.len() != vals2.len() {
+            return Err(RefpropError::InvalidInput(format!(
+                "get_batch needs inputs of equal length, got {} and {}",
+                vals1.len(),
+                vals2.len()
+            )));
+        }
+        let points = vals1
+            .iter()
+            .zip(vals2)
+            .map(|(&v1, &v2)| {
+                Ok((
+                    self.conv.input_to_rp(key1, v1)?,
+                    self.conv.input_to_rp(key2, v2)?,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let raw = self.backend.get_many(output, key1, key2, &points)?;
+        Ok(raw
+            .into_iter()
+            .map(|v| self.conv.output_from_rp(output, v))
+            .collect())
+    }
+
+    /// [`get`](Self::get) over the full grid `vals1 × vals2`: row `i`
+    /// holds the values at `vals1[i]` for every entry of `vals2`.
+    ///
+    /// Evaluated under a single lock acquisition; the first failing
+    /// point aborts the whole grid.
+    pub fn grid(
+        &self,
+        output: &str,
+        key1: &str,
+        vals1: &[f64],
+        key2: &str,
+        vals2: &[f64],
+    ) -> Result<Vec<Vec<f64>>> {
+        let flat = self.grid_flat(output, key1, vals1, key2, vals2)?;
+        if vals2.is_empty() {
+            return Ok(vec![Vec::new(); vals1.len()]);
+        }
+        Ok(flat.chunks(vals2.len()).map(<[f64]>::to_vec).collect())
+    }
+
+    /// Row-major values of [`grid`](Self::grid).
+    fn grid_flat(
+        &self,
+        output: &str,
+        key1: &str,
+        vals1: &[f64],
+        key2: &str,
+        vals2: &[f64],
+    ) -> Result<Vec<f64>> {
+        let mut points = Vec::with_capacity(vals1.len() * vals2.len());
+        for &v1 in vals1 {
+            let r1 = self.conv.input_to_rp(key1, v1)?;
+            for &v2 in vals2 {
+                points.push((r1, self.conv.input_to_rp(key2, v2)?));
+            }
+        }
+        let raw = self.backend.get_many(output, key1, key2, &points)?;
+        Ok(raw
+            .into_iter()
+            .map(|v| self.conv.output_from_rp(output, v))
+            .collect())
+    }
+
+    /// Tabulate `property` on a uniform (T, P) grid of `nt × np` nodes
+    /// spanning `t_range` × `p_range` (configured units), for fast
+    /// lock-free lookups with [`PropertyTable::interpolate`].
+    ///
+    /// All nodes are flashed under a single lock acquisition; the first
+    /// failing node aborts the build.  See the [`table`](crate::table)
+    /// module for the accuracy/speed tradeoff.
+    pub fn build_table(
+        &self,
+        property: &str,
+        t_range: RangeInclusive<f64>,
+        p_range: RangeInclusive<f64>,
+        nt: usize,
+        np: usize,
+    ) -> Result<PropertyTable> {
+        let temps = PropertyTable::nodes("T", &t_range, nt)?;
+        let pressures = PropertyTable::nodes("P", &p_range, np)?;
+        let values = self.grid_flat(property, "T", &temps, "P", &pressures)?;
+        Ok(PropertyTable::new(property, temps, pressures, values))
+    }
+
+    /// [`get_batch`](Self::get_batch) as an `ndarray::Array1`.
+    #[cfg(feature = "ndarray")]
+    pub fn get_batch_array(
+        &self,
+        output: &str,
+        key1: &str,
+        vals1: &[f64],
+        key2: &str,
+        vals2: &[f64],
+    ) -> Result<ndarray::Array1<f64>> {
+        Ok(ndarray::Array1::from_vec(
+            self.get_batch(output, key1, vals1, key2, vals2)?,
+        ))
+    }
+
+    /// [`grid`](Self::grid) as an `ndarray::Array2` of shape
+    /// `(vals1.len(), vals2.len())`, built without per-row copies.
+    #[cfg(feature = "ndarray")]
+    pub fn grid_array(
+        &self,
+        output: &str,
+        key1: &str,
+        vals1: &[f64],
+        key2: &str,
+        vals2: &[f64],
+    ) -> Result<ndarray::Array2<f64>> {
+        let flat = self.grid_flat(output, key1, vals1, key2, vals2)?;
+        ndarray::Array2::from_shape_vec((vals1.len(), vals2.len()), flat)
+            .map_err(|e| RefpropError::CalculationFailed(format!("Grid shape mismatch: {e}")))
+    }
+
+    /// **Single-input lookup** for saturation values and constants.
+    ///
+    /// * `"PSAT"` (input `"T"`), `"TSAT"` (input `"P"`): saturation
+    ///   pressure / temperature.
+    /// * `"DLIQ"`, `"DVAP"` (input `"T"` or `"P"`): saturated liquid /
+    ///   vapor density.
+    /// * `"TCRIT"`, `"PCRIT"`, `"DCRIT"`, `"M"` (g/mol), `"TTRP"`,
+    ///   `"TNBP"`: constants; `key` and `val` are ignored.
+    ///
+    /// Values are in the configured units.  Mixtures use the bubble
+    /// point for `PSAT`, `TSAT` and `DLIQ`, the dew point for `DVAP`,
+    /// and the first component for `TTRP` / `TNBP`.  Any other output
+    /// is [`InvalidInput`](RefpropError::InvalidInput).
+    ///
+    /// ```no_run
+    /// # use refprop::{Fluid, UnitSystem};
+    /// let f = Fluid::with_units("R134A", UnitSystem::engineering())?;
+    /// let p = f.get1("PSAT", "T", 25.0)?;  // bar
+    /// let tc = f.get1("TCRIT", "", 0.0)?; // °C
+    /// # Ok::<(), refprop::RefpropError>(())
+    /// ```
+    pub fn get1(&self, output: &str, key: &str, val: f64) -> Result<f64> {
+        let v = self.conv.input_to_rp(key, val)?;
+        let raw = self.backend.get1(output, key, v)?;
+        Ok(self.conv.output_from_rp(output, raw))
+    }
+
+    /// Temperature on the isobar `p` at which `target_property` reaches
+    /// `target_value`, in user units.
+    ///
+    /// The inverse of `get(target_property, "T", t, "P", p)`, found by a
+    /// bracketed root search over TP flashes between the triple point
+    /// and three times the critical temperature.  Any [`Property`] key
+    /// except `"T"`, `"P"` and `"Q"` may be targeted.  The property is
+    /// not assumed monotonic: the lowest crossing is returned.  For
+    /// enthalpy, [`props_ph`](Self::props_ph) is the direct route.
+    ///
+    /// ```no_run
+    /// # use refprop::{Fluid, UnitSystem};
+    /// let f = Fluid::with_units("R134A", UnitSystem::engineering())?;
+    /// let t = f.solve_temperature(5.0, "D", 1200.0)?; // °C where ρ = 1200 kg/m³
+    /// # Ok::<(), refprop::RefpropError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// [`InvalidInput`](RefpropError::InvalidInput) for an unknown or
+    /// untargetable key, and
+    /// [`CalculationFailed`](RefpropError::CalculationFailed) when no
+    /// crossing is bracketed in that range, or when the target falls in
+    /// the jump of the property across the phase change (e.g. a density
+    /// between the saturated liquid and vapor values).
+    pub fn solve_temperature(
+        &self,
+        p: f64,
+        target_property: &str,
+        target_value: f64,
+    ) -> Result<f64> {
+        let prop: Property = target_property.parse()?;
+        if matches!(
+            prop,
+            Property::Temperature | Property::Pressure | Property::Quality
+        ) {
+            return Err(RefpropError::InvalidInput(format!(
+                "solve_temperature cannot target {}; it is an input of the isobar search",
+                prop.key()
+            )));
+        }
+        let target = self.conv.to_rp(prop, target_value)?;
+        let t = self
+            .backend
+            .solve_temperature(self.conv.p_to_rp(p), prop.key(), target)?;
+        Ok(self.conv.t_from_rp(t))
+    }
+
+    /// Input pairs accepted by [`get`](Self::get), one entry per pair.
+    ///
+    /// Either order is accepted, keys are case-insensitive and `"D"`
+    /// may also be written `"RHO"`.
+    pub fn supported_input_pairs() -> &'static [(&'static str, &'static str)] {
+        &INPUT_PAIRS
+    }
+
+    /// Output keys accepted by [`get`](Self::get), aliases included
+    /// (e.g. `"D"` and `"RHO"`).
+    pub fn supported_outputs() -> &'static [&'static str] {
+        &OUTPUT_KEYS
+    }
+
+    /// Full states along a path of arbitrary input pairs.
+    ///
+    /// Each point is `(key1, val1, key2, val2)` with the same keys and
+    /// units as [`get`](Self::get); pairs may differ from point to
+    /// point.  All flashes run under a single lock acquisition, and the
+    /// first failing point aborts the whole path.
+    ///
+    /// ```no_run
+    /// # use refprop::{Fluid, UnitSystem};
+    /// let f = Fluid::with_units("R134A", UnitSystem::engineering())?;
+    /// let path = vec![
+    ///     ("T".to_string(), 10.0, "P".to_string(), 3.0),
+    ///     ("P".to_string(), 12.0, "Q".to_string(), 100.0),
+    /// ];
+    /// let states = f.evaluate_path(&path)?;
+    /// # Ok::<(), refprop::RefpropError>(())
+    /// ```
+    pub fn evaluate_path(&self, points: &[(String, f64, String, f64)]) -> Result<Vec<ThermoProp>> {
+        let raw_points = points
+            .iter()
+            .map(|(k1, v1, k2, v2)| {
+                Ok((
+                    k1.clone(),
+                    self.conv.input_to_rp(k1, *v1)?,
+                    k2.clone(),
+                    self.conv.input_to_rp(k2, *v2)?,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let raw = self.backend.evaluate_path(&raw_points)?;
+        Ok(raw.into_iter().map(|s| self.convert_thermo(s)).collect())
+    }
+
     /// Temperature–pressure flash.
     pub fn props_tp(&self, t: f64, p: f64) -> Result<ThermoProp> {
         let raw = self
@@ -169,67 +853,402 @@ impl Fluid {
         Ok(self.convert_thermo(raw))
     }
 
-    /// Pressure–enthalpy flash.
-    pub fn props_ph(&self, p: f64, h: f64) -> Result<ThermoProp> {
+    /// Molar and mass enthalpy of the (T, P) state from one flash,
+    /// returned as `(J/mol, kJ/kg)` **regardless** of the configured
+    /// energy unit (T and P are still in user units).
+    ///
+    /// The mass value uses the molar mass cached at construction
+    /// (mixture-averaged for mixtures).
+    pub fn enthalpy_both(&self, t: f64, p: f64) -> Result<(f64, f64)> {
         let raw = self
             .backend
-            .props_ph(self.conv.p_to_rp(p), self.conv.h_to_rp(h))?;
-        Ok(self.convert_thermo(raw))
+            .props_tp(self.conv.t_to_rp(t), self.conv.p_to_rp(p))?;
+        // J/mol ÷ g/mol = J/g = kJ/kg
+        let h = raw.enthalpy - self.conv.h_offset;
+        Ok((h, h / self.conv.molar_mass))
     }
 
-    /// Pressure–entropy flash.
-    pub fn props_ps(&self, p: f64, s: f64) -> Result<ThermoProp> {
+    /// Flash at (T, P) and scale by `mass_flow` (configured
+    /// [`MassFlowUnit`](crate::MassFlowUnit), kg/s by default).
+    ///
+    /// Enthalpy and entropy flows are built from mass-basis values
+    /// (kJ/kg, kJ/(kg·K)) **regardless** of the configured energy
+    /// units, so [`Stream::enthalpy_flow`] is always in kW and energy
+    /// balances reduce to sums of stream flows.
+    ///
+    /// ```no_run
+    /// # use refprop::{Fluid, UnitSystem};
+    /// let r134a = Fluid::with_units("R134A", UnitSystem::engineering())?;
+    /// let inlet = r134a.stream_tp(5.0, 3.0, 0.1)?;
+    /// let outlet = r134a.stream_tp(60.0, 12.0, 0.1)?;
+    /// let power_kw = outlet.enthalpy_flow - inlet.enthalpy_flow;
+    /// # Ok::<(), refprop::RefpropError>(())
+    /// ```
+    pub fn stream_tp(&self, t: f64, p: f64, mass_flow: f64) -> Result<Stream> {
+        let m = self.conv.mass_flow_to_kg_s(mass_flow);
+        if !m.is_finite() {
+            return Err(RefpropError::InvalidInput(format!(
+                "Mass flow must be finite (got {mass_flow})"
+            )));
+        }
         let raw = self
             .backend
-            .props_ps(self.conv.p_to_rp(p), self.conv.s_to_rp(s))?;
-        Ok(self.convert_thermo(raw))
+            .props_tp(self.conv.t_to_rp(t), self.conv.p_to_rp(p))?;
+        // J/mol ÷ g/mol = kJ/kg, then × kg/s = kW
+        let h = (raw.enthalpy - self.conv.h_offset) / self.conv.molar_mass;
+        let s = (raw.entropy - self.conv.s_offset) / self.conv.molar_mass;
+        let state = self.convert_thermo(raw);
+        Ok(Stream {
+            mass_flow: m,
+            enthalpy_flow: m * h,
+            entropy_flow: m * s,
+            density: state.density,
+            state,
+        })
     }
 
-    /// Temperature–density flash.
-    pub fn props_td(&self, t: f64, d: f64) -> Result<ThermoProp> {
-        let raw = self
-            .backend
-            .props_td(self.conv.t_to_rp(t), self.conv.d_to_rp(d))?;
-        Ok(self.convert_thermo(raw))
+    /// Enthalpy change h_out − h_in between two states, each given as
+    /// a `(key1, val1, key2, val2)` input pair like [`get`](Self::get),
+    /// in the configured energy unit.
+    ///
+    /// Both states are flashed under one lock with the same reference
+    /// state, so the difference does not depend on
+    /// [`rezero_reference`](Self::rezero_reference).
+    ///
+    /// ```no_run
+    /// # use refprop::{Fluid, UnitSystem};
+    /// let r134a = Fluid::with_units("R134A", UnitSystem::engineering())?;
+    /// // Evaporator outlet → 10 K superheat at 3 bar (kJ/kg)
+    /// let dh = r134a.enthalpy_difference(("P", 3.0, "Q", 100.0), ("P", 3.0, "T", 10.7))?;
+    /// # Ok::<(), refprop::RefpropError>(())
+    /// ```
+    pub fn enthalpy_difference(
+        &self,
+        inlet: (&str, f64, &str, f64),
+        outlet: (&str, f64, &str, f64),
+    ) -> Result<f64> {
+        Ok(self
+            .conv
+            .h_from_rp(self.enthalpy_difference_rp(inlet, outlet)?))
     }
 
-    /// Temperature–enthalpy flash.
-    pub fn props_th(&self, t: f64, h: f64) -> Result<ThermoProp> {
+    /// Heat duty ṁ·(h_out − h_in) in kW for `mass_flow` in the
+    /// configured [`MassFlowUnit`](crate::MassFlowUnit), whatever the
+    /// configured energy unit.  Positive when the fluid is heated.
+    pub fn heat_duty(
+        &self,
+        inlet: (&str, f64, &str, f64),
+        outlet: (&str, f64, &str, f64),
+        mass_flow: f64,
+    ) -> Result<f64> {
+        let m = self.conv.mass_flow_to_kg_s(mass_flow);
+        if !m.is_finite() {
+            return Err(RefpropError::InvalidInput(format!(
+                "Mass flow must be finite (got {mass_flow})"
+            )));
+        }
+        // J/mol ÷ g/mol = kJ/kg, then × kg/s = kW
+        Ok(m * self.enthalpy_difference_rp(inlet, outlet)? / self.conv.molar_mass)
+    }
+
+    /// h_out − h_in in J/mol.
+    fn enthalpy_difference_rp(
+        &self,
+        inlet: (&str, f64, &str, f64),
+        outlet: (&str, f64, &str, f64),
+    ) -> Result<f64> {
+        let points = [inlet, outlet]
+            .iter()
+            .map(|&(k1, v1, k2, v2)| {
+                Ok((
+                    k1.to_string(),
+                    self.conv.input_to_rp(k1, v1)?,
+                    k2.to_string(),
+                    self.conv.input_to_rp(k2, v2)?,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let states = self.backend.evaluate_path(&points)?;
+        Ok(states[1].enthalpy - states[0].enthalpy)
+    }
+
+    /// Molar density (mol/L) of the (T, P) state, **regardless** of the
+    /// configured density unit (T and P are still in user units).
+    pub fn density_molar_tp(&self, t: f64, p: f64) -> Result<f64> {
         let raw = self
             .backend
-            .props_th(self.conv.t_to_rp(t), self.conv.h_to_rp(h))?;
-        Ok(self.convert_thermo(raw))
+            .props_tp(self.conv.t_to_rp(t), self.conv.p_to_rp(p))?;
+        Ok(raw.density)
     }
 
-    /// Temperature–entropy flash.
-    pub fn props_ts(&self, t: f64, s: f64) -> Result<ThermoProp> {
+    /// Mass density (kg/m³) of the (T, P) state, **regardless** of the
+    /// configured density unit, using the molar mass cached at
+    /// construction (mixture-averaged for mixtures).
+    pub fn density_mass_tp(&self, t: f64, p: f64) -> Result<f64> {
+        // mol/L × g/mol = g/L = kg/m³
+        Ok(self.density_molar_tp(t, p)? * self.conv.molar_mass)
+    }
+
+    /// All states at (T, P), with deterministic root selection.
+    ///
+    /// Returns a single state for a single-phase point.  When (T, P)
+    /// lies on the saturation line, returns **both** the saturated
+    /// liquid (`[0]`, Q = 0) and saturated vapor (`[1]`, Q = 100)
+    /// states instead of whichever root the TP flash happens to pick.
+    ///
+    /// # Errors
+    ///
+    /// [`InvalidInput`](RefpropError::InvalidInput) for a mixture
+    /// inside its two-phase region, whose equilibrium phases differ in
+    /// composition from the feed; use
+    /// [`flash_separator`](Self::flash_separator) there.
+    pub fn states_tp(&self, t: f64, p: f64) -> Result<Vec<ThermoProp>> {
         let raw = self
             .backend
-            .props_ts(self.conv.t_to_rp(t), self.conv.s_to_rp(s))?;
-        Ok(self.convert_thermo(raw))
+            .states_tp(self.conv.t_to_rp(t), self.conv.p_to_rp(p))?;
+        Ok(raw.into_iter().map(|s| self.convert_thermo(s)).collect())
     }
 
-    /// Pressure–density flash.
-    pub fn props_pd(&self, p: f64, d: f64) -> Result<ThermoProp> {
-        let raw = self
+    /// Split the feed at (T, P) into the liquid and vapor streams of a
+    /// flash separator.
+    ///
+    /// One `TPFLSHdll` call gives the molar vapor fraction and the
+    /// phase densities and compositions; each stream is then evaluated
+    /// at its own density and composition, and converted with its own
+    /// molar mass in mass-based units.  Stream qualities are 0 (liquid)
+    /// and 100 % (vapor).  A single-phase feed returns one stream with a
+    /// vapor fraction of 0 or 1; supercritical feeds count as vapor.
+    ///
+    /// ```no_run
+    /// # use refprop::{Fluid, UnitSystem};
+    /// let r407c = Fluid::with_units("R407C", UnitSystem::engineering())?;
+    /// let sep = r407c.flash_separator(0.0, 4.0)?;
+    /// if let (Some(liq), Some(vap)) = (&sep.liquid, &sep.vapor) {
+    ///     let beta = sep.vapor_fraction;
+    ///     println!("β = {beta:.3}, ρ_L = {:.1}, ρ_V = {:.2}", liq.density, vap.density);
+    /// }
+    /// # Ok::<(), refprop::RefpropError>(())
+    /// ```
+    pub fn flash_separator(&self, t: f64, p: f64) -> Result<SeparatorResult> {
+        let (raw, (m_liq, m_vap)) = self
             .backend
-            .props_pd(self.conv.p_to_rp(p), self.conv.d_to_rp(d))?;
-        Ok(self.convert_thermo(raw))
+            .flash_separator(self.conv.t_to_rp(t), self.conv.p_to_rp(p))?;
+        let convert = |props: Option<ThermoProp>, molar_mass: f64| {
+            let conv = Converter {
+                molar_mass,
+                ..self.conv.clone()
+            };
+            props.map(|raw| Self::convert_thermo_with(&conv, raw))
+        };
+        Ok(SeparatorResult {
+            vapor_fraction: raw.vapor_fraction,
+            liquid: convert(raw.liquid, m_liq),
+            vapor: convert(raw.vapor, m_vap),
+            liquid_composition: raw.liquid_composition,
+            vapor_composition: raw.vapor_composition,
+        })
     }
 
-    /// Density–enthalpy flash.
-    pub fn props_dh(&self, d: f64, h: f64) -> Result<ThermoProp> {
+    /// Pressure–enthalpy flash.
+    pub fn props_ph(&self, p: f64, h: f64) -> Result<ThermoProp> {
         let raw = self
             .backend
-            .props_dh(self.conv.d_to_rp(d), self.conv.h_to_rp(h))?;
+            .props_ph(self.conv.p_to_rp(p), self.conv.enthalpy_to_rp(h))?;
         Ok(self.convert_thermo(raw))
     }
 
-    /// Density–entropy flash.
-    pub fn props_ds(&self, d: f64, s: f64) -> Result<ThermoProp> {
+    /// Pressure–enthalpy flash with the enthalpy in **J/mol**
+    /// regardless of the configured energy unit (P is still in user
+    /// units, and the result is in the configured units).
+    ///
+    /// For streams defined on a molar basis, without a second
+    /// molar-configured `Fluid`.  `h_molar` is on the same reference as
+    /// the `"HMOLAR"` output of [`get`](Self::get) and the molar value
+    /// of [`enthalpy_both`](Self::enthalpy_both), rezero shift
+    /// included.
+    pub fn props_ph_molar(&self, p: f64, h_molar: f64) -> Result<ThermoProp> {
         let raw = self
             .backend
-            .props_ds(self.conv.d_to_rp(d), self.conv.s_to_rp(s))?;
+            .props_ph(self.conv.p_to_rp(p), h_molar + self.conv.h_offset)?;
+        Ok(self.convert_thermo(raw))
+    }
+
+    /// Vapor quality of the (P, H) state, e.g. the flash-gas fraction
+    /// after an isenthalpic expansion.
+    ///
+    /// Returns `Some(q)` in **percent** (0–100) inside the two-phase
+    /// dome, including the saturation lines, and `None` for a
+    /// single-phase state (subcooled liquid, superheated vapor or
+    /// supercritical), where REFPROP reports an out-of-range sentinel.
+    pub fn quality_ph(&self, p: f64, h: f64) -> Result<Option<f64>> {
+        let raw = self
+            .backend
+            .props_ph(self.conv.p_to_rp(p), self.conv.enthalpy_to_rp(h))?;
+        Ok(self.two_phase_quality(raw.quality))
+    }
+
+    /// Vapor quality of the (T, H) state.
+    ///
+    /// Same semantics as [`quality_ph`](Self::quality_ph): `Some(q)` in
+    /// percent inside the dome, `None` for a single-phase state.
+    pub fn quality_th(&self, t: f64, h: f64) -> Result<Option<f64>> {
+        let raw = self
+            .backend
+            .props_th(self.conv.t_to_rp(t), self.conv.enthalpy_to_rp(h))?;
+        Ok(self.two_phase_quality(raw.quality))
+    }
+
+    /// Isothermal derivative (∂H/∂P)_T at (T, P), in user energy per
+    /// user pressure unit (e.g. kJ/kg/bar).
+    ///
+    /// REFPROP's analytic derivatives are not bound, so this uses a
+    /// central finite difference of two TP flashes at `p ± 1e-4·p`.
+    /// For pure fluids the stencil never crosses the saturation
+    /// pressure: near it a one-sided difference on the side of `p` is
+    /// used.  Meaningless inside the two-phase dome.
+    pub fn dhdp_t(&self, t: f64, p: f64) -> Result<f64> {
+        let (dhdp, _) = self
+            .backend
+            .p_derivatives_t(self.conv.t_to_rp(t), self.conv.p_to_rp(p))?;
+        Ok(dhdp * self.conv.h_from_rp(1.0) / self.conv.p_from_rp(1.0))
+    }
+
+    /// Isothermal compressibility derivative (∂ρ/∂P)_T at (T, P), in
+    /// user density per user pressure unit (e.g. kg/m³/bar).
+    ///
+    /// Computed numerically like [`dhdp_t`](Self::dhdp_t); positive
+    /// for every stable single-phase state.
+    pub fn drhodp_t(&self, t: f64, p: f64) -> Result<f64> {
+        let (_, dddp) = self
+            .backend
+            .p_derivatives_t(self.conv.t_to_rp(t), self.conv.p_to_rp(p))?;
+        Ok(dddp * self.conv.d_from_rp(1.0) / self.conv.p_from_rp(1.0))
+    }
+
+    /// Isothermal bulk modulus K_T = ρ·(∂P/∂ρ)_T at (T, P), in the user
+    /// pressure unit.
+    ///
+    /// The derivative is analytic (`DPDDdll`).  Two-phase states are
+    /// rejected with [`InvalidInput`](RefpropError::InvalidInput).
+    pub fn bulk_modulus_isothermal(&self, t: f64, p: f64) -> Result<f64> {
+        let (k_t, _) = self
+            .backend
+            .bulk_moduli(self.conv.t_to_rp(t), self.conv.p_to_rp(p))?;
+        Ok(self.conv.p_from_rp(k_t))
+    }
+
+    /// Isentropic bulk modulus K_s = ρ·w² at (T, P), in the user
+    /// pressure unit, from the density and speed of sound of the flash.
+    ///
+    /// K_s = γ·K_T ≥ K_T; this is the modulus that sets pressure-wave
+    /// speeds (water hammer, acoustics).  Two-phase states are rejected
+    /// like in [`bulk_modulus_isothermal`](Self::bulk_modulus_isothermal).
+    pub fn bulk_modulus_isentropic(&self, t: f64, p: f64) -> Result<f64> {
+        let (_, k_s) = self
+            .backend
+            .bulk_moduli(self.conv.t_to_rp(t), self.conv.p_to_rp(p))?;
+        Ok(self.conv.p_from_rp(k_s))
+    }
+
+    /// Pressure–entropy flash.
+    pub fn props_ps(&self, p: f64, s: f64) -> Result<ThermoProp> {
+        let raw = self
+            .backend
+            .props_ps(self.conv.p_to_rp(p), self.conv.entropy_to_rp(s))?;
+        Ok(self.convert_thermo(raw))
+    }
+
+    /// Pressure–entropy flash with the entropy in **J/(mol·K)**
+    /// regardless of the configured entropy unit; the molar counterpart
+    /// of [`props_ps`](Self::props_ps), like
+    /// [`props_ph_molar`](Self::props_ph_molar).
+    pub fn props_ps_molar(&self, p: f64, s_molar: f64) -> Result<ThermoProp> {
+        let raw = self
+            .backend
+            .props_ps(self.conv.p_to_rp(p), s_molar + self.conv.s_offset)?;
+        Ok(self.convert_thermo(raw))
+    }
+
+    /// Temperature–density flash.
+    pub fn props_td(&self, t: f64, d: f64) -> Result<ThermoProp> {
+        let raw = self
+            .backend
+            .props_td(self.conv.t_to_rp(t), self.conv.d_to_rp(d))?;
+        Ok(self.convert_thermo(raw))
+    }
+
+    /// Direct equation-of-state evaluation at (T, D) via `THERMdll`.
+    ///
+    /// **Assumes single phase, no two-phase check.**  Unlike
+    /// [`props_td`](Self::props_td) there is no flash iteration, which
+    /// makes this much faster in tight loops where the state is known
+    /// to be single-phase.  Inside the dome the result describes a
+    /// metastable or unstable single-phase state, not the equilibrium
+    /// mixture.  `quality` is always `NaN`.  A density the equation of
+    /// state cannot evaluate gives
+    /// [`CalculationFailed`](RefpropError::CalculationFailed) rather
+    /// than NaN-filled properties.
+    pub fn props_td_direct(&self, t: f64, d: f64) -> Result<ThermoProp> {
+        let raw = self
+            .backend
+            .props_td_direct(self.conv.t_to_rp(t), self.conv.d_to_rp(d))?;
+        Ok(self.convert_thermo(raw))
+    }
+
+    /// Pressure at (T, D) from `PRESSdll`, in user units.
+    ///
+    /// The cheapest pressure evaluation available: one call to the
+    /// equation of state, with neither the flash of
+    /// [`props_td`](Self::props_td) nor the other properties of
+    /// [`props_td_direct`](Self::props_td_direct), e.g. inside a Newton
+    /// iteration on density.  Like `props_td_direct` it **assumes a
+    /// single phase**: inside the dome it returns the (metastable or
+    /// unstable) equation-of-state pressure, not the saturation
+    /// pressure.
+    pub fn pressure_td(&self, t: f64, d: f64) -> Result<f64> {
+        let p = self
+            .backend
+            .pressure_td(self.conv.t_to_rp(t), self.conv.d_to_rp(d))?;
+        Ok(self.conv.p_from_rp(p))
+    }
+
+    /// Temperature–enthalpy flash.
+    pub fn props_th(&self, t: f64, h: f64) -> Result<ThermoProp> {
+        let raw = self
+            .backend
+            .props_th(self.conv.t_to_rp(t), self.conv.enthalpy_to_rp(h))?;
+        Ok(self.convert_thermo(raw))
+    }
+
+    /// Temperature–entropy flash.
+    pub fn props_ts(&self, t: f64, s: f64) -> Result<ThermoProp> {
+        let raw = self
+            .backend
+            .props_ts(self.conv.t_to_rp(t), self.conv.entropy_to_rp(s))?;
+        Ok(self.convert_thermo(raw))
+    }
+
+    /// Pressure–density flash.
+    pub fn props_pd(&self, p: f64, d: f64) -> Result<ThermoProp> {
+        let raw = self
+            .backend
+            .props_pd(self.conv.p_to_rp(p), self.conv.d_to_rp(d))?;
+        Ok(self.convert_thermo(raw))
+    }
+
+    /// Density–enthalpy flash.
+    pub fn props_dh(&self, d: f64, h: f64) -> Result<ThermoProp> {
+        let raw = self
+            .backend
+            .props_dh(self.conv.d_to_rp(d), self.conv.enthalpy_to_rp(h))?;
+        Ok(self.convert_thermo(raw))
+    }
+
+    /// Density–entropy flash.
+    pub fn props_ds(&self, d: f64, s: f64) -> Result<ThermoProp> {
+        let raw = self
+            .backend
+            .props_ds(self.conv.d_to_rp(d), self.conv.entropy_to_rp(s))?;
         Ok(self.convert_thermo(raw))
     }
 
@@ -237,13 +1256,46 @@ impl Fluid {
     pub fn props_hs(&self, h: f64, s: f64) -> Result<ThermoProp> {
         let raw = self
             .backend
-            .props_hs(self.conv.h_to_rp(h), self.conv.s_to_rp(s))?;
+            .props_hs(self.conv.enthalpy_to_rp(h), self.conv.entropy_to_rp(s))?;
+        Ok(self.convert_thermo(raw))
+    }
+
+    /// Enthalpy–entropy flash on the requested branch.
+    ///
+    /// (H, S) can have several solutions near the dome, and
+    /// [`props_hs`](Self::props_hs) may return any of them.  Here the
+    /// density search is restricted to the side of the critical density
+    /// that matches `phase`, and the result is checked against the hint:
+    /// if the converged state is not of the requested phase an error is
+    /// returned instead of a state on the wrong branch.
+    pub fn props_hs_hint(&self, h: f64, s: f64, phase: Phase) -> Result<ThermoProp> {
+        let raw = self.backend.props_hs_hint(
+            self.conv.enthalpy_to_rp(h),
+            self.conv.entropy_to_rp(s),
+            phase,
+        )?;
         Ok(self.convert_thermo(raw))
     }
 
+    /// Flash once at (T, P) and keep the result as a [`StatePoint`].
+    ///
+    /// Reading several properties from the state point costs a single
+    /// flash; transport properties are evaluated at the resolved
+    /// (T, D) on first access.
+    pub fn at_tp(&self, t: f64, p: f64) -> Result<StatePoint<'_>> {
+        Ok(StatePoint::new(self, self.props_tp(t, p)?))
+    }
+
     /// Temperature–quality flash.
     ///
-    /// Quality `q` is in **percent** (0–100).
+    /// Quality `q` is in **percent** (0–100).  Mixtures use the bubble
+    /// curve below 50 % and the dew curve from 50 %; see
+    /// [`props_tq_kph`](Self::props_tq_kph) to choose.
+    ///
+    /// `q = 0` and `q = 100` are the saturated liquid and vapor
+    /// themselves; values outside 0–100 return
+    /// [`InvalidInput`](RefpropError::InvalidInput) rather than being
+    /// clamped to the nearest saturated state.
     pub fn props_tq(&self, t: f64, q: f64) -> Result<ThermoProp> {
         let raw = self
             .backend
@@ -253,7 +1305,8 @@ impl Fluid {
 
     /// Pressure–quality flash.
     ///
-    /// Quality `q` is in **percent** (0–100).
+    /// Quality `q` is in **percent** (0–100), with the same boundary
+    /// behavior as [`props_tq`](Self::props_tq).
     pub fn props_pq(&self, p: f64, q: f64) -> Result<ThermoProp> {
         let raw = self
             .backend
@@ -261,19 +1314,800 @@ impl Fluid {
         Ok(self.convert_thermo(raw))
     }
 
+    /// [`props_tq`](Self::props_tq) with the saturation branch forced:
+    /// `kph = 1` takes the pressure and saturated densities from the
+    /// bubble curve, `kph = 2` from the dew curve.
+    ///
+    /// `props_tq` picks the bubble branch for Q < 50 % and the dew
+    /// branch above, so for zeotropic mixtures its results jump at
+    /// Q = 50 %.  Fixing `kph` keeps a sweep over Q on one branch.
+    /// Pure fluids give the same state for both values.
+    ///
+    /// ```no_run
+    /// # use refprop::{Fluid, UnitSystem};
+    /// let r407c = Fluid::with_units("R407C", UnitSystem::engineering())?;
+    /// let bubble_side = r407c.props_tq_kph(0.0, 50.0, 1)?;
+    /// let dew_side = r407c.props_tq_kph(0.0, 50.0, 2)?;
+    /// # Ok::<(), refprop::RefpropError>(())
+    /// ```
+    pub fn props_tq_kph(&self, t: f64, q: f64, kph: i32) -> Result<ThermoProp> {
+        let raw = self
+            .backend
+            .props_tq_kph(self.conv.t_to_rp(t), self.conv.q_to_rp(q)?, kph)?;
+        Ok(self.convert_thermo(raw))
+    }
+
+    /// [`props_pq`](Self::props_pq) with the saturation branch forced,
+    /// see [`props_tq_kph`](Self::props_tq_kph).
+    pub fn props_pq_kph(&self, p: f64, q: f64, kph: i32) -> Result<ThermoProp> {
+        let raw = self
+            .backend
+            .props_pq_kph(self.conv.p_to_rp(p), self.conv.q_to_rp(q)?, kph)?;
+        Ok(self.convert_thermo(raw))
+    }
+
     /// Saturation properties at a given pressure.
     pub fn saturation_p(&self, p: f64) -> Result<SaturationProps> {
         let raw = self.backend.saturation_p(self.conv.p_to_rp(p))?;
         Ok(self.convert_sat(raw))
     }
 
+    /// Bubble point at pressure `p`: the temperature at which the
+    /// liquid of the bulk composition starts to boil, and the
+    /// composition of the first vapor bubble (`y` from `SATPdll`).
+    ///
+    /// ```no_run
+    /// # use refprop::{Fluid, UnitSystem};
+    /// let r407c = Fluid::with_units("R407C", UnitSystem::engineering())?;
+    /// let bubble = r407c.bubble_point(10.0)?;
+    /// println!("T = {:.2} °C, y = {:?}", bubble.temperature, bubble.incipient_composition);
+    /// # Ok::<(), refprop::RefpropError>(())
+    /// ```
+    pub fn bubble_point(&self, p: f64) -> Result<PhaseBoundary> {
+        self.phase_boundary(p, 1)
+    }
+
+    /// Dew point at pressure `p`: the temperature at which the vapor of
+    /// the bulk composition starts to condense, and the composition of
+    /// the first liquid drop (`x` from `SATPdll`).
+    ///
+    /// For pure fluids both points coincide, with an incipient
+    /// composition of `[1.0]`.
+    pub fn dew_point(&self, p: f64) -> Result<PhaseBoundary> {
+        let kph = if self.backend.component_names().len() > 1 {
+            2
+        } else {
+            1
+        };
+        self.phase_boundary(p, kph)
+    }
+
+    /// `SATPdll` at `p` on the bubble (`kph = 1`) or dew (`kph = 2`)
+    /// line, in user units.
+    fn phase_boundary(&self, p: f64, kph: i32) -> Result<PhaseBoundary> {
+        let (sat, incipient) = self
+            .backend
+            .saturation_p_incipient(self.conv.p_to_rp(p), kph)?;
+        Ok(PhaseBoundary {
+            temperature: self.conv.t_from_rp(sat.temperature),
+            pressure: self.conv.p_from_rp(sat.pressure),
+            incipient_composition: incipient,
+        })
+    }
+
+    /// Bubble-point temperature at pressure `p` (the saturation
+    /// temperature for pure fluids).
+    pub fn bubble_temperature_at(&self, p: f64) -> Result<f64> {
+        Ok(self.bubble_point(p)?.temperature)
+    }
+
+    /// Dew-point temperature at pressure `p` (the saturation
+    /// temperature for pure fluids).
+    pub fn dew_temperature_at(&self, p: f64) -> Result<f64> {
+        Ok(self.dew_point(p)?.temperature)
+    }
+
+    /// How far (T, P) lies from the bubble and dew lines at pressure
+    /// `p`, as temperature margins in the configured unit.
+    ///
+    /// ```no_run
+    /// # use refprop::{Fluid, UnitSystem};
+    /// let r134a = Fluid::with_units("R134A", UnitSystem::engineering())?;
+    /// let d = r134a.distance_to_saturation(20.0, 3.0)?;
+    /// println!("{:.1} K of superheat", d.dew_margin);
+    /// # Ok::<(), refprop::RefpropError>(())
+    /// ```
+    pub fn distance_to_saturation(&self, t: f64, p: f64) -> Result<SaturationDistance> {
+        let bubble_temperature = self.bubble_temperature_at(p)?;
+        let dew_temperature = if self.backend.component_names().len() > 1 {
+            self.dew_temperature_at(p)?
+        } else {
+            bubble_temperature
+        };
+        Ok(SaturationDistance {
+            bubble_temperature,
+            dew_temperature,
+            bubble_margin: bubble_temperature - t,
+            dew_margin: t - dew_temperature,
+        })
+    }
+
+    /// Saturation state whose saturated-liquid or saturated-vapor
+    /// density is `d` (configured density unit), from `SATDdll`.
+    ///
+    /// The returned [`Phase`] is [`Liquid`](Phase::Liquid) when `d` is
+    /// the liquid density of that state and [`Vapor`](Phase::Vapor)
+    /// when it is the vapor density.  Useful when starting from a
+    /// measured density rather than T or P.
+    ///
+    /// ```no_run
+    /// # use refprop::{Fluid, Phase, UnitSystem};
+    /// let r134a = Fluid::with_units("R134A", UnitSystem::engineering())?;
+    /// let (sat, phase) = r134a.saturation_d(1200.0)?;
+    /// assert_eq!(phase, Phase::Liquid);
+    /// println!("T_sat = {:.2} °C", sat.temperature);
+    /// # Ok::<(), refprop::RefpropError>(())
+    /// ```
+    pub fn saturation_d(&self, d: f64) -> Result<(SaturationProps, Phase)> {
+        let (raw, phase) = self.backend.saturation_d(self.conv.d_to_rp(d))?;
+        Ok((self.convert_sat(raw), phase))
+    }
+
     /// Saturation properties at a given temperature.
     pub fn saturation_t(&self, t: f64) -> Result<SaturationProps> {
         let raw = self.backend.saturation_t(self.conv.t_to_rp(t))?;
         Ok(self.convert_sat(raw))
     }
 
+    /// Saturation at `t` (user units) with the enthalpies of both
+    /// phases and the slope dP/dT of the vapor-pressure curve.
+    ///
+    /// One call returns everything a Clausius–Clapeyron cross-check
+    /// needs; see [`SaturationPropsExt`].  The slope comes from
+    /// DPTSATKdll for pure fluids when the library exports it, and from
+    /// a finite difference of saturation pressures otherwise.  For
+    /// mixtures the pressure and slope are those of the bubble curve,
+    /// the liquid is at the bubble point and the vapor at the dew
+    /// point.
+    ///
+    /// ```no_run
+    /// # use refprop::{Fluid, UnitSystem};
+    /// let r134a = Fluid::with_units("R134A", UnitSystem::engineering())?;
+    /// let sat = r134a.saturation_t_ext(0.0)?;
+    /// let dpdt = sat.dpdt_sat; // bar/K
+    /// # Ok::<(), refprop::RefpropError>(())
+    /// ```
+    pub fn saturation_t_ext(&self, t: f64) -> Result<SaturationPropsExt> {
+        let raw = self.backend.saturation_t_ext(self.conv.t_to_rp(t))?;
+        // Differences, so unit offsets (°C, °F) cancel.
+        let dp = self.conv.p_from_rp(raw.dpdt_sat) - self.conv.p_from_rp(0.0);
+        let dt = self.conv.t_from_rp(1.0) - self.conv.t_from_rp(0.0);
+        Ok(SaturationPropsExt {
+            temperature: self.conv.t_from_rp(raw.temperature),
+            pressure: self.conv.p_from_rp(raw.pressure),
+            density_liquid: self.conv.d_from_rp(raw.density_liquid),
+            density_vapor: self.conv.d_from_rp(raw.density_vapor),
+            enthalpy_liquid: self.conv.enthalpy_from_rp(raw.enthalpy_liquid),
+            enthalpy_vapor: self.conv.enthalpy_from_rp(raw.enthalpy_vapor),
+            dpdt_sat: dp / dt,
+        })
+    }
+
+    /// Fit an Antoine correlation ln P = A − B / (T + C) to the
+    /// saturation curve, from `n` saturation pressures at temperatures
+    /// evenly spaced from `t_min` to `t_max` (user units, both
+    /// included).
+    ///
+    /// The samples come from one lock acquisition; the least-squares fit
+    /// on ln P is then pure Rust (see [`AntoineFit`]), and the result
+    /// can be evaluated without REFPROP.  For mixtures the bubble
+    /// pressure is fitted.
+    ///
+    /// ```no_run
+    /// # use refprop::{Fluid, UnitSystem};
+    /// let r134a = Fluid::with_units("R134A", UnitSystem::engineering())?;
+    /// let fit = r134a.fit_vapor_pressure(-40.0, 80.0, 61)?;
+    /// let p = fit.pressure(25.0); // bar
+    /// # Ok::<(), refprop::RefpropError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// [`InvalidInput`](RefpropError::InvalidInput) when `n < 3` or the
+    /// range is empty, and
+    /// [`CalculationFailed`](RefpropError::CalculationFailed) when the
+    /// samples cannot be fitted.
+    pub fn fit_vapor_pressure(&self, t_min: f64, t_max: f64, n: usize) -> Result<AntoineFit> {
+        if n < 3 {
+            return Err(RefpropError::InvalidInput(format!(
+                "An Antoine fit needs at least 3 points, got {n}"
+            )));
+        }
+        let temps = PropertyTable::nodes("T", &(t_min..=t_max), n)?;
+        let raw_temps: Vec<f64> = temps.iter().map(|&t| self.conv.t_to_rp(t)).collect();
+        let pressures: Vec<f64> = self
+            .backend
+            .saturation_t_many(&raw_temps)?
+            .into_iter()
+            .map(|sat| self.conv.p_from_rp(sat.pressure))
+            .collect();
+        AntoineFit::fit(&temps, &pressures).ok_or_else(|| {
+            RefpropError::CalculationFailed(format!(
+                "Antoine fit failed between T = {t_min} and {t_max}"
+            ))
+        })
+    }
+
+    /// Lazy sweep of saturation states from just above the triple
+    /// point to just below the critical point, in `n` evenly spaced
+    /// temperature steps (ends included).
+    ///
+    /// The end points are pulled in by 0.1 % of the range so both
+    /// solves converge.  Each item takes the REFPROP lock on its own;
+    /// use [`collect_saturation`](Self::collect_saturation) to compute
+    /// the whole table under a single lock.  If the range itself cannot
+    /// be determined, the iterator yields that single error.
+    pub fn saturation_iter(&self, n: usize) -> impl Iterator<Item = Result<SaturationProps>> + '_ {
+        let (temps, range_err) = match self.saturation_sweep_temps(n) {
+            Ok(temps) => (temps, None),
+            Err(e) => (Vec::new(), Some(Err(e))),
+        };
+        range_err.into_iter().chain(temps.into_iter().map(|t| {
+            let raw = self.backend.saturation_t(t)?;
+            Ok(self.convert_sat(raw))
+        }))
+    }
+
+    /// Same sweep as [`saturation_iter`](Self::saturation_iter), computed
+    /// eagerly under one lock acquisition.
+    pub fn collect_saturation(&self, n: usize) -> Result<Vec<SaturationProps>> {
+        let temps = self.saturation_sweep_temps(n)?;
+        let raw = self.backend.saturation_t_many(&temps)?;
+        Ok(raw.into_iter().map(|sat| self.convert_sat(sat)).collect())
+    }
+
+    /// Joule-Thomson inversion curve as `(T, P)` points in user units.
+    ///
+    /// Each point is where μ_JT = (∂T/∂P)_H changes sign on one of
+    /// `n_points` isochores spaced from the dilute-gas limit to three
+    /// times the critical density; throttling cools the fluid only
+    /// inside the curve.  The curve ends where it meets the saturated
+    /// liquid, so it can hold fewer than `n_points` points.
+    pub fn jt_inversion_curve(&self, n_points: usize) -> Result<Vec<(f64, f64)>> {
+        let raw = self.backend.jt_inversion_curve(n_points)?;
+        Ok(raw
+            .into_iter()
+            .map(|(t, p)| (self.conv.t_from_rp(t), self.conv.p_from_rp(p)))
+            .collect())
+    }
+
+    /// Maximum Joule-Thomson inversion temperature, in user units.
+    ///
+    /// Above it, throttling never cools the fluid whatever the
+    /// pressure.  Taken from the dilute-gas end of
+    /// [`jt_inversion_curve`](Self::jt_inversion_curve).  The curve is
+    /// computed on the first call of this or
+    /// [`maximum_inversion_pressure`](Self::maximum_inversion_pressure)
+    /// and its maxima are kept for later calls.
+    pub fn maximum_inversion_temperature(&self) -> Result<f64> {
+        let (t_max, _) = self.backend.jt_inversion_maximum()?;
+        Ok(self.conv.t_from_rp(t_max))
+    }
+
+    /// Maximum Joule-Thomson inversion pressure, in user units.
+    ///
+    /// Above it, throttling heats the fluid at any temperature.  Taken
+    /// as the highest point of
+    /// [`jt_inversion_curve`](Self::jt_inversion_curve), evaluated on
+    /// a fine density grid, shared with
+    /// [`maximum_inversion_temperature`](Self::maximum_inversion_temperature).
+    pub fn maximum_inversion_pressure(&self) -> Result<f64> {
+        let (_, p_max) = self.backend.jt_inversion_maximum()?;
+        Ok(self.conv.p_from_rp(p_max))
+    }
+
+    /// Liquid and vapor spinodal curves, where (∂P/∂ρ)_T = 0, at the
+    /// same `n` temperatures as [`saturation_iter`](Self::saturation_iter).
+    ///
+    /// For each temperature the density is pushed from the saturated
+    /// liquid (resp. vapor) density into the dome until `DPDDdll`
+    /// changes sign, and the root is refined by bisection; the pressure
+    /// then comes from `THERMdll`.  These states are metastable or
+    /// unstable by construction.  Points are `(T, P)` in user units, in
+    /// increasing temperature, and both branches converge to the
+    /// critical point.  Intended for pure fluids: for mixtures the
+    /// curves are evaluated at fixed bulk composition from the bubble
+    /// densities.
+    pub fn spinodal(&self, n_points: usize) -> Result<Spinodal> {
+        let temps = self.saturation_sweep_temps(n_points)?;
+        let raw = self.backend.spinodal(&temps)?;
+        let convert = |pts: Vec<(f64, f64)>| {
+            pts.into_iter()
+                .map(|(t, p)| (self.conv.t_from_rp(t), self.conv.p_from_rp(p)))
+                .collect()
+        };
+        Ok(Spinodal {
+            liquid: convert(raw.liquid),
+            vapor: convert(raw.vapor),
+        })
+    }
+
+    /// `n` evenly spaced REFPROP temperatures (K) between the triple and
+    /// critical points, pulled in by 0.1 % of the range at both ends.
+    fn saturation_sweep_temps(&self, n: usize) -> Result<Vec<f64>> {
+        let t_trp = self.backend.fluid_info()?.triple_point_temp;
+        let t_c = self.backend.critical_point()?.temperature;
+        let margin = 1e-3 * (t_c - t_trp);
+        let (lo, hi) = (t_trp + margin, t_c - margin);
+        let step = if n > 1 {
+            (hi - lo) / (n - 1) as f64
+        } else {
+            0.0
+        };
+        Ok((0..n).map(|i| lo + step * i as f64).collect())
+    }
+
+    /// Latent heat, entropy of vaporization, saturation pressure and
+    /// density ratio at saturation temperature `t`, in user units.
+    ///
+    /// One saturation solve and two `THERMdll` evaluations under a single
+    /// lock (see [`saturation_both`](Self::saturation_both)).  For
+    /// mixtures the liquid is at the bubble point and the vapor at the
+    /// dew point, both at `t`; `pressure` is then the bubble pressure.
+    pub fn vaporization(&self, t: f64) -> Result<Vaporization> {
+        let (liq, vap) = self.backend.saturation_both(self.conv.t_to_rp(t))?;
+        Ok(Vaporization {
+            latent_heat: self.conv.h_from_rp(vap.enthalpy - liq.enthalpy),
+            entropy_of_vaporization: self.conv.s_from_rp(vap.entropy - liq.entropy),
+            pressure: self.conv.p_from_rp(liq.pressure),
+            density_ratio: liq.density / vap.density,
+        })
+    }
+
+    /// Watson exponent `n` in L(T) / L(T₁) = ((Tc − T) / (Tc − T₁))ⁿ,
+    /// fitted through the latent heats at saturation temperatures `t1`
+    /// and `t2` (user units).
+    ///
+    /// Two [`vaporization`](Self::vaporization) calls and the critical
+    /// temperature; the exponent is then
+    /// n = ln(L₁ / L₂) / ln((Tc − T₁) / (Tc − T₂)).  Together with one
+    /// latent heat it gives a compact latent-heat model that needs no
+    /// REFPROP.  Typical values are close to 0.38.
+    ///
+    /// ```no_run
+    /// # use refprop::{Fluid, UnitSystem};
+    /// let r134a = Fluid::with_units("R134A", UnitSystem::engineering())?;
+    /// let n = r134a.watson_exponent(-20.0, 40.0)?;
+    /// # Ok::<(), refprop::RefpropError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// [`InvalidInput`](RefpropError::InvalidInput) when `t1 == t2` or
+    /// either temperature is not below the critical temperature.
+    pub fn watson_exponent(&self, t1: f64, t2: f64) -> Result<f64> {
+        let t_c = self.backend.critical_point()?.temperature;
+        let (k1, k2) = (self.conv.t_to_rp(t1), self.conv.t_to_rp(t2));
+        if k1 == k2 {
+            return Err(RefpropError::InvalidInput(format!(
+                "Watson exponent needs two distinct temperatures, got {t1} twice"
+            )));
+        }
+        if let Some(t) = [(t1, k1), (t2, k2)].iter().find(|(_, k)| *k >= t_c) {
+            return Err(RefpropError::InvalidInput(format!(
+                "T = {} is not below the critical temperature {}",
+                t.0,
+                self.conv.t_from_rp(t_c)
+            )));
+        }
+        let l1 = self.vaporization(t1)?.latent_heat;
+        let l2 = self.vaporization(t2)?.latent_heat;
+        Ok((l1 / l2).ln() / ((t_c - k1) / (t_c - k2)).ln())
+    }
+
+    /// Isobaric condensation path from saturated vapor to saturated
+    /// liquid at pressure `p`: `n` steps with the quality falling
+    /// evenly from 100 % to 0 %.
+    ///
+    /// Each [`CondensationStep`] carries the quality (percent, molar),
+    /// the equilibrium temperature in user units and the liquid (`x`)
+    /// and vapor (`y`) mole fractions from `PQFLSHdll`.  For zeotropic
+    /// mixtures the temperature drops across the glide while the
+    /// phases shift in composition; for pure fluids both compositions
+    /// stay `[1.0]`.
+    ///
+    /// # Errors
+    ///
+    /// [`InvalidInput`](RefpropError::InvalidInput) when `n < 2`.
+    pub fn condensation_path(&self, p: f64, n: usize) -> Result<Vec<CondensationStep>> {
+        if n < 2 {
+            return Err(RefpropError::InvalidInput(format!(
+                "condensation path needs at least 2 steps, got {n}"
+            )));
+        }
+        let qualities: Vec<f64> = (0..n).map(|i| 1.0 - i as f64 / (n - 1) as f64).collect();
+        let raw = self
+            .backend
+            .condensation_path(self.conv.p_to_rp(p), &qualities)?;
+        Ok(raw
+            .into_iter()
+            .map(|step| CondensationStep {
+                quality: self.conv.q_from_rp(step.quality),
+                temperature: self.conv.t_from_rp(step.temperature),
+                ..step
+            })
+            .collect())
+    }
+
+    /// Phase-equilibrium diagnostic: Gibbs-energy difference
+    /// g_vapor − g_liquid (g = h − T·s) between the saturated roots at
+    /// temperature `t`, in the configured energy unit.
+    ///
+    /// Both phases come from one saturation solve, evaluated with
+    /// `THERMdll` at the saturated densities; a consistent equation of
+    /// state gives ≈ 0 (equal-area rule).  A value far from zero points
+    /// at a broken or edited fluid definition.
+    ///
+    /// Mixtures are rejected with
+    /// [`InvalidInput`](RefpropError::InvalidInput): their coexisting
+    /// phases differ in composition, so equilibrium means equal
+    /// chemical potentials per component, not equal molar g.
+    pub fn check_saturation_consistency(&self, t: f64) -> Result<f64> {
+        if self.backend.component_names().len() > 1 {
+            return Err(RefpropError::InvalidInput(
+                "check_saturation_consistency is only defined for pure fluids".into(),
+            ));
+        }
+        let (liq, vap) = self.backend.saturation_both(self.conv.t_to_rp(t))?;
+        let gibbs = |s: &ThermoProp| s.enthalpy - s.temperature * s.entropy;
+        Ok(self.conv.h_from_rp(gibbs(&vap) - gibbs(&liq)))
+    }
+
+    /// Saturated density ratio ρ_liquid / ρ_vapor at a given temperature.
+    ///
+    /// Dimensionless and independent of the configured density unit;
+    /// computed from a single saturation solve.  The ratio falls
+    /// monotonically toward 1 as `t` approaches the critical
+    /// temperature, where the two phases become indistinguishable.
+    /// For mixtures both densities are taken at the bubble point.
+    pub fn saturated_density_ratio(&self, t: f64) -> Result<f64> {
+        let raw = self.backend.saturation_t(self.conv.t_to_rp(t))?;
+        Ok(raw.density_liquid / raw.density_vapor)
+    }
+
+    /// Saturated-liquid and saturated-vapor states at a given
+    /// temperature, returned as `(liquid, vapor)`.
+    ///
+    /// Both states come from one saturation solve under a single lock,
+    /// about half the work of two separate `props_tq` calls.  For
+    /// zeotropic mixtures the liquid is taken at the bubble point and
+    /// the vapor at the dew point (the two pressures then differ).
+    pub fn saturation_both(&self, t: f64) -> Result<(ThermoProp, ThermoProp)> {
+        let (liq, vap) = self.backend.saturation_both(self.conv.t_to_rp(t))?;
+        Ok((self.convert_thermo(liq), self.convert_thermo(vap)))
+    }
+
+    /// Write a refrigerant-style saturation table as CSV: `n` rows at
+    /// temperatures evenly spaced from `t_start` to `t_end` (both
+    /// included), with columns T, Psat, ρ_liq, ρ_vap, h_liq, h_vap,
+    /// s_liq and s_vap.
+    ///
+    /// Headers carry the configured unit symbols (e.g. `Psat [bar]`)
+    /// and values use the [`DisplayConfig`] precision.  All rows are
+    /// computed under a single lock acquisition before anything is
+    /// written.  For mixtures the liquid is at the bubble point and the
+    /// vapor at the dew point, as in
+    /// [`saturation_both`](Self::saturation_both), and Psat is the
+    /// bubble pressure.
+    ///
+    /// ```no_run
+    /// # use refprop::{Fluid, UnitSystem};
+    /// let f = Fluid::with_units("R134A", UnitSystem::engineering())?;
+    /// f.write_saturation_table(std::io::stdout(), -40.0, 60.0, 21)?;
+    /// # Ok::<(), refprop::RefpropError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// [`InvalidInput`](RefpropError::InvalidInput) when `n < 2` or the
+    /// range is empty, and [`Io`](RefpropError::Io) when the writer
+    /// fails.
+    pub fn write_saturation_table<W: Write>(
+        &self,
+        mut w: W,
+        t_start: f64,
+        t_end: f64,
+        n: usize,
+    ) -> Result<()> {
+        let temps = PropertyTable::nodes("T", &(t_start..=t_end), n)?;
+        let raw_temps: Vec<f64> = temps.iter().map(|&t| self.conv.t_to_rp(t)).collect();
+        let rows = self.backend.saturation_both_many(&raw_temps)?;
+
+        let u = &self.conv.units;
+        let (t_u, p_u, d_u) = (
+            u.temperature.symbol(),
+            u.pressure.symbol(),
+            u.density.symbol(),
+        );
+        let (h_u, s_u) = (u.energy.symbol(), u.entropy.symbol());
+        let DisplayConfig {
+            temperature: td,
+            pressure: pd,
+            density: dd,
+            energy: hd,
+            entropy: sd,
+            ..
+        } = self.display;
+        writeln!(
+            w,
+            "T [{t_u}],Psat [{p_u}],rho_liq [{d_u}],rho_vap [{d_u}],\
+             h_liq [{h_u}],h_vap [{h_u}],s_liq [{s_u}],s_vap [{s_u}]"
+        )?;
+        for (t, (liq, vap)) in temps.iter().zip(rows) {
+            let (liq, vap) = (self.convert_thermo(liq), self.convert_thermo(vap));
+            writeln!(
+                w,
+                "{t:.td$},{:.pd$},{:.dd$},{:.dd$},{:.hd$},{:.hd$},{:.sd$},{:.sd$}",
+                liq.pressure,
+                liq.density,
+                vap.density,
+                liq.enthalpy,
+                vap.enthalpy,
+                liq.entropy,
+                vap.entropy
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Human-readable saturation report at a given pressure, in the
+    /// configured units.
+    ///
+    /// Lists the bubble and dew temperatures, the temperature glide
+    /// (T_dew − T_bubble), the saturated densities, and for mixtures the
+    /// bulk composition next to the incipient-phase compositions (vapor
+    /// at the bubble point, liquid at the dew point).  Pure fluids show
+    /// a zero glide.
+    pub fn saturation_report(&self, p: f64) -> Result<String> {
+        use std::fmt::Write;
+
+        let p_rp = self.conv.p_to_rp(p);
+        let (bubble, y) = self.backend.saturation_p_incipient(p_rp, 1)?;
+        let (dew, x) = if self.backend.component_names().len() > 1 {
+            self.backend.saturation_p_incipient(p_rp, 2)?
+        } else {
+            (bubble.clone(), y.clone())
+        };
+        let bubble = self.convert_sat(bubble);
+        let dew = self.convert_sat(dew);
+
+        let u = &self.conv.units;
+        let (t_u, p_u, d_u) = (
+            u.temperature.symbol(),
+            u.pressure.symbol(),
+            u.density.symbol(),
+        );
+        let DisplayConfig {
+            temperature: td,
+            pressure: pd,
+            density: dd,
+            composition: xd,
+            ..
+        } = self.display;
+        let mut out = String::new();
+        // Writing to a String cannot fail.
+        let _ = writeln!(out, "P_sat    = {p:.pd$} {p_u}");
+        let _ = writeln!(out, "T_bubble = {:.td$} {t_u}", bubble.temperature);
+        let _ = writeln!(out, "T_dew    = {:.td$} {t_u}", dew.temperature);
+        let _ = writeln!(
+            out,
+            "Glide    = {:.td$} {t_u}",
+            dew.temperature - bubble.temperature
+        );
+        let _ = writeln!(out, "D_liq    = {:.dd$} {d_u}", bubble.density_liquid);
+        let _ = write!(out, "D_vap    = {:.dd$} {d_u}", dew.density_vapor);
+
+        let names = self.backend.component_names();
+        if names.len() > 1 {
+            let z = self.backend.composition();
+            let _ = write!(
+                out,
+                "\n{:<12} {:>10} {:>12} {:>12}",
+                "Component", "z", "y(bubble)", "x(dew)"
+            );
+            for (i, name) in names.iter().enumerate() {
+                let _ = write!(
+                    out,
+                    "\n{:<12} {:>10.xd$} {:>12.xd$} {:>12.xd$}",
+                    name, z[i], y[i], x[i]
+                );
+            }
+        }
+        Ok(out)
+    }
+
+    /// Unit-aware description of a state returned by this `Fluid`
+    /// (values already in the configured units), one property per line
+    /// with unit symbols and the configured [`DisplayConfig`] precision.
+    ///
+    /// ```no_run
+    /// # use refprop::{DisplayConfig, Fluid, UnitSystem};
+    /// let mut f = Fluid::with_units("R134A", UnitSystem::engineering())?;
+    /// f.set_display_config(DisplayConfig::new().pressure(2).density(1));
+    /// println!("{}", f.describe(&f.props_tq(0.0, 100.0)?));
+    /// # Ok::<(), refprop::RefpropError>(())
+    /// ```
+    pub fn describe(&self, state: &ThermoProp) -> String {
+        let u = &self.conv.units;
+        let c = &self.display;
+        let (t_u, p_u, d_u) = (
+            u.temperature.symbol(),
+            u.pressure.symbol(),
+            u.density.symbol(),
+        );
+        let (h_u, s_u) = (u.energy.symbol(), u.entropy.symbol());
+        let c_u = u.heat_capacity_unit().symbol();
+        [
+            format!("T  = {:.*} {t_u}", c.temperature, state.temperature),
+            format!("P  = {:.*} {p_u}", c.pressure, state.pressure),
+            format!("D  = {:.*} {d_u}", c.density, state.density),
+            format!("H  = {:.*} {h_u}", c.energy, state.enthalpy),
+            format!("S  = {:.*} {s_u}", c.entropy, state.entropy),
+            format!("Cv = {:.*} {c_u}", c.entropy, state.cv),
+            format!("Cp = {:.*} {c_u}", c.entropy, state.cp),
+            format!("W  = {:.*} m/s", c.sound_speed, state.sound_speed),
+            format!("Q  = {:.*} %", c.quality, state.quality),
+        ]
+        .join("\n")
+    }
+
+    /// Number formatting used by [`describe`](Self::describe) and
+    /// [`saturation_report`](Self::saturation_report).
+    pub fn set_display_config(&mut self, config: DisplayConfig) {
+        self.display = config;
+    }
+
+    /// Current [`DisplayConfig`].
+    pub fn display_config(&self) -> &DisplayConfig {
+        &self.display
+    }
+
+    /// Component names of the loaded fluid or mixture, in REFPROP order.
+    pub fn component_names(&self) -> Vec<String> {
+        self.backend.component_names()
+    }
+
+    /// Molar composition (mole fractions), one entry per component.
+    ///
+    /// For predefined mixtures the fractions read from the `.MIX` file
+    /// are renormalized once at load time so that they sum to exactly
+    /// 1 (a deviation above 1e-6 is reported on stderr).  Only this
+    /// stored composition, which every later call passes to REFPROP,
+    /// is affected; the `.MIX` file is left as is.
+    pub fn composition(&self) -> Vec<f64> {
+        self.backend.composition()
+    }
+
+    /// Component names, mole fractions and molar mass in one struct,
+    /// e.g. to report that R410A is R32/R125 at about 0.70/0.30 (mole).
+    ///
+    /// The molar mass is recomputed by REFPROP for the current
+    /// composition, in g/mol whatever the configured units.  Pure
+    /// fluids list one component with a fraction of 1.
+    pub fn mixture_info(&self) -> Result<MixtureInfo> {
+        let components = self
+            .backend
+            .component_names()
+            .into_iter()
+            .zip(self.backend.composition())
+            .collect();
+        Ok(MixtureInfo {
+            components,
+            molar_mass: self.backend.molar_mass_mix()?,
+        })
+    }
+
+    /// Change the mole fractions of the loaded components in place,
+    /// without reloading the library or re-running `SETUPdll`.
+    ///
+    /// `z` gives one fraction per component in
+    /// [`component_names`](Self::component_names) order; it is
+    /// normalized to sum to exactly 1, and the molar mass used for
+    /// mass-based units is recomputed.  Only the composition can change
+    /// this way: to add, remove or reorder components, build a new
+    /// `Fluid`.
+    ///
+    /// ```no_run
+    /// use refprop::{Fluid, UnitSystem};
+    ///
+    /// let mut mix = Fluid::mixture_with_units(
+    ///     &[("R32", 0.5), ("R125", 0.5)],
+    ///     UnitSystem::engineering(),
+    /// )?;
+    /// mix.with_composition(&[0.51, 0.49])?;
+    /// let p_bubble = mix.get("P", "T", 0.0, "Q", 0.0)?;
+    /// # Ok::<(), refprop::RefpropError>(())
+    /// ```
+    pub fn with_composition(&mut self, z: &[f64]) -> Result<()> {
+        self.conv.molar_mass = self.backend.set_composition(z)?;
+        Ok(())
+    }
+
+    /// Finite-difference derivative of `get(property, "T", t, "P", p)`
+    /// with respect to the mole fraction of component `component_i`
+    /// (in [`component_names`](Self::component_names) order), in the
+    /// property's user units per unit mole fraction.
+    ///
+    /// z_i is moved by ±1e-4 (one-sided at 0 and 1) and the other
+    /// fractions are rescaled by the same factor so that they keep their
+    /// ratios and the sum stays 1; each perturbed mixture is evaluated
+    /// through [`with_composition`](Self::with_composition), and the
+    /// original composition is restored afterwards, even on error.  Use
+    /// `"PSAT"` or `"TSAT"` for the bubble point, e.g. the sensitivity of
+    /// the bubble pressure to a fractionation shift after a leak.
+    ///
+    /// ```no_run
+    /// # use refprop::{Fluid, UnitSystem};
+    /// let mut r407c = Fluid::with_units("R407C", UnitSystem::engineering())?;
+    /// // bar per unit mole fraction of R32 (P is ignored by PSAT)
+    /// let dp_dz = r407c.composition_sensitivity("PSAT", 0.0, 1.0, 0)?;
+    /// # Ok::<(), refprop::RefpropError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// [`InvalidInput`](RefpropError::InvalidInput) for a pure fluid,
+    /// an out-of-range index, or a component that makes up the whole
+    /// mixture (the others cannot be rescaled).
+    pub fn composition_sensitivity(
+        &mut self,
+        property: &str,
+        t: f64,
+        p: f64,
+        component_i: usize,
+    ) -> Result<f64> {
+        const DZ: f64 = 1e-4;
+
+        let z = self.composition();
+        if z.len() < 2 {
+            return Err(RefpropError::InvalidInput(
+                "Composition sensitivity needs a mixture".into(),
+            ));
+        }
+        let Some(&zi) = z.get(component_i) else {
+            return Err(RefpropError::InvalidInput(format!(
+                "Component index {component_i} out of range for {} components",
+                z.len()
+            )));
+        };
+        if zi >= 1.0 {
+            return Err(RefpropError::InvalidInput(format!(
+                "Component {component_i} makes up the whole mixture"
+            )));
+        }
+
+        let perturbed = |x: f64| -> Vec<f64> {
+            let scale = (1.0 - x) / (1.0 - zi);
+            z.iter()
+                .enumerate()
+                .map(|(j, &zj)| if j == component_i { x } else { zj * scale })
+                .collect()
+        };
+        let (lo, hi) = ((zi - DZ).max(0.0), (zi + DZ).min(1.0));
+        let mut eval = |x: f64| -> Result<f64> {
+            self.with_composition(&perturbed(x))?;
+            self.get(property, "T", t, "P", p)
+        };
+        let slope = eval(hi).and_then(|f_hi| Ok((f_hi - eval(lo)?) / (hi - lo)));
+        self.with_composition(&z)?;
+        slope
+    }
+
     /// Transport properties at (T, D) — density must be in user units.
+    ///
+    /// Fails with [`TransportUnavailable`](RefpropError::TransportUnavailable)
+    /// when the fluid file has no viscosity or thermal-conductivity
+    /// model; thermodynamic calls on the same fluid keep working.
     pub fn transport(&self, t: f64, d: f64) -> Result<TransportProps> {
         let raw = self
             .backend
@@ -284,6 +2118,107 @@ impl Fluid {
         })
     }
 
+    /// Transport properties at (T, Q), quality `q` in percent.
+    ///
+    /// Inside the dome (0 < Q < 100) viscosity and thermal
+    /// conductivity are combined from the saturated-liquid and
+    /// saturated-vapor values with the rule chosen by
+    /// [`set_two_phase_transport_model`](Self::set_two_phase_transport_model)
+    /// (McAdams by default); at Q = 0 or 100 they are those of the
+    /// saturated phase.  Mixtures use REFPROP's molar quality and, for
+    /// the branches, the bubble liquid and dew vapor at `t`.
+    pub fn transport_tq(&self, t: f64, q: f64) -> Result<TransportProps> {
+        let raw = self
+            .backend
+            .transport_tq(self.conv.t_to_rp(t), self.conv.q_to_rp(q)?)?;
+        Ok(TransportProps {
+            viscosity: self.conv.eta_from_rp(raw.viscosity),
+            thermal_conductivity: self.conv.tcx_from_rp(raw.thermal_conductivity),
+        })
+    }
+
+    /// Speed of sound (m/s) of a two-phase mixture at temperature `t`
+    /// and quality `q` (percent), under the chosen [`SoundModel`].
+    ///
+    /// [`HomogeneousEquilibrium`](SoundModel::HomogeneousEquilibrium)
+    /// is the value the T–Q and P–Q flashes report; it is much lower
+    /// than [`Frozen`](SoundModel::Frozen) inside the dome.  At Q = 0
+    /// and Q = 100 both reduce to the saturated value.
+    ///
+    /// ```no_run
+    /// # use refprop::{Fluid, SoundModel, UnitSystem};
+    /// let r134a = Fluid::with_units("R134A", UnitSystem::engineering())?;
+    /// let w_hem = r134a.two_phase_sound_speed(0.0, 50.0, SoundModel::HomogeneousEquilibrium)?;
+    /// let w_frozen = r134a.two_phase_sound_speed(0.0, 50.0, SoundModel::Frozen)?;
+    /// # Ok::<(), refprop::RefpropError>(())
+    /// ```
+    pub fn two_phase_sound_speed(&self, t: f64, q: f64, model: SoundModel) -> Result<f64> {
+        self.backend
+            .two_phase_sound_speed(self.conv.t_to_rp(t), self.conv.q_to_rp(q)?, model)
+    }
+
+    /// Choose how two-phase viscosity is estimated by
+    /// [`transport_tq`](Self::transport_tq) and by
+    /// `get("ETA", …)` / `get("TCX", …)` at two-phase states.
+    pub fn set_two_phase_transport_model(&mut self, model: TwoPhaseViscosityModel) {
+        self.backend.set_two_phase_viscosity_model(model);
+    }
+
+    /// Current two-phase viscosity rule.
+    pub fn two_phase_transport_model(&self) -> TwoPhaseViscosityModel {
+        self.backend.two_phase_viscosity_model()
+    }
+
+    /// Viscosity and thermal-conductivity model codes of the component
+    /// at `index`, as reported by `GETMODdll`.
+    ///
+    /// Dedicated correlations have codes such as `"VS1"` / `"TC1"`.
+    /// `"ECS"` means the property is *estimated* by extended
+    /// corresponding states from a reference fluid, with a
+    /// markedly larger uncertainty (often 5–20 % for viscosity).
+    pub fn transport_models(&self, index: usize) -> Result<(String, String)> {
+        self.backend.transport_models(index)
+    }
+
+    /// `true` if viscosity or thermal conductivity of this pure fluid is
+    /// estimated through the ECS model rather than a dedicated
+    /// correlation.
+    pub fn uses_ecs_transport(&self) -> Result<bool> {
+        let (eta, tcx) = self.backend.transport_models(0)?;
+        Ok(eta == "ECS" || tcx == "ECS")
+    }
+
+    /// Reference fluid of the transport model of a pure fluid.
+    ///
+    /// For ECS fluids this is the fluid the correspondence is built on
+    /// (e.g. `"R134A"` or `"PROPANE"`), read from the `.FLD` file;
+    /// fluids with dedicated correlations report their own name.
+    /// REFPROP fixes the reference in the fluid file, so it cannot be
+    /// changed from here.  Fails for mixtures.
+    pub fn transport_reference(&self) -> Result<String> {
+        self.backend.transport_reference()
+    }
+
+    /// Transport properties at (T, P) together with kinematic viscosity
+    /// and thermal diffusivity.
+    ///
+    /// Flashes once for ρ and cp, then evaluates transport at that
+    /// state.  η and λ follow the configured units, ν and α are in m²/s.
+    pub fn transport_ext_tp(&self, t: f64, p: f64) -> Result<TransportPropsExt> {
+        let (props, trn) = self
+            .backend
+            .transport_tp(self.conv.t_to_rp(t), self.conv.p_to_rp(p))?;
+        // REFPROP units: η µPa·s, D mol/L, M g/mol (D·M = kg/m³), cp J/(mol·K)
+        let rho_mass = props.density * self.conv.molar_mass;
+        let rho_cp = props.density * 1000.0 * props.cp;
+        Ok(TransportPropsExt {
+            viscosity: self.conv.eta_from_rp(trn.viscosity),
+            thermal_conductivity: self.conv.tcx_from_rp(trn.thermal_conductivity),
+            kinematic_viscosity: trn.viscosity * 1e-6 / rho_mass,
+            thermal_diffusivity: trn.thermal_conductivity / rho_cp,
+        })
+    }
+
     /// Critical point (Tc, Pc, Dc) in user units.
     pub fn critical_point(&self) -> Result<CriticalProps> {
         let raw = self.backend.critical_point()?;
@@ -294,6 +2229,100 @@ impl Fluid {
         })
     }
 
+    /// Reduced temperature, pressure and density T/Tc, P/Pc and ρ/ρc
+    /// of the (T, P) state (user units in).
+    ///
+    /// The ratios are taken in REFPROP units, so the temperature ratio
+    /// is between absolute temperatures whatever the configured unit.
+    /// The critical point is that of [`critical_point`](Self::critical_point).
+    ///
+    /// ```no_run
+    /// # use refprop::{Fluid, UnitSystem};
+    /// let r134a = Fluid::with_units("R134A", UnitSystem::engineering())?;
+    /// let r = r134a.reduced_properties(25.0, 10.0)?;
+    /// println!("T/Tc = {:.3}, P/Pc = {:.3}, ρ/ρc = {:.3}", r.t_r, r.p_r, r.rho_r);
+    /// # Ok::<(), refprop::RefpropError>(())
+    /// ```
+    pub fn reduced_properties(&self, t: f64, p: f64) -> Result<ReducedProps> {
+        let crit = self.backend.critical_point()?;
+        let state = self
+            .backend
+            .props_tp(self.conv.t_to_rp(t), self.conv.p_to_rp(p))?;
+        Ok(ReducedProps {
+            t_r: state.temperature / crit.temperature,
+            p_r: state.pressure / crit.pressure,
+            rho_r: state.density / crit.density,
+        })
+    }
+
+    /// Critical locus of the binary `components = (A, B)` in
+    /// REFPROP-native units; see
+    /// [`critical_locus_with_units`](Self::critical_locus_with_units).
+    pub fn critical_locus(components: (&str, &str), n: usize) -> Result<Vec<(f64, CriticalProps)>> {
+        Self::critical_locus_with_units(components, n, UnitSystem::refprop())
+    }
+
+    /// Critical point of the binary `components = (A, B)` at `n` mole
+    /// fractions x_A evenly spaced from 1 (pure A) to 0 (pure B),
+    /// returned as `(x_A, critical point)` pairs in `units`.
+    ///
+    /// The endpoints are loaded as pure fluids and the intermediate
+    /// compositions as custom mixtures.  Where `CRITPdll` fails for a
+    /// mixture, the direct critical-point solver `CRTPNTdll` (REFPROP
+    /// 10+) is tried before giving up.
+    pub fn critical_locus_with_units(
+        components: (&str, &str),
+        n: usize,
+        units: UnitSystem,
+    ) -> Result<Vec<(f64, CriticalProps)>> {
+        if n < 2 {
+            return Err(RefpropError::InvalidInput(format!(
+                "critical locus needs at least 2 compositions, got {n}"
+            )));
+        }
+        let (a, b) = components;
+        (0..n)
+            .map(|i| {
+                let x_a = 1.0 - i as f64 / (n - 1) as f64;
+                let fluid = if i == 0 {
+                    Self::with_units(a, units.clone())?
+                } else if i == n - 1 {
+                    Self::with_units(b, units.clone())?
+                } else {
+                    Self::mixture_with_units(&[(a, x_a), (b, 1.0 - x_a)], units.clone())?
+                };
+                let raw = fluid.backend.critical_point_with_fallback()?;
+                let crit = CriticalProps {
+                    temperature: fluid.conv.t_from_rp(raw.temperature),
+                    pressure: fluid.conv.p_from_rp(raw.pressure),
+                    density: fluid.conv.d_from_rp(raw.density),
+                };
+                Ok((x_a, crit))
+            })
+            .collect()
+    }
+
+    /// Whether the state (T, P) lies in a retrograde region of the
+    /// mixture's phase envelope.
+    ///
+    /// Definition used: the state is two-phase and either
+    ///
+    /// * T is above the mixture critical temperature — the band between
+    ///   the critical point and the cricondentherm, where lowering P
+    ///   from the upper dew point condenses liquid (isothermal
+    ///   retrograde condensation), or
+    /// * P is above the mixture critical pressure — the band between
+    ///   the critical point and the cricondenbar (isobaric retrograde
+    ///   vaporization).
+    ///
+    /// Any two-phase state beyond the critical temperature or pressure
+    /// is necessarily inside these bands, so the envelope extrema need
+    /// not be traced.  Pure fluids always return `false`.
+    pub fn is_retrograde(&self, t: f64, p: f64) -> Result<bool> {
+        self.backend
+            .is_retrograde(self.conv.t_to_rp(t), self.conv.p_to_rp(p))
+    }
+
     /// Static fluid information (molar mass, triple point, …).
     ///
     /// **Note:** values in this struct are always in REFPROP-native
@@ -303,6 +2332,487 @@ impl Fluid {
         self.backend.fluid_info()
     }
 
+    /// Saturated-liquid state at the triple-point temperature, in
+    /// user units.
+    ///
+    /// The temperature is `info().triple_point_temp`; for mixtures that
+    /// is the value REFPROP reports for the first component, and the
+    /// state is the bubble point at that temperature.
+    pub fn triple_point(&self) -> Result<ThermoProp> {
+        let t_trp = self.backend.fluid_info()?.triple_point_temp;
+        let raw = self.backend.props_tq(t_trp, 0.0)?;
+        Ok(self.convert_thermo(raw))
+    }
+
+    /// Saturated-liquid state at 1 atm (101.325 kPa), in user units.
+    ///
+    /// For pure fluids the temperature matches
+    /// `info().normal_boiling_point`; for mixtures this is the bubble
+    /// point at 1 atm.
+    pub fn normal_boiling_point(&self) -> Result<ThermoProp> {
+        let raw = self.backend.props_pq(101.325, 0.0)?;
+        Ok(self.convert_thermo(raw))
+    }
+
+    /// Stated relative uncertainty (fraction, 0.001 = 0.1 %) of
+    /// property `key` — `"D"`, `"CP"`/`"CV"`, `"W"`, `"P"` (vapor
+    /// pressure) or `"H"` — for the phase of the state (T, P).
+    ///
+    /// REFPROP has no uncertainty routine, so the value is read from the
+    /// equation-of-state description in the pure fluid's `.FLD` file:
+    /// the largest percentage quoted for that property in the phase of
+    /// the state (or overall if no phase is named).  It is a coarse,
+    /// conservative figure, not a state-by-state estimate.  Fluids whose
+    /// file quotes nothing for the property return an error.
+    pub fn property_uncertainty(&self, key: &str, t: f64, p: f64) -> Result<f64> {
+        self.backend
+            .property_uncertainty(key, self.conv.t_to_rp(t), self.conv.p_to_rp(p))
+    }
+
+    /// Version of the loaded REFPROP library, e.g. `"10.0"`.
+    ///
+    /// Needs `RPVersion`, which only REFPROP 10 and later export; older
+    /// DLLs return [`RefpropError::Sys`] with a
+    /// [`SymbolNotFound`](crate::sys::RefpropSysError::SymbolNotFound) source.  For reproducibility logs on those, record
+    /// [`library_path`](Self::library_path) and
+    /// [`fluids_fingerprint`](Self::fluids_fingerprint) instead.
+    pub fn refprop_version(&self) -> Result<String> {
+        self.backend.refprop_version()
+    }
+
+    /// Number of flash calculations this fluid has run so far.
+    ///
+    /// A diagnostic for checking that cached paths such as
+    /// [`at_tp`](Self::at_tp) do not flash again.
+    pub fn flash_count(&self) -> usize {
+        self.backend.flash_count()
+    }
+
+    /// Number of `TRNPRPdll` transport evaluations this fluid has run
+    /// so far; a two-phase state counts one per saturated branch.
+    pub fn transport_count(&self) -> usize {
+        self.backend.transport_count()
+    }
+
+    /// Number of REFPROP warnings (`ierr < 0`) this fluid's
+    /// calculations have logged so far.
+    ///
+    /// Warnings suppressed by [`ignore_warnings`](Self::ignore_warnings)
+    /// or [`allow_extrapolation`](Self::allow_extrapolation) are not
+    /// counted.
+    pub fn warning_count(&self) -> usize {
+        self.backend.warning_count()
+    }
+
+    /// Contention counters of the global REFPROP lock, shared by every
+    /// fluid in the process.
+    ///
+    /// All REFPROP calls are serialized through one `std::sync::Mutex`,
+    /// which does not queue waiters in arrival order; under heavy
+    /// concurrency `max_wait` much larger than
+    /// [`mean_wait`](LockStats::mean_wait) points to a starved thread.
+    /// The counters are always on (three relaxed atomic updates per
+    /// acquisition).
+    pub fn lock_stats() -> LockStats {
+        RefpropBackend::lock_stats()
+    }
+
+    /// Check the REFPROP installation against a few well-known
+    /// reference points: saturation pressures of R134A and CO2 at
+    /// 0 °C, the critical temperature and a liquid density of water,
+    /// the normal boiling point of nitrogen and the molar mass of the
+    /// predefined R410A mixture (which needs the `mixtures/` folder).
+    ///
+    /// A fluid that fails to load or compute is recorded as a failed
+    /// [`SelfTestCheck`] with its error; only a REFPROP directory that
+    /// cannot be located at all returns `Err`.
+    ///
+    /// ```no_run
+    /// let report = refprop::Fluid::self_test()?;
+    /// if !report.all_passed() {
+    ///     eprintln!("{report}");
+    /// }
+    /// # Ok::<(), refprop::RefpropError>(())
+    /// ```
+    pub fn self_test() -> Result<SelfTestReport> {
+        Self::discover_refprop_path()?;
+
+        // (check, fluid, expected, relative tolerance, unit, evaluation)
+        type Eval = fn(&Fluid) -> Result<f64>;
+        let points: [(&str, &str, f64, f64, &'static str, Eval); 6] = [
+            ("R134A Psat(273.15 K)", "R134A", 292.80, 5e-3, "kPa", |f| {
+                f.get1("PSAT", "T", 273.15)
+            }),
+            ("CO2 Psat(273.15 K)", "CO2", 3485.1, 5e-3, "kPa", |f| {
+                f.get1("PSAT", "T", 273.15)
+            }),
+            ("WATER Tc", "WATER", 647.096, 1e-4, "K", |f| {
+                f.get1("TCRIT", "", 0.0)
+            }),
+            ("WATER D(298.15 K)", "WATER", 55.345, 1e-3, "mol/L", |f| {
+                f.get("D", "T", 298.15, "P", 101.325)
+            }),
+            ("NITROGEN Tnbp", "NITROGEN", 77.355, 1e-3, "K", |f| {
+                f.get1("TNBP", "", 0.0)
+            }),
+            ("R410A molar mass", "R410A", 72.585, 1e-3, "g/mol", |f| {
+                f.get1("M", "", 0.0)
+            }),
+        ];
+
+        let checks = points
+            .into_iter()
+            .map(|(name, fluid, expected, tolerance, unit, eval)| {
+                let (measured, error) = match Fluid::new(fluid).and_then(|f| eval(&f)) {
+                    Ok(v) => (Some(v), None),
+                    Err(e) => (None, Some(e.to_string())),
+                };
+                SelfTestCheck {
+                    name: name.to_string(),
+                    expected,
+                    measured,
+                    tolerance,
+                    unit,
+                    error,
+                }
+            })
+            .collect();
+        Ok(SelfTestReport { checks })
+    }
+
+    /// REFPROP's standard text for error code `code`, e.g. the `code`
+    /// of a [`RefpropError::Refprop`] whose `message` was truncated.
+    ///
+    /// Needs `ERRMSGdll` (REFPROP 10+); older DLLs return
+    /// [`RefpropError::Sys`].
+    pub fn error_message(&self, code: i32) -> Result<String> {
+        self.backend.error_message(code)
+    }
+
+    /// Integer code REFPROP 10's unified interface uses for the property
+    /// name `name` (e.g. `"D"`, `"Hmass"`), looked up by the DLL itself
+    /// rather than a table in this crate, so names added by newer
+    /// REFPROP releases resolve too.
+    ///
+    /// An unknown name returns
+    /// [`InvalidInput`](RefpropError::InvalidInput) with REFPROP's
+    /// message.  Needs `GETENUMdll` (REFPROP 10+); older DLLs return
+    /// [`RefpropError::Sys`].
+    pub fn property_enum(&self, name: &str) -> Result<i32> {
+        self.backend.property_enum(name)
+    }
+
+    /// Path of the REFPROP shared library that is actually loaded.
+    pub fn library_path(&self) -> &Path {
+        self.backend.library_path()
+    }
+
+    /// REFPROP routines the loaded library does not export.
+    ///
+    /// The library is loaded leniently: only the
+    /// [`CORE_SYMBOLS`](crate::sys::CORE_SYMBOLS) are required, so an
+    /// old or trimmed DLL still works for whatever it supports.
+    /// Methods that need a listed routine fail with
+    /// [`RefpropError::Sys`] wrapping
+    /// [`SymbolNotFound`](crate::sys::RefpropSysError::SymbolNotFound);
+    /// e.g. without `SETMIXdll` predefined mixtures cannot be loaded
+    /// but pure fluids work.  Empty for a complete REFPROP 10 install.
+    pub fn missing_symbols(&self) -> &[&'static str] {
+        self.backend.missing_symbols()
+    }
+
+    /// Escape hatch for REFPROP routines this crate does not wrap.
+    ///
+    /// Runs `f` with the loaded [`RefpropLibrary`] while holding the
+    /// global REFPROP lock, after REFPROP has been set up for this
+    /// fluid, so the routines `f` calls see this fluid and no other
+    /// thread can interleave.  `f`'s result is returned as is; inputs
+    /// and outputs are in REFPROP units, and the composition to pass
+    /// is [`composition`](Self::composition) (read it **before** the
+    /// call, padded to [`REFPROP_NC_MAX`](crate::sys::REFPROP_NC_MAX)).
+    ///
+    /// # Deadlock
+    ///
+    /// The lock is not reentrant.  `f` must not call methods of **any**
+    /// `Fluid`, this one included (the lock-free
+    /// [`lock_stats`](Self::lock_stats) aside): doing so blocks the
+    /// thread forever or panics.  Call only `RefpropLibrary` methods
+    /// inside `f`.  Dropping a `Fluid` inside `f` is fine: its cleanup
+    /// does not wait for the lock.
+    ///
+    /// # Safety of the calls
+    ///
+    /// The method itself is safe, but every `RefpropLibrary` routine is
+    /// `unsafe`: argument pointers and string lengths must follow the
+    /// REFPROP manual.  Routines that change global state (`SETUPdll`,
+    /// `SETREFdll`, …) will silently affect later calls on this fluid.
+    ///
+    /// ```no_run
+    /// use refprop::Fluid;
+    /// use refprop::sys::REFPROP_NC_MAX;
+    ///
+    /// let r134a = Fluid::new("R134A")?;
+    /// let mut z = [0.0; REFPROP_NC_MAX];
+    /// z[0] = 1.0;
+    /// let dpdd = r134a.with_raw_library(|lib| {
+    ///     let (t, d, mut dpdd) = (300.0, 12.0, 0.0);
+    ///     unsafe { lib.DPDDdll(&t, &d, z.as_ptr(), &mut dpdd) };
+    ///     dpdd
+    /// })?;
+    /// # Ok::<(), refprop::RefpropError>(())
+    /// ```
+    pub fn with_raw_library<F, R>(&self, f: F) -> Result<R>
+    where
+        F: FnOnce(&RefpropLibrary) -> R,
+    {
+        self.backend.with_raw_library(f)
+    }
+
+    /// Hash (16 hex digits) of the names and contents of every file in
+    /// the fluids directory, identifying the installed fluid versions.
+    pub fn fluids_fingerprint(&self) -> Result<String> {
+        self.backend.fluids_fingerprint()
+    }
+
+    /// Static information for one component of a mixture (`index` is
+    /// 0-based, in [`component_names`](Self::component_names) order).
+    ///
+    /// Like [`info`](Self::info), values are in REFPROP-native units,
+    /// except the dipole moment which follows the configured
+    /// [`DipoleUnit`](crate::DipoleUnit).  The acentric factor is
+    /// dimensionless.
+    pub fn component_info(&self, index: usize) -> Result<FluidInfo> {
+        let mut info = self.backend.component_info(index)?;
+        info.dipole_moment = self.conv.dipole_from_rp(info.dipole_moment);
+        Ok(info)
+    }
+
+    /// Cross second virial coefficient Bᵢⱼ between components `i` and
+    /// `j` (0-based) at temperature `t`, in **L/mol** regardless of the
+    /// configured units.
+    ///
+    /// The interaction term of the virial mixing rule
+    /// B_mix = Σ zᵢ·zⱼ·Bᵢⱼ, useful to check a mixture model against
+    /// low-pressure gas data.  `B12dll` only gives mixture values, so
+    /// Bᵢⱼ = 2·B(½, ½) − ½·(Bᵢᵢ + Bⱼⱼ) from the equimolar i–j pair;
+    /// `i == j` returns Bᵢᵢ.  Pure fluids return
+    /// [`InvalidInput`](RefpropError::InvalidInput).
+    pub fn cross_virial(&self, t: f64, i: usize, j: usize) -> Result<f64> {
+        self.backend.cross_virial(self.conv.t_to_rp(t), i, j)
+    }
+
+    /// Ideal-gas heat-capacity ratio γ⁰ = cp⁰ / cv⁰ = cp⁰ / (cp⁰ − R)
+    /// at temperature `t` (dimensionless).
+    ///
+    /// This is the γ assumed by textbook isentropic-flow relations
+    /// (nozzles, choked flow); it depends on T only.  The real-gas
+    /// `cp / cv` from a flash tends to it as P → 0, so the gap between
+    /// the two measures the non-ideality at a given state.
+    ///
+    /// ```no_run
+    /// # use refprop::{Fluid, UnitSystem};
+    /// let n2 = Fluid::with_units("NITROGEN", UnitSystem::engineering())?;
+    /// let gamma0 = n2.ideal_gas_gamma(25.0)?; // ≈ 1.40
+    /// # Ok::<(), refprop::RefpropError>(())
+    /// ```
+    pub fn ideal_gas_gamma(&self, t: f64) -> Result<f64> {
+        self.backend.ideal_gas_gamma(self.conv.t_to_rp(t))
+    }
+
+    /// New pure `Fluid` for one component of this mixture (`index` is
+    /// 0-based, in [`component_names`](Self::component_names) order),
+    /// e.g. the R32 in R410A.
+    ///
+    /// The component is loaded from the same library and fluids
+    /// directory, and inherits the unit system and display settings.
+    pub fn component_as_fluid(&self, index: usize) -> Result<Fluid> {
+        let backend = self.backend.component_backend(index)?;
+        let mm = backend.molar_mass_mix()?;
+        Ok(Fluid {
+            backend,
+            conv: Converter::new(self.conv.units.clone(), mm),
+            display: self.display,
+        })
+    }
+
+    /// Allow (or forbid) evaluations slightly outside the fluid's
+    /// validated range without REFPROP range warnings.
+    ///
+    /// When enabled, REFPROP's extrapolation flag is switched on via
+    /// `FLAGSdll` (REFPROP 10+; silently skipped on older DLLs) and the
+    /// following `ierr < 0` warnings are no longer logged:
+    ///
+    /// | Code | Meaning                            |
+    /// |------|------------------------------------|
+    /// | -1   | T above Tmax (below 1.5·Tmax)      |
+    /// | -2   | D above Dmax (below 1.1·Dmax)      |
+    /// | -4   | P above Pmax (below 2·Pmax)        |
+    /// | -3, -5, -6, -7 | combinations of the above |
+    ///
+    /// Genuine failures (`ierr > 0`) are still returned as errors, and
+    /// composition warnings are still logged.
+    pub fn allow_extrapolation(&mut self, allow: bool) -> Result<()> {
+        self.backend.set_allow_extrapolation(allow)
+    }
+
+    /// Accept the REFPROP warnings with the given codes silently: they
+    /// are neither logged nor counted by
+    /// [`warning_count`](Self::warning_count).
+    ///
+    /// Each call replaces the previous list; `&[]` restores the default.
+    /// Other warnings are still logged, and errors (`ierr > 0`) are
+    /// never affected.
+    ///
+    /// ```no_run
+    /// # use refprop::{Fluid, UnitSystem};
+    /// let mut r134a = Fluid::with_units("R134A", UnitSystem::engineering())?;
+    /// r134a.ignore_warnings(&[-1])?; // T above Tmax
+    /// # Ok::<(), refprop::RefpropError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// [`InvalidInput`](RefpropError::InvalidInput) for a code that is
+    /// not negative.
+    pub fn ignore_warnings(&mut self, codes: &[i32]) -> Result<()> {
+        self.backend.set_ignored_warnings(codes)
+    }
+
+    /// Warning codes accepted silently, see
+    /// [`ignore_warnings`](Self::ignore_warnings).
+    pub fn ignored_warnings(&self) -> &[i32] {
+        self.backend.ignored_warnings()
+    }
+
+    /// Switch the enthalpy/entropy reference state (REFPROP's `hrf`)
+    /// and set REFPROP up again with it.
+    ///
+    /// Only h, s, u and the derived energies shift; every other
+    /// property is unchanged.
+    ///
+    /// ```no_run
+    /// use refprop::{Fluid, ReferenceState, UnitSystem};
+    ///
+    /// let mut r134a = Fluid::with_units("R134A", UnitSystem::engineering())?;
+    /// r134a.set_reference_state(ReferenceState::Ashrae)?;
+    /// let h = r134a.get("H", "T", -40.0, "Q", 0.0)?; // ≈ 0 kJ/kg
+    /// # Ok::<(), refprop::RefpropError>(())
+    /// ```
+    pub fn set_reference_state(&mut self, reference: ReferenceState) -> Result<()> {
+        self.backend.set_reference_state(reference)
+    }
+
+    /// Current enthalpy/entropy reference state.
+    pub fn reference_state(&self) -> ReferenceState {
+        self.backend.reference_state()
+    }
+
+    /// Equation-of-state models shipped in the fluid file of a pure
+    /// fluid, as REFPROP model codes (e.g. `"FEQ"` for a Helmholtz
+    /// equation, `"BWR"`, `"ECS"`): the recommended model first, then
+    /// the alternatives, for [`select_model`](Self::select_model).
+    ///
+    /// Read from the `#EOS` and `@EOS` sections of the `.FLD` file.
+    /// Mixtures return [`InvalidInput`](RefpropError::InvalidInput).
+    pub fn available_models(&self) -> Result<Vec<String>> {
+        self.backend.available_eos_models()
+    }
+
+    /// Evaluate with the equation of state `name` (one of
+    /// [`available_models`](Self::available_models), case-insensitive)
+    /// instead of the recommended one, e.g. to compare formulations.
+    ///
+    /// The model is applied with `SETMODdll` and REFPROP is set up
+    /// again, now and every time this fluid is set up after another
+    /// one.  Values derived from the previous model are **not**
+    /// updated: states, critical points or molar masses read before
+    /// the switch, and the shift of
+    /// [`rezero_reference`](Self::rezero_reference), must be computed
+    /// again.  The molar mass used for mass-based units is refreshed.
+    /// On failure the previous model stays active.
+    ///
+    /// ```no_run
+    /// # use refprop::{Fluid, UnitSystem};
+    /// let mut r134a = Fluid::with_units("R134A", UnitSystem::engineering())?;
+    /// let models = r134a.available_models()?; // e.g. ["FEQ", "BWR", "ECS"]
+    /// if let Some(alt) = models.get(1) {
+    ///     r134a.select_model(alt)?;
+    /// }
+    /// # Ok::<(), refprop::RefpropError>(())
+    /// ```
+    pub fn select_model(&mut self, name: &str) -> Result<()> {
+        let code = name.trim().to_uppercase();
+        let models = self.available_models()?;
+        if !models.contains(&code) {
+            return Err(RefpropError::InvalidInput(format!(
+                "Model \"{name}\" is not available; the fluid file lists {}",
+                models.join(", ")
+            )));
+        }
+        self.backend.set_eos_model(Some(code))?;
+        self.conv.molar_mass = self.backend.molar_mass_mix()?;
+        Ok(())
+    }
+
+    /// Equation-of-state model chosen with
+    /// [`select_model`](Self::select_model), `None` for REFPROP's
+    /// recommended one.
+    pub fn selected_model(&self) -> Option<&str> {
+        self.backend.eos_model()
+    }
+
+    /// Shift enthalpy and entropy so that both read zero at (T, P).
+    ///
+    /// A **post-hoc display shift**, not a thermodynamic reference
+    /// change: REFPROP keeps computing with its own reference state
+    /// (see [`set_reference_state`](Self::set_reference_state)), and the
+    /// [`Converter`] subtracts the stored h and s from every absolute
+    /// enthalpy, internal energy and entropy it outputs (adding them
+    /// back for H/S inputs).  Differences, Cv, Cp and all other
+    /// properties are unchanged.  When (T, P) lies on the saturation
+    /// line the saturated liquid is used, so
+    /// `rezero_reference(0.0, psat(0 °C))` gives h = s = 0 for the
+    /// saturated liquid at 0 °C.  Calling it again replaces the
+    /// previous shift; the offsets are per mole of the current
+    /// composition.
+    ///
+    /// ```no_run
+    /// use refprop::{Fluid, UnitSystem};
+    ///
+    /// let mut r134a = Fluid::with_units("R134A", UnitSystem::engineering())?;
+    /// let p0 = r134a.get("P", "T", 0.0, "Q", 0.0)?;
+    /// r134a.rezero_reference(0.0, p0)?;
+    /// let h = r134a.get("H", "T", 0.0, "Q", 0.0)?; // ≈ 0 kJ/kg
+    /// # Ok::<(), refprop::RefpropError>(())
+    /// ```
+    pub fn rezero_reference(&mut self, t_ref: f64, p_ref: f64) -> Result<()> {
+        let states = self
+            .backend
+            .states_tp(self.conv.t_to_rp(t_ref), self.conv.p_to_rp(p_ref))?;
+        let reference = states.first().ok_or_else(|| {
+            RefpropError::CalculationFailed(format!(
+                "No state found at the reference point T = {t_ref}, P = {p_ref}"
+            ))
+        })?;
+        self.conv.h_offset = reference.enthalpy;
+        self.conv.s_offset = reference.entropy;
+        Ok(())
+    }
+
+    /// Remove the shift applied by
+    /// [`rezero_reference`](Self::rezero_reference).
+    pub fn clear_reference_shift(&mut self) {
+        self.conv.h_offset = 0.0;
+        self.conv.s_offset = 0.0;
+    }
+
+    /// Unit system this `Fluid` was built with, e.g. to label outputs
+    /// or to build a sibling fluid in the same units:
+    /// `Fluid::with_units("CO2", r134a.units().clone())`.
+    pub fn units(&self) -> &UnitSystem {
+        self.conv.unit_system()
+    }
+
     /// Access the active converter (useful for manual conversions).
     pub fn converter(&self) -> &Converter {
         &self.conv
@@ -311,20 +2821,31 @@ impl Fluid {
     // ── Internal conversion helpers ──────────────────────────────────
 
     fn convert_thermo(&self, raw: ThermoProp) -> ThermoProp {
+        Self::convert_thermo_with(&self.conv, raw)
+    }
+
+    /// [`convert_thermo`](Self::convert_thermo) with an explicit
+    /// converter, e.g. one carrying the molar mass of a single phase.
+    fn convert_thermo_with(conv: &Converter, raw: ThermoProp) -> ThermoProp {
         ThermoProp {
-            temperature: self.conv.t_from_rp(raw.temperature),
-            pressure: self.conv.p_from_rp(raw.pressure),
-            density: self.conv.d_from_rp(raw.density),
-            enthalpy: self.conv.h_from_rp(raw.enthalpy),
-            entropy: self.conv.s_from_rp(raw.entropy),
-            cv: self.conv.s_from_rp(raw.cv),
-            cp: self.conv.s_from_rp(raw.cp),
+            temperature: conv.t_from_rp(raw.temperature),
+            pressure: conv.p_from_rp(raw.pressure),
+            density: conv.d_from_rp(raw.density),
+            enthalpy: conv.enthalpy_from_rp(raw.enthalpy),
+            entropy: conv.entropy_from_rp(raw.entropy),
+            cv: conv.cp_from_rp(raw.cv),
+            cp: conv.cp_from_rp(raw.cp),
             sound_speed: raw.sound_speed,
-            quality: self.conv.q_from_rp(raw.quality),
-            internal_energy: self.conv.h_from_rp(raw.internal_energy),
+            quality: conv.q_from_rp(raw.quality),
+            internal_energy: conv.enthalpy_from_rp(raw.internal_energy),
         }
     }
 
+    /// Map a raw REFPROP quality to user units, `None` outside [0, 1].
+    fn two_phase_quality(&self, q: f64) -> Option<f64> {
+        (0.0..=1.0).contains(&q).then(|| self.conv.q_from_rp(q))
+    }
+
     fn convert_sat(&self, raw: SaturationProps) -> SaturationProps {
         SaturationProps {
             temperature: self.conv.t_from_rp(raw.temperature),
@@ -33,7 +33,7 @@
 //!
 //! Choose units at construction time with [`UnitSystem`] presets
 //! ([`refprop()`](UnitSystem::refprop), [`engineering()`](UnitSystem::engineering),
-//! [`si()`](UnitSystem::si)) or the builder:
+//! [`si()`](UnitSystem::si), [`si_molar()`](UnitSystem::si_molar)) or the builder:
 //!
 //! ```
 //! use refprop::{UnitSystem, TempUnit, PressUnit};
@@ -43,6 +43,13 @@
 //!     .pressure(PressUnit::Bar);
 //! ```
 //!
+//! ## Locating REFPROP
+//!
+//! The REFPROP directory is taken from `REFPROP_PATH` (environment or
+//! `.env`), then from `REFPROP_BUILD_PATH` if it was set at build time,
+//! then from the standard install locations; see
+//! [`Fluid::discover_refprop_path`].
+//!
 //! ## Mixtures
 //!
 //! ```no_run
@@ -60,22 +67,45 @@
 //! ```
 
 // ── Internal modules ─────────────────────────────────────────────────
+#[cfg(feature = "refprop")]
 mod backend;
+#[cfg(feature = "refprop")]
+pub mod capture;
 pub mod converter;
+pub mod display;
 pub mod error;
+#[cfg(feature = "refprop")]
 pub mod sys;
+#[cfg(feature = "refprop")]
 pub mod fluid;
 pub mod properties;
+#[cfg(feature = "refprop")]
+pub mod state;
+#[cfg(feature = "refprop")]
+pub mod table;
+#[cfg(feature = "mock")]
+pub mod testing;
 
 // ── Public re-exports ────────────────────────────────────────────────
+#[cfg(feature = "refprop")]
+pub use capture::OutputCapture;
+pub use display::DisplayConfig;
 pub use error::{RefpropError, Result};
+#[cfg(feature = "refprop")]
 pub use fluid::Fluid;
 pub use properties::{
-    CriticalProps, FluidInfo, SaturationProps, ThermoProp, TransportProps,
+    AntoineFit, CondensationStep, CriticalProps, FluidInfo, LockStats, MixtureInfo, Phase,
+    PhaseBoundary, ReducedProps, ReferenceState, SaturationDistance, SaturationProps,
+    SaturationPropsExt, SelfTestCheck, SelfTestReport, SeparatorResult, SoundModel, Spinodal,
+    Stream, ThermoProp, TransportProps, TransportPropsExt, TwoPhaseViscosityModel, Vaporization,
 };
+#[cfg(feature = "refprop")]
+pub use state::StatePoint;
+#[cfg(feature = "refprop")]
+pub use table::PropertyTable;
 
 pub use converter::{
-    Converter, UnitSystem,
-    TempUnit, PressUnit, DensityUnit, EnergyUnit, EntropyUnit,
-    ViscosityUnit, ConductivityUnit,
+    Basis, ConductivityUnit, Converter, DensityUnit, DipoleUnit, EnergyUnit, EntropyUnit,
+    HeatCapacityUnit, MassFlowUnit, PressUnit, Property, TempUnit, UnitConfig, UnitSystem,
+    ViscosityUnit,
 };
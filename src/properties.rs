@@ -44,32 +44,309 @@ impl std::fmt::Display for ThermoProp {
     }
 }
 
+// ── Streams ─────────────────────────────────────────────────────────
+
+/// A flowing state: a (T, P) flash scaled by a mass flow.
+///
+/// `mass_flow`, `enthalpy_flow` and `entropy_flow` are always in kg/s,
+/// kW and kW/K whatever the configured units; `density` and `state`
+/// follow the configured units.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stream {
+    /// Mass flow (kg/s)
+    pub mass_flow: f64,
+    /// Enthalpy flow ṁ·h (kW)
+    pub enthalpy_flow: f64,
+    /// Entropy flow ṁ·s (kW/K)
+    pub entropy_flow: f64,
+    /// Density
+    pub density: f64,
+    /// The flashed state
+    pub state: ThermoProp,
+}
+
 // ── Saturation properties ───────────────────────────────────────────
 
 /// Saturation-line properties returned by `SATPdll` / `SATTdll`.
 ///
-/// Densities are in **mol/L**.
+/// Values follow the configured units of the [`Fluid`](crate::Fluid)
+/// that returned them, so `Display` prints bare numbers; use
+/// [`Fluid::saturation_report`](crate::Fluid::saturation_report) for a
+/// labelled report.
 #[derive(Debug, Clone, PartialEq)]
 pub struct SaturationProps {
-    /// Saturation temperature (K)
+    /// Saturation temperature
     pub temperature: f64,
-    /// Saturation pressure (kPa)
+    /// Saturation pressure
     pub pressure: f64,
-    /// Saturated-liquid density (mol/L)
+    /// Saturated-liquid density
     pub density_liquid: f64,
-    /// Saturated-vapor density (mol/L)
+    /// Saturated-vapor density
     pub density_vapor: f64,
 }
 
 impl std::fmt::Display for SaturationProps {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        writeln!(f, "T_sat  = {:.4} K ({:.2} °C)", self.temperature, self.temperature - 273.15)?;
-        writeln!(f, "P_sat  = {:.4} kPa", self.pressure)?;
-        writeln!(f, "D_liq  = {:.6} mol/L", self.density_liquid)?;
-        write!(f, "D_vap  = {:.6} mol/L", self.density_vapor)
+        writeln!(f, "T_sat  = {:.4}", self.temperature)?;
+        writeln!(f, "P_sat  = {:.4}", self.pressure)?;
+        writeln!(f, "D_liq  = {:.6}", self.density_liquid)?;
+        write!(f, "D_vap  = {:.6}", self.density_vapor)
+    }
+}
+
+/// [`SaturationProps`] completed with the saturated enthalpies and the
+/// slope of the vapor-pressure curve: everything a Clausius–Clapeyron
+/// check L = T·(dP/dT)·(1/ρ_vap − 1/ρ_liq) needs, from one call.
+///
+/// Values follow the configured units; `dpdt_sat` is in pressure units
+/// per temperature-unit step (bar/K for °C + bar).
+#[derive(Debug, Clone, PartialEq)]
+pub struct SaturationPropsExt {
+    /// Saturation temperature
+    pub temperature: f64,
+    /// Saturation pressure (bubble pressure for mixtures)
+    pub pressure: f64,
+    /// Saturated-liquid density
+    pub density_liquid: f64,
+    /// Saturated-vapor density
+    pub density_vapor: f64,
+    /// Saturated-liquid enthalpy
+    pub enthalpy_liquid: f64,
+    /// Saturated-vapor enthalpy
+    pub enthalpy_vapor: f64,
+    /// Slope dP/dT of the saturation (bubble) curve
+    pub dpdt_sat: f64,
+}
+
+impl std::fmt::Display for SaturationPropsExt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "T_sat  = {:.4}", self.temperature)?;
+        writeln!(f, "P_sat  = {:.4}", self.pressure)?;
+        writeln!(f, "D_liq  = {:.6}", self.density_liquid)?;
+        writeln!(f, "D_vap  = {:.6}", self.density_vapor)?;
+        writeln!(f, "h_liq  = {:.4}", self.enthalpy_liquid)?;
+        writeln!(f, "h_vap  = {:.4}", self.enthalpy_vapor)?;
+        write!(f, "dP/dT  = {:.6}", self.dpdt_sat)
+    }
+}
+
+/// Vaporization data at one saturation temperature (a refrigerant
+/// table row).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Vaporization {
+    /// Latent heat h_vap − h_liq
+    pub latent_heat: f64,
+    /// Entropy of vaporization s_vap − s_liq
+    pub entropy_of_vaporization: f64,
+    /// Saturation pressure (bubble pressure for mixtures)
+    pub pressure: f64,
+    /// ρ_liq / ρ_vap (dimensionless)
+    pub density_ratio: f64,
+}
+
+impl std::fmt::Display for Vaporization {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "dh_vap = {:.4}", self.latent_heat)?;
+        writeln!(f, "ds_vap = {:.4}", self.entropy_of_vaporization)?;
+        writeln!(f, "P_sat  = {:.4}", self.pressure)?;
+        write!(f, "rho_l/rho_v = {:.4}", self.density_ratio)
     }
 }
 
+/// One point of an isobaric condensation path: the equilibrium
+/// temperature and phase compositions at a given vapor quality.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CondensationStep {
+    /// Vapor quality (molar)
+    pub quality: f64,
+    /// Equilibrium temperature
+    pub temperature: f64,
+    /// Liquid-phase mole fractions `x`, one per component
+    pub liquid_composition: Vec<f64>,
+    /// Vapor-phase mole fractions `y`, one per component
+    pub vapor_composition: Vec<f64>,
+}
+
+/// Outcome of an isothermal, isobaric flash separator: the molar vapor
+/// fraction and the two outlet streams, in the configured units.
+///
+/// A single-phase feed leaves as one stream: `vapor_fraction` is 0 with
+/// only `liquid` set, or 1 with only `vapor` set, and the missing
+/// stream has an empty composition.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeparatorResult {
+    /// Vapor fraction (molar, 0–1)
+    pub vapor_fraction: f64,
+    /// Liquid outlet, at the liquid-phase density and composition
+    pub liquid: Option<ThermoProp>,
+    /// Vapor outlet, at the vapor-phase density and composition
+    pub vapor: Option<ThermoProp>,
+    /// Liquid-phase mole fractions `x`, one per component
+    pub liquid_composition: Vec<f64>,
+    /// Vapor-phase mole fractions `y`, one per component
+    pub vapor_composition: Vec<f64>,
+}
+
+/// One end of the two-phase region at fixed pressure: the bubble or dew
+/// point of the bulk composition, in the configured units.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PhaseBoundary {
+    /// Bubble- or dew-point temperature
+    pub temperature: f64,
+    /// Pressure
+    pub pressure: f64,
+    /// Mole fractions of the incipient (minority) phase, one per
+    /// component: the vapor at the bubble point, the liquid at the dew
+    /// point
+    pub incipient_composition: Vec<f64>,
+}
+
+/// Temperature margins of a (T, P) state to the bubble and dew lines
+/// at its pressure, in the configured temperature unit.
+///
+/// Both margins are positive on the single-phase side of their line
+/// and both are negative inside the dome.  For pure fluids the bubble
+/// and dew temperatures coincide.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SaturationDistance {
+    /// Bubble-point temperature at the state's pressure
+    pub bubble_temperature: f64,
+    /// Dew-point temperature at the state's pressure
+    pub dew_temperature: f64,
+    /// T_bubble − T: the subcooling, positive for a compressed liquid
+    pub bubble_margin: f64,
+    /// T − T_dew: the superheat, positive for a superheated vapor
+    pub dew_margin: f64,
+}
+
+impl SaturationDistance {
+    /// Signed distance to the nearest of the two lines: positive
+    /// outside the dome, negative inside.
+    pub fn margin(&self) -> f64 {
+        self.bubble_margin.max(self.dew_margin)
+    }
+
+    /// `true` when the state lies strictly inside the dome.
+    pub fn is_inside_dome(&self) -> bool {
+        self.margin() < 0.0
+    }
+}
+
+/// Antoine vapor-pressure correlation ln P = A − B / (T + C) fitted to
+/// a fluid's saturation curve.
+///
+/// T and P are in the units of the [`Fluid`](crate::Fluid) that built
+/// the fit, so C absorbs the temperature offset (C ≈ −30 to −50 in K,
+/// ≈ 240 in °C for most refrigerants).  Outside
+/// `t_min..=t_max` the correlation is an extrapolation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AntoineFit {
+    /// A (ln of the pressure unit)
+    pub a: f64,
+    /// B (temperature unit)
+    pub b: f64,
+    /// C (temperature unit)
+    pub c: f64,
+    /// Lower end of the fitted temperature range
+    pub t_min: f64,
+    /// Upper end of the fitted temperature range
+    pub t_max: f64,
+    /// Largest |ln P_fit − ln P| over the samples, i.e. roughly the
+    /// largest relative pressure error
+    pub max_residual: f64,
+}
+
+impl AntoineFit {
+    /// Saturation pressure at `t` from the correlation.
+    pub fn pressure(&self, t: f64) -> f64 {
+        (self.a - self.b / (t + self.c)).exp()
+    }
+
+    /// Least-squares fit on ln P through the points `(temps[k],
+    /// pressures[k])`.
+    ///
+    /// The linearized form T·ln P = A·T + (A·C − B) − C·ln P gives the
+    /// starting point, refined by Gauss-Newton on the ln P residuals.
+    /// `None` when the system is singular or T + C changes sign over
+    /// the samples.
+    #[cfg(feature = "refprop")]
+    pub(crate) fn fit(temps: &[f64], pressures: &[f64]) -> Option<Self> {
+        const MAX_ITERATIONS: usize = 50;
+        const TOLERANCE: f64 = 1e-12;
+
+        let ln_p: Vec<f64> = pressures.iter().map(|p| p.ln()).collect();
+        let points = || temps.iter().copied().zip(ln_p.iter().copied());
+
+        let [mut a, k, mut c] = least_squares3(points().map(|(t, y)| ([t, 1.0, -y], t * y)))?;
+        let mut b = a * c - k;
+        for _ in 0..MAX_ITERATIONS {
+            let [da, db, dc] = least_squares3(points().map(|(t, y)| {
+                let x = t + c;
+                ([1.0, -1.0 / x, b / (x * x)], y - (a - b / x))
+            }))?;
+            a += da;
+            b += db;
+            c += dc;
+            if dc.abs() < TOLERANCE * c.abs().max(1.0) && db.abs() < TOLERANCE * b.abs() {
+                break;
+            }
+        }
+
+        let t_min = temps.iter().copied().fold(f64::INFINITY, f64::min);
+        let t_max = temps.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        if (t_min + c).signum() != (t_max + c).signum() {
+            return None;
+        }
+        let max_residual = points()
+            .map(|(t, y)| (a - b / (t + c) - y).abs())
+            .fold(0.0, f64::max);
+        Some(Self {
+            a,
+            b,
+            c,
+            t_min,
+            t_max,
+            max_residual,
+        })
+    }
+}
+
+/// Solution θ of the 3-parameter linear least-squares problem
+/// min Σ (xₖ·θ − yₖ)², from the normal equations by Gaussian
+/// elimination with partial pivoting.  `None` when singular.
+#[cfg(feature = "refprop")]
+fn least_squares3(rows: impl Iterator<Item = ([f64; 3], f64)>) -> Option<[f64; 3]> {
+    let mut m = [[0.0f64; 4]; 3];
+    for (x, y) in rows {
+        for i in 0..3 {
+            for j in 0..3 {
+                m[i][j] += x[i] * x[j];
+            }
+            m[i][3] += x[i] * y;
+        }
+    }
+    for col in 0..3 {
+        let pivot = (col..3).max_by(|&i, &j| m[i][col].abs().total_cmp(&m[j][col].abs()))?;
+        m.swap(col, pivot);
+        if m[col][col] == 0.0 {
+            return None;
+        }
+        let pivot_row = m[col];
+        for row in &mut m[col + 1..] {
+            let f = row[col] / pivot_row[col];
+            for (v, p) in row.iter_mut().zip(pivot_row).skip(col) {
+                *v -= f * p;
+            }
+        }
+    }
+    let mut theta = [0.0f64; 3];
+    for i in (0..3).rev() {
+        let rest: f64 = (i + 1..3).map(|j| m[i][j] * theta[j]).sum();
+        theta[i] = (m[i][3] - rest) / m[i][i];
+    }
+    theta.iter().all(|v| v.is_finite()).then_some(theta)
+}
+
 // ── Transport properties ────────────────────────────────────────────
 
 /// Viscosity and thermal conductivity at a given (T, D) state point.
@@ -88,6 +365,32 @@ impl std::fmt::Display for TransportProps {
     }
 }
 
+/// [`TransportProps`] completed with the diffusivities, from a (T, P)
+/// evaluation.
+///
+/// `viscosity` and `thermal_conductivity` follow the configured units;
+/// the two diffusivities are always in m²/s.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransportPropsExt {
+    /// Dynamic viscosity η
+    pub viscosity: f64,
+    /// Thermal conductivity λ
+    pub thermal_conductivity: f64,
+    /// Kinematic viscosity ν = η/ρ (m²/s)
+    pub kinematic_viscosity: f64,
+    /// Thermal diffusivity α = λ/(ρ·cp) (m²/s)
+    pub thermal_diffusivity: f64,
+}
+
+impl std::fmt::Display for TransportPropsExt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "eta   = {:.6}", self.viscosity)?;
+        writeln!(f, "tcx   = {:.6}", self.thermal_conductivity)?;
+        writeln!(f, "nu    = {:.6e} m²/s", self.kinematic_viscosity)?;
+        write!(f, "alpha = {:.6e} m²/s", self.thermal_diffusivity)
+    }
+}
+
 // ── Critical point ──────────────────────────────────────────────────
 
 #[derive(Debug, Clone, PartialEq)]
@@ -108,6 +411,18 @@ impl std::fmt::Display for CriticalProps {
     }
 }
 
+/// State variables divided by their critical values (dimensionless),
+/// the coordinates of the corresponding-states principle.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReducedProps {
+    /// Reduced temperature T / Tc (absolute temperatures)
+    pub t_r: f64,
+    /// Reduced pressure P / Pc
+    pub p_r: f64,
+    /// Reduced density ρ / ρc
+    pub rho_r: f64,
+}
+
 // ── Fluid information ───────────────────────────────────────────────
 
 /// Static information about a pure component (from `INFOdll`).
@@ -149,3 +464,312 @@ impl std::fmt::Display for FluidInfo {
         write!(f, "R     = {:.6} J/(mol·K)", self.gas_constant)
     }
 }
+
+/// Components and composition of a loaded fluid, see
+/// [`Fluid::mixture_info`](crate::Fluid::mixture_info).
+#[derive(Debug, Clone, PartialEq)]
+pub struct MixtureInfo {
+    /// Component names with their mole fractions, in REFPROP order
+    pub components: Vec<(String, f64)>,
+    /// Mixture-averaged molar mass (g/mol)
+    pub molar_mass: f64,
+}
+
+impl std::fmt::Display for MixtureInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (name, z) in &self.components {
+            writeln!(f, "{name:<12} z = {z:.6}")?;
+        }
+        write!(f, "M = {:.4} g/mol", self.molar_mass)
+    }
+}
+
+// ── Spinodal ────────────────────────────────────────────────────────
+
+/// Liquid and vapor spinodal curves, where (∂P/∂ρ)_T = 0.
+///
+/// Each point is `(T, P)`.  Both branches lie inside the two-phase dome
+/// (the states are metastable or unstable by construction) and meet at
+/// the critical point.  The liquid spinodal reaches negative pressures
+/// at low temperature.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Spinodal {
+    /// Limit of superheated liquid.
+    pub liquid: Vec<(f64, f64)>,
+    /// Limit of subcooled vapor.
+    pub vapor: Vec<(f64, f64)>,
+}
+
+/// Rule combining saturated-liquid and saturated-vapor transport
+/// properties into a two-phase value.
+///
+/// `x` below is the vapor quality, ρ_l / ρ_v the saturated densities
+/// and ρ_h = 1 / (x/ρ_v + (1 − x)/ρ_l) the homogeneous density.  All
+/// four results lie between the liquid and vapor values.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TwoPhaseViscosityModel {
+    /// Cicchitti et al. (1960): μ = x·μ_v + (1 − x)·μ_l.  The largest
+    /// of the four; leans toward the liquid value.
+    Cicchitti,
+    /// McAdams et al. (1942): 1/μ = x/μ_v + (1 − x)/μ_l.  The usual
+    /// choice for homogeneous-flow pressure-drop models.
+    #[default]
+    McAdams,
+    /// Dukler et al. (1964): μ = ρ_h·[x·μ_v/ρ_v + (1 − x)·μ_l/ρ_l],
+    /// i.e. weighted by the homogeneous volume fractions.  Close to the
+    /// vapor value except near Q = 0.
+    Dukler,
+    /// Linear in the mass quality on a logarithmic scale:
+    /// μ = μ_l^(1 − x)·μ_v^x.  Between Cicchitti and McAdams.
+    LinearMass,
+}
+
+impl TwoPhaseViscosityModel {
+    /// Two-phase value at quality `x` (0–1) from the liquid and vapor
+    /// values and the saturated densities (any consistent unit).
+    pub fn combine(self, x: f64, liquid: f64, vapor: f64, rho_liquid: f64, rho_vapor: f64) -> f64 {
+        match self {
+            Self::Cicchitti => x * vapor + (1.0 - x) * liquid,
+            Self::McAdams => 1.0 / (x / vapor + (1.0 - x) / liquid),
+            Self::Dukler => {
+                let rho_h = 1.0 / (x / rho_vapor + (1.0 - x) / rho_liquid);
+                rho_h * (x * vapor / rho_vapor + (1.0 - x) * liquid / rho_liquid)
+            }
+            Self::LinearMass => liquid.powf(1.0 - x) * vapor.powf(x),
+        }
+    }
+}
+
+/// Model for the speed of sound of a two-phase mixture, see
+/// [`Fluid::two_phase_sound_speed`](crate::Fluid::two_phase_sound_speed).
+///
+/// `x` is the vapor quality, w_l / w_v and ρ_l / ρ_v the saturated
+/// sound speeds and densities, ρ = 1 / (x/ρ_v + (1 − x)/ρ_l) the
+/// homogeneous density.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SoundModel {
+    /// Wood's equation, 1/(ρ·w²) = α/(ρ_v·w_v²) + (1 − α)/(ρ_l·w_l²)
+    /// with the no-slip void fraction α = x·ρ_l / (x·ρ_l + (1 − x)·ρ_v).
+    /// The phases move together and share one pressure, so the bubbly
+    /// mixture is as compressible as its vapor but as heavy as its
+    /// liquid: the result drops far below both saturated values.  Suits
+    /// well-mixed flows (bubbly flow, flashing jets, choking).  Heat and
+    /// mass transfer during the wave are neglected.
+    #[default]
+    HomogeneousEquilibrium,
+    /// Mass-weighted average x·w_v + (1 − x)·w_l: each phase carries the
+    /// wave at its own speed without exchanging momentum, mass or heat
+    /// with the other.  Lies between the saturated values; an upper
+    /// bound suited to separated (stratified, annular) flows and to
+    /// high-frequency signals.
+    Frozen,
+}
+
+impl SoundModel {
+    /// Two-phase sound speed at quality `x` (0–1) from the saturated
+    /// sound speeds and densities (any consistent units).
+    pub fn combine(self, x: f64, liquid: f64, vapor: f64, rho_liquid: f64, rho_vapor: f64) -> f64 {
+        match self {
+            Self::HomogeneousEquilibrium => {
+                let rho = 1.0 / (x / rho_vapor + (1.0 - x) / rho_liquid);
+                let alpha = x * rho_liquid / (x * rho_liquid + (1.0 - x) * rho_vapor);
+                let compressibility = alpha / (rho_vapor * vapor * vapor)
+                    + (1.0 - alpha) / (rho_liquid * liquid * liquid);
+                (1.0 / (rho * compressibility)).sqrt()
+            }
+            Self::Frozen => x * vapor + (1.0 - x) * liquid,
+        }
+    }
+}
+
+// ── Reference state ─────────────────────────────────────────────────
+
+/// Enthalpy/entropy reference state passed to `SETUPdll` as `hrf`.
+///
+/// Only the zero points of h and s (and of u, a, g) change; all other
+/// properties are unaffected.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ReferenceState {
+    /// `"DEF"`: the default state of each fluid file (IIR for most
+    /// refrigerants, NBP or the ideal-gas state for other fluids).
+    #[default]
+    Default,
+    /// `"NBP"`: h = 0 and s = 0 for the saturated liquid at the normal
+    /// boiling point.
+    NormalBoilingPoint,
+    /// `"ASH"`: h = 0 and s = 0 for the saturated liquid at −40 °C
+    /// (ASHRAE convention).
+    Ashrae,
+    /// `"IIR"`: h = 200 kJ/kg and s = 1 kJ/(kg·K) for the saturated
+    /// liquid at 0 °C (IIR convention).
+    Iir,
+}
+
+impl ReferenceState {
+    /// Three-letter `hrf` code understood by REFPROP.
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::Default => "DEF",
+            Self::NormalBoilingPoint => "NBP",
+            Self::Ashrae => "ASH",
+            Self::Iir => "IIR",
+        }
+    }
+}
+
+impl std::str::FromStr for ReferenceState {
+    type Err = crate::error::RefpropError;
+
+    /// Parse a REFPROP code (`"DEF"`, `"NBP"`, `"ASH"`, `"IIR"`) or a
+    /// variant name, case-insensitively.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [Self::Default, Self::NormalBoilingPoint, Self::Ashrae, Self::Iir]
+            .into_iter()
+            .find(|r| {
+                r.code().eq_ignore_ascii_case(s) || format!("{r:?}").eq_ignore_ascii_case(s)
+            })
+            .ok_or_else(|| {
+                crate::error::RefpropError::InvalidInput(format!(
+                    "Unknown reference state \"{s}\" (expected DEF, NBP, ASH or IIR)"
+                ))
+            })
+    }
+}
+
+// ── Phase ───────────────────────────────────────────────────────────
+
+/// Phase of a thermodynamic state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    /// Subcooled or saturated liquid (T < Tc).
+    Liquid,
+    /// Superheated or saturated vapor (T < Tc).
+    Vapor,
+    /// Inside the vapor–liquid dome (0 < Q < 1).
+    TwoPhase,
+    /// At or above the critical temperature.
+    Supercritical,
+}
+
+impl Phase {
+    /// Classify a state from its temperature, REFPROP molar quality
+    /// (0–1, with out-of-range sentinels for single phase) and the
+    /// critical temperature, all in REFPROP units.
+    pub fn classify(t: f64, q: f64, tc: f64) -> Self {
+        if q > 0.0 && q < 1.0 {
+            Self::TwoPhase
+        } else if t >= tc {
+            Self::Supercritical
+        } else if q <= 0.0 {
+            Self::Liquid
+        } else {
+            Self::Vapor
+        }
+    }
+}
+
+impl std::fmt::Display for Phase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Liquid => "liquid",
+            Self::Vapor => "vapor",
+            Self::TwoPhase => "two-phase",
+            Self::Supercritical => "supercritical",
+        };
+        f.write_str(name)
+    }
+}
+
+// ── Lock diagnostics ────────────────────────────────────────────────
+
+/// Contention counters of the process-wide REFPROP lock, see
+/// [`Fluid::lock_stats`](crate::Fluid::lock_stats).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LockStats {
+    /// Number of times the lock was acquired.
+    pub acquisitions: u64,
+    /// Sum of the time spent waiting for the lock.
+    pub total_wait: std::time::Duration,
+    /// Longest single wait for the lock.
+    pub max_wait: std::time::Duration,
+}
+
+impl LockStats {
+    /// Mean wait per acquisition (zero before the first one).
+    pub fn mean_wait(&self) -> std::time::Duration {
+        if self.acquisitions == 0 {
+            std::time::Duration::ZERO
+        } else {
+            self.total_wait / self.acquisitions.min(u32::MAX as u64) as u32
+        }
+    }
+}
+
+// ── Installation self-test ──────────────────────────────────────────
+
+/// One reference point of [`Fluid::self_test`](crate::Fluid::self_test),
+/// in REFPROP units.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SelfTestCheck {
+    /// What was checked, e.g. `"R134A Psat(273.15 K)"`
+    pub name: String,
+    /// Reference value
+    pub expected: f64,
+    /// Value computed by the installation, `None` when it failed
+    pub measured: Option<f64>,
+    /// Accepted relative deviation
+    pub tolerance: f64,
+    /// Unit of `expected` and `measured`
+    pub unit: &'static str,
+    /// Error raised while loading the fluid or computing the value
+    pub error: Option<String>,
+}
+
+impl SelfTestCheck {
+    /// `true` when the value was computed and lies within the tolerance.
+    pub fn passed(&self) -> bool {
+        self.measured
+            .is_some_and(|m| ((m - self.expected) / self.expected).abs() <= self.tolerance)
+    }
+}
+
+/// Outcome of [`Fluid::self_test`](crate::Fluid::self_test).
+#[derive(Debug, Clone, PartialEq)]
+pub struct SelfTestReport {
+    /// Every reference point, in the order they were run.
+    pub checks: Vec<SelfTestCheck>,
+}
+
+impl SelfTestReport {
+    /// `true` when every check passed.
+    pub fn all_passed(&self) -> bool {
+        self.checks.iter().all(SelfTestCheck::passed)
+    }
+
+    /// The checks that did not pass.
+    pub fn failures(&self) -> impl Iterator<Item = &SelfTestCheck> {
+        self.checks.iter().filter(|c| !c.passed())
+    }
+}
+
+impl std::fmt::Display for SelfTestReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, c) in self.checks.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            let status = if c.passed() { "PASS" } else { "FAIL" };
+            write!(
+                f,
+                "[{status}] {:<28} expected {:.4} {}",
+                c.name, c.expected, c.unit
+            )?;
+            match (c.measured, &c.error) {
+                (Some(m), _) => write!(f, ", got {m:.4}")?,
+                (None, Some(e)) => write!(f, ", error: {e}")?,
+                (None, None) => {}
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,122 @@
+//! A resolved state point with cached properties.
+//!
+//! [`Fluid::at_tp`] flashes once and returns a [`StatePoint`]; its
+//! thermodynamic accessors read the cached flash result, and transport
+//! properties are evaluated on first use at the resolved (T, D) without
+//! repeating the flash.
+//!
+//! ```no_run
+//! use refprop::{Fluid, UnitSystem};
+//!
+//! let r134a = Fluid::with_units("R134A", UnitSystem::engineering())?;
+//! let state = r134a.at_tp(25.0, 1.0)?;
+//! let (h, s) = (state.enthalpy(), state.entropy());
+//! let eta = state.viscosity()?;
+//! # Ok::<(), refprop::RefpropError>(())
+//! ```
+
+use std::cell::OnceCell;
+
+use crate::error::Result;
+use crate::fluid::Fluid;
+use crate::properties::{ThermoProp, TransportProps};
+
+/// One flashed state of a [`Fluid`], all values in the fluid's
+/// configured units.
+pub struct StatePoint<'a> {
+    fluid: &'a Fluid,
+    props: ThermoProp,
+    transport: OnceCell<TransportProps>,
+}
+
+impl<'a> StatePoint<'a> {
+    pub(crate) fn new(fluid: &'a Fluid, props: ThermoProp) -> Self {
+        Self {
+            fluid,
+            props,
+            transport: OnceCell::new(),
+        }
+    }
+
+    /// All thermodynamic properties of the state.
+    pub fn props(&self) -> &ThermoProp {
+        &self.props
+    }
+
+    /// Temperature
+    pub fn temperature(&self) -> f64 {
+        self.props.temperature
+    }
+
+    /// Pressure
+    pub fn pressure(&self) -> f64 {
+        self.props.pressure
+    }
+
+    /// Density
+    pub fn density(&self) -> f64 {
+        self.props.density
+    }
+
+    /// Enthalpy
+    pub fn enthalpy(&self) -> f64 {
+        self.props.enthalpy
+    }
+
+    /// Entropy
+    pub fn entropy(&self) -> f64 {
+        self.props.entropy
+    }
+
+    /// Internal energy
+    pub fn internal_energy(&self) -> f64 {
+        self.props.internal_energy
+    }
+
+    /// Isochoric heat capacity
+    pub fn cv(&self) -> f64 {
+        self.props.cv
+    }
+
+    /// Isobaric heat capacity
+    pub fn cp(&self) -> f64 {
+        self.props.cp
+    }
+
+    /// Speed of sound (m/s)
+    pub fn sound_speed(&self) -> f64 {
+        self.props.sound_speed
+    }
+
+    /// Quality in percent (out-of-range sentinel for single phase).
+    pub fn quality(&self) -> f64 {
+        self.props.quality
+    }
+
+    /// Viscosity and thermal conductivity, evaluated once on first use.
+    ///
+    /// Single-phase states call `TRNPRPdll` at the resolved (T, D);
+    /// two-phase states use [`Fluid::transport_tq`].
+    pub fn transport(&self) -> Result<&TransportProps> {
+        if let Some(trn) = self.transport.get() {
+            return Ok(trn);
+        }
+        let p = &self.props;
+        let trn = if p.quality > 0.0 && p.quality < 100.0 {
+            self.fluid.transport_tq(p.temperature, p.quality)?
+        } else {
+            self.fluid.transport(p.temperature, p.density)?
+        };
+        Ok(self.transport.get_or_init(|| trn))
+    }
+
+    /// Dynamic viscosity
+    pub fn viscosity(&self) -> Result<f64> {
+        Ok(self.transport()?.viscosity)
+    }
+
+    /// Thermal conductivity
+    pub fn thermal_conductivity(&self) -> Result<f64> {
+        Ok(self.transport()?.thermal_conductivity)
+    }
+}
@@ -7,10 +7,13 @@
 #![allow(non_snake_case)]
 
 use std::os::raw::{c_char, c_double, c_int, c_long};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use libloading::Library;
 
+#[cfg(feature = "mock")]
+mod mock;
+
 // ── REFPROP constants ───────────────────────────────────────────────
 pub const REFPROP_STRLEN: usize = 255;
 pub const REFPROP_FILESTR: usize = 10000;
@@ -107,6 +110,30 @@ type FnFlashKr = unsafe extern "C" fn(
     c_long,
 );
 
+/// PQFLSHdll(p, q, z, kq, t, d, dl, dv, x, y, e, h, s, cv, cp, w, ierr,
+/// herr, herr_length) – `kq` is the integer quality basis
+type FnPqflsh = unsafe extern "C" fn(
+    *const c_double,
+    *const c_double,
+    *const c_double,
+    *const c_int,
+    *mut c_double,
+    *mut c_double,
+    *mut c_double,
+    *mut c_double,
+    *mut c_double,
+    *mut c_double,
+    *mut c_double,
+    *mut c_double,
+    *mut c_double,
+    *mut c_double,
+    *mut c_double,
+    *mut c_double,
+    *mut c_int,
+    *mut c_char,
+    c_long,
+);
+
 /// SATTdll / SATPdll – same signature:
 /// (in, z, kph, out1..out5, ierr, herr, herr_length)
 type FnSat = unsafe extern "C" fn(
@@ -123,7 +150,24 @@ type FnSat = unsafe extern "C" fn(
     c_long,
 );
 
-/// CRITPdll(z, tc, pc, dc, ierr, herr, herr_length)
+/// SATDdll(d, z, kph, kr, t, p, dl, dv, x, y, ierr, herr, herr_length)
+type FnSatd = unsafe extern "C" fn(
+    *const c_double,
+    *const c_double,
+    *const c_int,
+    *mut c_int,
+    *mut c_double,
+    *mut c_double,
+    *mut c_double,
+    *mut c_double,
+    *mut c_double,
+    *mut c_double,
+    *mut c_int,
+    *mut c_char,
+    c_long,
+);
+
+/// CRITPdll / CRTPNTdll(z, tc, pc, dc, ierr, herr, herr_length)
 type FnCritp = unsafe extern "C" fn(
     *const c_double,
     *mut c_double,
@@ -193,42 +237,238 @@ type FnInfo = unsafe extern "C" fn(
     *mut c_double,
 );
 
+/// TPRHOdll(t, p, z, kph, kguess, d, ierr, herr, herr_length)
+type FnTprho = unsafe extern "C" fn(
+    *const c_double,
+    *const c_double,
+    *const c_double,
+    *const c_int,
+    *const c_int,
+    *mut c_double,
+    *mut c_int,
+    *mut c_char,
+    c_long,
+);
+
+/// HSFL1dll(h, s, z, dmin, dmax, t, d, ierr, herr, herr_length)
+type FnHsfl1 = unsafe extern "C" fn(
+    *const c_double,
+    *const c_double,
+    *const c_double,
+    *const c_double,
+    *const c_double,
+    *mut c_double,
+    *mut c_double,
+    *mut c_int,
+    *mut c_char,
+    c_long,
+);
+
+/// DPDDdll(t, d, z, dpdd)
+type FnDpdd =
+    unsafe extern "C" fn(*const c_double, *const c_double, *const c_double, *mut c_double);
+
+/// B12dll(t, z, b) – second virial coefficient of the mixture z
+type FnB12 = unsafe extern "C" fn(*const c_double, *const c_double, *mut c_double);
+
+/// CP0dll(t, z, cp0) – ideal-gas isobaric heat capacity of the mixture z
+type FnCp0 = unsafe extern "C" fn(*const c_double, *const c_double, *mut c_double);
+
+/// SETMODdll(nc, htype, hmix, hcomp, ierr, herr, htype_length,
+/// hmix_length, hcomp_length, herr_length)
+type FnSetmod = unsafe extern "C" fn(
+    *const c_int,
+    *const c_char,
+    *const c_char,
+    *const c_char,
+    *mut c_int,
+    *mut c_char,
+    c_long,
+    c_long,
+    c_long,
+    c_long,
+);
+
+/// DPTSATKdll(icomp, t, kph, p, rho, csat, dpdt, ierr, herr, herr_length)
+/// – saturation slope dP/dT of a pure component
+type FnDptsatk = unsafe extern "C" fn(
+    *const c_int,
+    *const c_double,
+    *const c_int,
+    *mut c_double,
+    *mut c_double,
+    *mut c_double,
+    *mut c_double,
+    *mut c_int,
+    *mut c_char,
+    c_long,
+);
+
+/// PRESSdll(t, d, z, p) – pressure from the equation of state
+type FnPress =
+    unsafe extern "C" fn(*const c_double, *const c_double, *const c_double, *mut c_double);
+
+/// GETMODdll(icomp, htype, hcode, hcite, htype_length, hcode_length, hcite_length)
+type FnGetmod = unsafe extern "C" fn(
+    *const c_int,
+    *const c_char,
+    *mut c_char,
+    *mut c_char,
+    c_long,
+    c_long,
+    c_long,
+);
+
+/// RPVersion(hv, hv_length) (REFPROP 10+)
+type FnRpversion = unsafe extern "C" fn(*mut c_char, c_long);
+
+/// FLAGSdll(hflag, jflag, kflag, ierr, herr, hflag_length, herr_length)
+/// (REFPROP 10+)
+type FnFlags = unsafe extern "C" fn(
+    *const c_char,
+    *const c_int,
+    *mut c_int,
+    *mut c_int,
+    *mut c_char,
+    c_long,
+    c_long,
+);
+
+/// ERRMSGdll(ierr, herr, herr_length) (REFPROP 10+)
+type FnErrmsg = unsafe extern "C" fn(*const c_int, *mut c_char, c_long);
+
+/// GETENUMdll(iflag, henum, ienum, ierr, herr, henum_length, herr_length)
+/// (REFPROP 10+)
+type FnGetenum = unsafe extern "C" fn(
+    *const c_int,
+    *const c_char,
+    *mut c_int,
+    *mut c_int,
+    *mut c_char,
+    c_long,
+    c_long,
+);
+
 // ── Dynamic library wrapper ─────────────────────────────────────────
 
+/// Symbols every load requires, lenient or not: without them no fluid
+/// can be set up or evaluated.
+pub const CORE_SYMBOLS: [&str; 6] = [
+    "SETPATHdll",
+    "SETUPdll",
+    "THERMdll",
+    "INFOdll",
+    "DPDDdll",
+    "GETMODdll",
+];
+
+/// Every symbol [`RefpropLibrary`] binds.
+pub const ALL_SYMBOLS: [&str; 35] = [
+    "SETPATHdll",
+    "SETUPdll",
+    "TPFLSHdll",
+    "PHFLSHdll",
+    "PSFLSHdll",
+    "SATTdll",
+    "SATPdll",
+    "CRITPdll",
+    "TRNPRPdll",
+    "SETMIXdll",
+    "TDFLSHdll",
+    "PDFLSHdll",
+    "THFLSHdll",
+    "PQFLSHdll",
+    "TSFLSHdll",
+    "DHFLSHdll",
+    "DSFLSHdll",
+    "HSFLSHdll",
+    "THERMdll",
+    "INFOdll",
+    "TPRHOdll",
+    "HSFL1dll",
+    "DPDDdll",
+    "GETMODdll",
+    "B12dll",
+    "SATDdll",
+    "CP0dll",
+    "PRESSdll",
+    "SETMODdll",
+    "DPTSATKdll",
+    "FLAGSdll",
+    "RPVersion",
+    "ERRMSGdll",
+    "CRTPNTdll",
+    "GETENUMdll",
+];
+
 /// Holds a dynamically-loaded REFPROP shared library with **pre-resolved
 /// function pointers** for zero-overhead calls.
 ///
 /// All function symbols are resolved once at construction time.  If any
 /// required symbol is missing the constructor returns an error instead
-/// of panicking later.
+/// of panicking later.  Symbols that only exist in newer REFPROP
+/// versions are *optional*: their wrappers return
+/// `Err(SymbolNotFound)` when called on a DLL that lacks them.  The
+/// lenient constructors ([`load_lenient_from_dir`](Self::load_lenient_from_dir))
+/// treat every routine outside [`CORE_SYMBOLS`] that way.
 ///
 /// All methods are `unsafe` because they forward raw pointers to Fortran
 /// code that cannot be verified by the Rust compiler.
+///
+/// The handle is `Send + Sync` (plain function pointers and a
+/// [`Library`]), so it can be moved to or shared with other threads.
+/// REFPROP itself keeps global state, however: callers must serialize
+/// every call into it, as the safe API does with its global lock.
 pub struct RefpropLibrary {
     /// The underlying library handle.  Must stay alive to keep the DLL
-    /// loaded and the function pointers valid.
-    _lib: Library,
+    /// loaded and the function pointers valid.  `None` for the
+    /// in-process [`mock`](Self::mock).
+    _lib: Option<Library>,
+    /// File the library was loaded from (a bare file name when found
+    /// through the system search path).
+    path: PathBuf,
 
     // ── Cached function pointers ────────────────────────────────────
     fn_setpath: FnSetpath,
     fn_setup: FnSetup,
-    fn_tpflsh: FnFlash,
-    fn_phflsh: FnFlash,
-    fn_psflsh: FnFlash,
-    fn_satt: FnSat,
-    fn_satp: FnSat,
-    fn_critp: FnCritp,
-    fn_trnprp: FnTrnprp,
-    fn_setmix: FnSetmix,
-    fn_tdflsh: FnFlash,
-    fn_pdflsh: FnFlash,
-    fn_thflsh: FnFlashKr,
-    fn_tsflsh: FnFlashKr,
-    fn_dhflsh: FnFlash,
-    fn_dsflsh: FnFlash,
-    fn_hsflsh: FnFlash,
+    fn_tpflsh: Option<FnFlash>,
+    fn_phflsh: Option<FnFlash>,
+    fn_psflsh: Option<FnFlash>,
+    fn_satt: Option<FnSat>,
+    fn_satp: Option<FnSat>,
+    fn_critp: Option<FnCritp>,
+    fn_trnprp: Option<FnTrnprp>,
+    fn_setmix: Option<FnSetmix>,
+    fn_tdflsh: Option<FnFlash>,
+    fn_pdflsh: Option<FnFlash>,
+    fn_thflsh: Option<FnFlashKr>,
+    fn_pqflsh: Option<FnPqflsh>,
+    fn_tsflsh: Option<FnFlashKr>,
+    fn_dhflsh: Option<FnFlash>,
+    fn_dsflsh: Option<FnFlash>,
+    fn_hsflsh: Option<FnFlash>,
     fn_therm: FnTherm,
     fn_info: FnInfo,
+    fn_tprho: Option<FnTprho>,
+    fn_hsfl1: Option<FnHsfl1>,
+    fn_dpdd: FnDpdd,
+    fn_getmod: FnGetmod,
+    fn_b12: Option<FnB12>,
+    fn_satd: Option<FnSatd>,
+    fn_cp0: Option<FnCp0>,
+    fn_press: Option<FnPress>,
+    fn_setmod: Option<FnSetmod>,
+    fn_dptsatk: Option<FnDptsatk>,
+
+    // ── Optional function pointers (newer DLLs only) ────────────────
+    fn_flags: Option<FnFlags>,
+    fn_rpversion: Option<FnRpversion>,
+    fn_errmsg: Option<FnErrmsg>,
+    fn_crtpnt: Option<FnCritp>,
+    fn_getenum: Option<FnGetenum>,
+
+    /// Symbols the library does not export, in resolution order.
+    missing: Vec<&'static str>,
 }
 
 impl RefpropLibrary {
@@ -249,33 +489,114 @@ impl RefpropLibrary {
         Ok(*sym)
     }
 
-    /// Resolve **all** required REFPROP symbols from an already-loaded
-    /// library.  Fails on the first missing symbol.
-    fn resolve_all(lib: Library) -> Result<Self, RefpropSysError> {
+    /// Resolve every REFPROP symbol from an already-loaded library.
+    ///
+    /// [`CORE_SYMBOLS`] are always required.  The other routines are
+    /// required too when `lenient` is false (failing on the first
+    /// missing one); with `lenient` they are recorded as missing and
+    /// their wrappers return `Err(SymbolNotFound)` when called.
+    /// Symbols of newer REFPROP versions are never required.
+    fn resolve_all(lib: Library, path: PathBuf, lenient: bool) -> Result<Self, RefpropSysError> {
+        let mut missing = Vec::new();
+        let m = &mut missing;
+        let fn_tpflsh = Self::resolve_deferred(&lib, "TPFLSHdll", !lenient, m)?;
+        let fn_phflsh = Self::resolve_deferred(&lib, "PHFLSHdll", !lenient, m)?;
+        let fn_psflsh = Self::resolve_deferred(&lib, "PSFLSHdll", !lenient, m)?;
+        let fn_satt = Self::resolve_deferred(&lib, "SATTdll", !lenient, m)?;
+        let fn_satp = Self::resolve_deferred(&lib, "SATPdll", !lenient, m)?;
+        let fn_critp = Self::resolve_deferred(&lib, "CRITPdll", !lenient, m)?;
+        let fn_trnprp = Self::resolve_deferred(&lib, "TRNPRPdll", !lenient, m)?;
+        let fn_setmix = Self::resolve_deferred(&lib, "SETMIXdll", !lenient, m)?;
+        let fn_tdflsh = Self::resolve_deferred(&lib, "TDFLSHdll", !lenient, m)?;
+        let fn_pdflsh = Self::resolve_deferred(&lib, "PDFLSHdll", !lenient, m)?;
+        let fn_thflsh = Self::resolve_deferred(&lib, "THFLSHdll", !lenient, m)?;
+        let fn_pqflsh = Self::resolve_deferred(&lib, "PQFLSHdll", !lenient, m)?;
+        let fn_tsflsh = Self::resolve_deferred(&lib, "TSFLSHdll", !lenient, m)?;
+        let fn_dhflsh = Self::resolve_deferred(&lib, "DHFLSHdll", !lenient, m)?;
+        let fn_dsflsh = Self::resolve_deferred(&lib, "DSFLSHdll", !lenient, m)?;
+        let fn_hsflsh = Self::resolve_deferred(&lib, "HSFLSHdll", !lenient, m)?;
+        let fn_tprho = Self::resolve_deferred(&lib, "TPRHOdll", !lenient, m)?;
+        let fn_hsfl1 = Self::resolve_deferred(&lib, "HSFL1dll", !lenient, m)?;
+        let fn_b12 = Self::resolve_deferred(&lib, "B12dll", !lenient, m)?;
+        let fn_satd = Self::resolve_deferred(&lib, "SATDdll", !lenient, m)?;
+        let fn_cp0 = Self::resolve_deferred(&lib, "CP0dll", !lenient, m)?;
+        let fn_press = Self::resolve_deferred(&lib, "PRESSdll", !lenient, m)?;
+        let fn_setmod = Self::resolve_deferred(&lib, "SETMODdll", !lenient, m)?;
+        // Callers fall back to a finite difference without DPTSATKdll.
+        let fn_dptsatk = Self::resolve_deferred(&lib, "DPTSATKdll", false, m)?;
+        let fn_flags = Self::resolve_deferred(&lib, "FLAGSdll", false, m)?;
+        let fn_rpversion = Self::resolve_deferred(&lib, "RPVersion", false, m)?;
+        let fn_errmsg = Self::resolve_deferred(&lib, "ERRMSGdll", false, m)?;
+        let fn_crtpnt = Self::resolve_deferred(&lib, "CRTPNTdll", false, m)?;
+        let fn_getenum = Self::resolve_deferred(&lib, "GETENUMdll", false, m)?;
+
         Ok(Self {
             fn_setpath: Self::resolve(&lib, b"SETPATHdll\0")?,
             fn_setup: Self::resolve(&lib, b"SETUPdll\0")?,
-            fn_tpflsh: Self::resolve(&lib, b"TPFLSHdll\0")?,
-            fn_phflsh: Self::resolve(&lib, b"PHFLSHdll\0")?,
-            fn_psflsh: Self::resolve(&lib, b"PSFLSHdll\0")?,
-            fn_satt: Self::resolve(&lib, b"SATTdll\0")?,
-            fn_satp: Self::resolve(&lib, b"SATPdll\0")?,
-            fn_critp: Self::resolve(&lib, b"CRITPdll\0")?,
-            fn_trnprp: Self::resolve(&lib, b"TRNPRPdll\0")?,
-            fn_setmix: Self::resolve(&lib, b"SETMIXdll\0")?,
-            fn_tdflsh: Self::resolve(&lib, b"TDFLSHdll\0")?,
-            fn_pdflsh: Self::resolve(&lib, b"PDFLSHdll\0")?,
-            fn_thflsh: Self::resolve(&lib, b"THFLSHdll\0")?,
-            fn_tsflsh: Self::resolve(&lib, b"TSFLSHdll\0")?,
-            fn_dhflsh: Self::resolve(&lib, b"DHFLSHdll\0")?,
-            fn_dsflsh: Self::resolve(&lib, b"DSFLSHdll\0")?,
-            fn_hsflsh: Self::resolve(&lib, b"HSFLSHdll\0")?,
+            fn_tpflsh,
+            fn_phflsh,
+            fn_psflsh,
+            fn_satt,
+            fn_satp,
+            fn_critp,
+            fn_trnprp,
+            fn_setmix,
+            fn_tdflsh,
+            fn_pdflsh,
+            fn_thflsh,
+            fn_pqflsh,
+            fn_tsflsh,
+            fn_dhflsh,
+            fn_dsflsh,
+            fn_hsflsh,
             fn_therm: Self::resolve(&lib, b"THERMdll\0")?,
             fn_info: Self::resolve(&lib, b"INFOdll\0")?,
-            _lib: lib,
+            fn_tprho,
+            fn_hsfl1,
+            fn_dpdd: Self::resolve(&lib, b"DPDDdll\0")?,
+            fn_getmod: Self::resolve(&lib, b"GETMODdll\0")?,
+            fn_b12,
+            fn_satd,
+            fn_cp0,
+            fn_press,
+            fn_setmod,
+            fn_dptsatk,
+            fn_flags,
+            fn_rpversion,
+            fn_errmsg,
+            fn_crtpnt,
+            fn_getenum,
+            missing,
+            _lib: Some(lib),
+            path,
         })
     }
 
+    /// Resolve a symbol that lenient loads may skip: `Err` when it is
+    /// missing and `required`, otherwise `None` with the name recorded
+    /// in `missing`.
+    fn resolve_deferred<T: Copy>(
+        lib: &Library,
+        name: &'static str,
+        required: bool,
+        missing: &mut Vec<&'static str>,
+    ) -> Result<Option<T>, RefpropSysError> {
+        match Self::resolve(lib, format!("{name}\0").as_bytes()) {
+            Ok(f) => Ok(Some(f)),
+            Err(e) if required => Err(e),
+            Err(_) => {
+                missing.push(name);
+                Ok(None)
+            }
+        }
+    }
+
+    /// The function pointer of a symbol that may be missing, or
+    /// `Err(SymbolNotFound)` naming it.
+    fn require<T: Copy>(f: Option<T>, name: &str) -> Result<T, RefpropSysError> {
+        f.ok_or_else(|| RefpropSysError::SymbolNotFound(name.into()))
+    }
+
     // ── Constructors ────────────────────────────────────────────────
 
     /// Try to load the REFPROP shared library from a **directory** that
@@ -288,6 +609,19 @@ impl RefpropLibrary {
     /// All required symbols are resolved eagerly.  If any symbol is
     /// missing, an error is returned immediately.
     pub fn load_from_dir(dir: &Path) -> Result<Self, RefpropSysError> {
+        Self::load_dir(dir, false)
+    }
+
+    /// Like [`load_from_dir`](Self::load_from_dir), but only the
+    /// [`CORE_SYMBOLS`] are required: other missing routines are
+    /// listed by [`missing_symbols`](Self::missing_symbols) and their
+    /// wrappers return `Err(SymbolNotFound)` when called.  A DLL
+    /// without `SETMIXdll`, say, still serves pure fluids.
+    pub fn load_lenient_from_dir(dir: &Path) -> Result<Self, RefpropSysError> {
+        Self::load_dir(dir, true)
+    }
+
+    fn load_dir(dir: &Path, lenient: bool) -> Result<Self, RefpropSysError> {
         // Order matters: prefer 64-bit DLL on 64-bit targets.
         let candidates: &[&str] = if cfg!(target_os = "windows") {
             if cfg!(target_pointer_width = "64") {
@@ -309,7 +643,7 @@ impl RefpropLibrary {
             let full = dir.join(name);
             if full.exists() {
                 match unsafe { Library::new(&full) } {
-                    Ok(lib) => return Self::resolve_all(lib),
+                    Ok(lib) => return Self::resolve_all(lib, full, lenient),
                     Err(e) => {
                         errors.push(format!("{}: {e}", full.display()));
                     }
@@ -320,7 +654,7 @@ impl RefpropLibrary {
         // 2. Fall back to system-wide search (PATH / LD_LIBRARY_PATH)
         for name in candidates {
             if let Ok(lib) = unsafe { Library::new(*name) } {
-                return Self::resolve_all(lib);
+                return Self::resolve_all(lib, PathBuf::from(name), lenient);
             }
         }
 
@@ -340,9 +674,50 @@ impl RefpropLibrary {
 
     /// Load the REFPROP shared library from an **exact file path**.
     pub fn load_from_file(path: &Path) -> Result<Self, RefpropSysError> {
+        Self::load_file(path, false)
+    }
+
+    /// Lenient variant of [`load_from_file`](Self::load_from_file), see
+    /// [`load_lenient_from_dir`](Self::load_lenient_from_dir).
+    pub fn load_lenient_from_file(path: &Path) -> Result<Self, RefpropSysError> {
+        Self::load_file(path, true)
+    }
+
+    fn load_file(path: &Path, lenient: bool) -> Result<Self, RefpropSysError> {
         let lib = unsafe { Library::new(path) }
             .map_err(|e| RefpropSysError::LibraryLoadFailed(format!("{}: {e}", path.display())))?;
-        Self::resolve_all(lib)
+        Self::resolve_all(lib, path.to_path_buf(), lenient)
+    }
+
+    /// In-process stand-in for REFPROP (feature `mock`): ideal gases
+    /// with canned constants for `R134A`, `NITROGEN` and `CO2`, and only
+    /// the routines a single-phase flash needs.  Placeholder `.FLD`
+    /// files are written to `data_dir/fluids`, and `data_dir` serves as
+    /// the library's directory, so
+    /// [`Fluid::from_library`](crate::Fluid::from_library) accepts it.
+    #[cfg(feature = "mock")]
+    pub fn mock(data_dir: &Path) -> Result<Self, RefpropSysError> {
+        mock::library(data_dir)
+    }
+
+    /// Path of the loaded library file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Names of the bound REFPROP symbols that the library exports, in
+    /// [`ALL_SYMBOLS`] order.
+    pub fn resolved_symbols(&self) -> Vec<&'static str> {
+        ALL_SYMBOLS
+            .into_iter()
+            .filter(|name| !self.missing.contains(name))
+            .collect()
+    }
+
+    /// Names of the bound REFPROP symbols that the library does not
+    /// export; calling their wrappers returns `Err(SymbolNotFound)`.
+    pub fn missing_symbols(&self) -> &[&'static str] {
+        &self.missing
     }
 
     // ── REFPROP function wrappers ───────────────────────────────────
@@ -408,9 +783,10 @@ impl RefpropLibrary {
         ierr: *mut c_int,
         herr: *mut c_char,
         herr_length: c_long,
-    ) {
+    ) -> Result<(), RefpropSysError> {
+        let f = Self::require(self.fn_tpflsh, "TPFLSHdll")?;
         unsafe {
-            (self.fn_tpflsh)(
+            f(
                 t,
                 p,
                 z,
@@ -431,6 +807,7 @@ impl RefpropLibrary {
                 herr_length,
             );
         }
+        Ok(())
     }
 
     /// Pressure-enthalpy flash calculation.
@@ -454,9 +831,10 @@ impl RefpropLibrary {
         ierr: *mut c_int,
         herr: *mut c_char,
         herr_length: c_long,
-    ) {
+    ) -> Result<(), RefpropSysError> {
+        let f = Self::require(self.fn_phflsh, "PHFLSHdll")?;
         unsafe {
-            (self.fn_phflsh)(
+            f(
                 p,
                 h,
                 z,
@@ -477,6 +855,7 @@ impl RefpropLibrary {
                 herr_length,
             );
         }
+        Ok(())
     }
 
     /// Pressure-entropy flash calculation.
@@ -500,9 +879,10 @@ impl RefpropLibrary {
         ierr: *mut c_int,
         herr: *mut c_char,
         herr_length: c_long,
-    ) {
+    ) -> Result<(), RefpropSysError> {
+        let f = Self::require(self.fn_psflsh, "PSFLSHdll")?;
         unsafe {
-            (self.fn_psflsh)(
+            f(
                 p,
                 s,
                 z,
@@ -523,6 +903,7 @@ impl RefpropLibrary {
                 herr_length,
             );
         }
+        Ok(())
     }
 
     /// Saturation properties at a given temperature.
@@ -539,8 +920,10 @@ impl RefpropLibrary {
         ierr: *mut c_int,
         herr: *mut c_char,
         herr_length: c_long,
-    ) {
-        unsafe { (self.fn_satt)(t, z, kph, p, dl, dv, x, y, ierr, herr, herr_length) };
+    ) -> Result<(), RefpropSysError> {
+        let f = Self::require(self.fn_satt, "SATTdll")?;
+        unsafe { f(t, z, kph, p, dl, dv, x, y, ierr, herr, herr_length) };
+        Ok(())
     }
 
     /// Saturation properties at a given pressure.
@@ -557,8 +940,34 @@ impl RefpropLibrary {
         ierr: *mut c_int,
         herr: *mut c_char,
         herr_length: c_long,
-    ) {
-        unsafe { (self.fn_satp)(p, z, kph, t, dl, dv, x, y, ierr, herr, herr_length) };
+    ) -> Result<(), RefpropSysError> {
+        let f = Self::require(self.fn_satp, "SATPdll")?;
+        unsafe { f(p, z, kph, t, dl, dv, x, y, ierr, herr, herr_length) };
+        Ok(())
+    }
+
+    /// Saturation properties at a given density.  `kph` selects the
+    /// root where several exist (water near its density maximum); `kr`
+    /// returns whether `d` is the liquid (1) or vapor (2) density.
+    pub unsafe fn SATDdll(
+        &self,
+        d: *const c_double,
+        z: *const c_double,
+        kph: *const c_int,
+        kr: *mut c_int,
+        t: *mut c_double,
+        p: *mut c_double,
+        dl: *mut c_double,
+        dv: *mut c_double,
+        x: *mut c_double,
+        y: *mut c_double,
+        ierr: *mut c_int,
+        herr: *mut c_char,
+        herr_length: c_long,
+    ) -> Result<(), RefpropSysError> {
+        let f = Self::require(self.fn_satd, "SATDdll")?;
+        unsafe { f(d, z, kph, kr, t, p, dl, dv, x, y, ierr, herr, herr_length) };
+        Ok(())
     }
 
     /// Critical-point properties.
@@ -571,8 +980,10 @@ impl RefpropLibrary {
         ierr: *mut c_int,
         herr: *mut c_char,
         herr_length: c_long,
-    ) {
-        unsafe { (self.fn_critp)(z, tcrit, pcrit, dcrit, ierr, herr, herr_length) };
+    ) -> Result<(), RefpropSysError> {
+        let f = Self::require(self.fn_critp, "CRITPdll")?;
+        unsafe { f(z, tcrit, pcrit, dcrit, ierr, herr, herr_length) };
+        Ok(())
     }
 
     /// Transport properties (viscosity, thermal conductivity).
@@ -586,8 +997,10 @@ impl RefpropLibrary {
         ierr: *mut c_int,
         herr: *mut c_char,
         herr_length: c_long,
-    ) {
-        unsafe { (self.fn_trnprp)(t, d, z, eta, tcx, ierr, herr, herr_length) };
+    ) -> Result<(), RefpropSysError> {
+        let f = Self::require(self.fn_trnprp, "TRNPRPdll")?;
+        unsafe { f(t, d, z, eta, tcx, ierr, herr, herr_length) };
+        Ok(())
     }
 
     /// Load a predefined mixture from a `.MIX` file.
@@ -609,9 +1022,10 @@ impl RefpropLibrary {
         hrf_length: c_long,
         hfld_length: c_long,
         herr_length: c_long,
-    ) {
+    ) -> Result<(), RefpropSysError> {
+        let f = Self::require(self.fn_setmix, "SETMIXdll")?;
         unsafe {
-            (self.fn_setmix)(
+            f(
                 hmxnme,
                 hfmix,
                 hrf,
@@ -627,6 +1041,7 @@ impl RefpropLibrary {
                 herr_length,
             );
         }
+        Ok(())
     }
 
     /// Temperature-density flash calculation.
@@ -650,9 +1065,10 @@ impl RefpropLibrary {
         ierr: *mut c_int,
         herr: *mut c_char,
         herr_length: c_long,
-    ) {
+    ) -> Result<(), RefpropSysError> {
+        let f = Self::require(self.fn_tdflsh, "TDFLSHdll")?;
         unsafe {
-            (self.fn_tdflsh)(
+            f(
                 t,
                 d,
                 z,
@@ -673,6 +1089,7 @@ impl RefpropLibrary {
                 herr_length,
             );
         }
+        Ok(())
     }
 
     /// Pressure-density flash calculation.
@@ -696,9 +1113,10 @@ impl RefpropLibrary {
         ierr: *mut c_int,
         herr: *mut c_char,
         herr_length: c_long,
-    ) {
+    ) -> Result<(), RefpropSysError> {
+        let f = Self::require(self.fn_pdflsh, "PDFLSHdll")?;
         unsafe {
-            (self.fn_pdflsh)(
+            f(
                 p,
                 d,
                 z,
@@ -719,6 +1137,7 @@ impl RefpropLibrary {
                 herr_length,
             );
         }
+        Ok(())
     }
 
     /// Temperature-enthalpy flash calculation.
@@ -743,9 +1162,10 @@ impl RefpropLibrary {
         ierr: *mut c_int,
         herr: *mut c_char,
         herr_length: c_long,
-    ) {
+    ) -> Result<(), RefpropSysError> {
+        let f = Self::require(self.fn_thflsh, "THFLSHdll")?;
         unsafe {
-            (self.fn_thflsh)(
+            f(
                 t,
                 h,
                 z,
@@ -767,6 +1187,59 @@ impl RefpropLibrary {
                 herr_length,
             );
         }
+        Ok(())
+    }
+
+    /// Pressure-quality flash calculation.
+    ///
+    /// `kq`: **1** = molar quality, **2** = mass quality.
+    pub unsafe fn PQFLSHdll(
+        &self,
+        p: *const c_double,
+        q: *const c_double,
+        z: *const c_double,
+        kq: *const c_int,
+        t: *mut c_double,
+        d: *mut c_double,
+        dl: *mut c_double,
+        dv: *mut c_double,
+        x: *mut c_double,
+        y: *mut c_double,
+        e: *mut c_double,
+        h: *mut c_double,
+        s: *mut c_double,
+        cv: *mut c_double,
+        cp: *mut c_double,
+        w: *mut c_double,
+        ierr: *mut c_int,
+        herr: *mut c_char,
+        herr_length: c_long,
+    ) -> Result<(), RefpropSysError> {
+        let f = Self::require(self.fn_pqflsh, "PQFLSHdll")?;
+        unsafe {
+            f(
+                p,
+                q,
+                z,
+                kq,
+                t,
+                d,
+                dl,
+                dv,
+                x,
+                y,
+                e,
+                h,
+                s,
+                cv,
+                cp,
+                w,
+                ierr,
+                herr,
+                herr_length,
+            );
+        }
+        Ok(())
     }
 
     /// Temperature-entropy flash calculation.
@@ -791,9 +1264,10 @@ impl RefpropLibrary {
         ierr: *mut c_int,
         herr: *mut c_char,
         herr_length: c_long,
-    ) {
+    ) -> Result<(), RefpropSysError> {
+        let f = Self::require(self.fn_tsflsh, "TSFLSHdll")?;
         unsafe {
-            (self.fn_tsflsh)(
+            f(
                 t,
                 s,
                 z,
@@ -815,6 +1289,7 @@ impl RefpropLibrary {
                 herr_length,
             );
         }
+        Ok(())
     }
 
     /// Density-enthalpy flash calculation.
@@ -838,9 +1313,10 @@ impl RefpropLibrary {
         ierr: *mut c_int,
         herr: *mut c_char,
         herr_length: c_long,
-    ) {
+    ) -> Result<(), RefpropSysError> {
+        let f = Self::require(self.fn_dhflsh, "DHFLSHdll")?;
         unsafe {
-            (self.fn_dhflsh)(
+            f(
                 d,
                 h,
                 z,
@@ -861,6 +1337,7 @@ impl RefpropLibrary {
                 herr_length,
             );
         }
+        Ok(())
     }
 
     /// Density-entropy flash calculation.
@@ -884,9 +1361,10 @@ impl RefpropLibrary {
         ierr: *mut c_int,
         herr: *mut c_char,
         herr_length: c_long,
-    ) {
+    ) -> Result<(), RefpropSysError> {
+        let f = Self::require(self.fn_dsflsh, "DSFLSHdll")?;
         unsafe {
-            (self.fn_dsflsh)(
+            f(
                 d,
                 s,
                 z,
@@ -907,6 +1385,7 @@ impl RefpropLibrary {
                 herr_length,
             );
         }
+        Ok(())
     }
 
     /// Enthalpy-entropy flash calculation.
@@ -930,9 +1409,10 @@ impl RefpropLibrary {
         ierr: *mut c_int,
         herr: *mut c_char,
         herr_length: c_long,
-    ) {
+    ) -> Result<(), RefpropSysError> {
+        let f = Self::require(self.fn_hsflsh, "HSFLSHdll")?;
         unsafe {
-            (self.fn_hsflsh)(
+            f(
                 h,
                 s,
                 z,
@@ -953,6 +1433,7 @@ impl RefpropLibrary {
                 herr_length,
             );
         }
+        Ok(())
     }
 
     /// Compute thermodynamic properties from temperature and density.
@@ -992,6 +1473,268 @@ impl RefpropLibrary {
     ) {
         unsafe { (self.fn_info)(icomp, wmm, ttrp, tnbpt, tc, pc, dc, zc, acf, dip, rgas) };
     }
+
+    /// Density from temperature and pressure for a given phase root.
+    ///
+    /// `kph`: **1** = liquid root, **2** = vapor root.  `kguess`: **0**
+    /// = no initial guess, **1** = `d` holds an initial guess.
+    pub unsafe fn TPRHOdll(
+        &self,
+        t: *const c_double,
+        p: *const c_double,
+        z: *const c_double,
+        kph: *const c_int,
+        kguess: *const c_int,
+        d: *mut c_double,
+        ierr: *mut c_int,
+        herr: *mut c_char,
+        herr_length: c_long,
+    ) -> Result<(), RefpropSysError> {
+        let f = Self::require(self.fn_tprho, "TPRHOdll")?;
+        unsafe { f(t, p, z, kph, kguess, d, ierr, herr, herr_length) };
+        Ok(())
+    }
+
+    /// Single-phase enthalpy–entropy flash with the density searched in
+    /// `[dmin, dmax]` (mol/L), which selects the branch when (h, s)
+    /// has several solutions.
+    pub unsafe fn HSFL1dll(
+        &self,
+        h: *const c_double,
+        s: *const c_double,
+        z: *const c_double,
+        dmin: *const c_double,
+        dmax: *const c_double,
+        t: *mut c_double,
+        d: *mut c_double,
+        ierr: *mut c_int,
+        herr: *mut c_char,
+        herr_length: c_long,
+    ) -> Result<(), RefpropSysError> {
+        let f = Self::require(self.fn_hsfl1, "HSFL1dll")?;
+        unsafe { f(h, s, z, dmin, dmax, t, d, ierr, herr, herr_length) };
+        Ok(())
+    }
+
+    /// Isothermal derivative (∂P/∂D)_T in kPa/(mol/L).
+    ///
+    /// Evaluated directly from the equation of state, so it is also
+    /// defined at metastable and unstable densities inside the dome.
+    pub unsafe fn DPDDdll(
+        &self,
+        t: *const c_double,
+        d: *const c_double,
+        z: *const c_double,
+        dpdd: *mut c_double,
+    ) {
+        unsafe { (self.fn_dpdd)(t, d, z, dpdd) };
+    }
+
+    /// Second virial coefficient B (L/mol) of the mixture `z` at `t`,
+    /// Σᵢ Σⱼ zᵢ·zⱼ·Bᵢⱼ.
+    pub unsafe fn B12dll(
+        &self,
+        t: *const c_double,
+        z: *const c_double,
+        b: *mut c_double,
+    ) -> Result<(), RefpropSysError> {
+        let f = Self::require(self.fn_b12, "B12dll")?;
+        unsafe { f(t, z, b) };
+        Ok(())
+    }
+
+    /// Ideal-gas isobaric heat capacity cp⁰ (J/(mol·K)) of the mixture
+    /// `z` at `t`.
+    pub unsafe fn CP0dll(
+        &self,
+        t: *const c_double,
+        z: *const c_double,
+        cp0: *mut c_double,
+    ) -> Result<(), RefpropSysError> {
+        let f = Self::require(self.fn_cp0, "CP0dll")?;
+        unsafe { f(t, z, cp0) };
+        Ok(())
+    }
+
+    /// Pressure (kPa) at (`t`, `d`) straight from the equation of
+    /// state, with no phase check.
+    pub unsafe fn PRESSdll(
+        &self,
+        t: *const c_double,
+        d: *const c_double,
+        z: *const c_double,
+        p: *mut c_double,
+    ) -> Result<(), RefpropSysError> {
+        let f = Self::require(self.fn_press, "PRESSdll")?;
+        unsafe { f(t, d, z, p) };
+        Ok(())
+    }
+
+    /// Select non-default models for the next `SETUPdll` call.
+    ///
+    /// `htype` is the model type (`"EOS"`, `"ETA"`, `"TCX"`, `"STN"`,
+    /// or `"NBS"` to reset all to the recommended models), `hmix` the
+    /// mixture model and `hcomp` one 3-character code per component,
+    /// back to back.
+    pub unsafe fn SETMODdll(
+        &self,
+        nc: *const c_int,
+        htype: *const c_char,
+        hmix: *const c_char,
+        hcomp: *const c_char,
+        ierr: *mut c_int,
+        herr: *mut c_char,
+        htype_length: c_long,
+        hmix_length: c_long,
+        hcomp_length: c_long,
+        herr_length: c_long,
+    ) -> Result<(), RefpropSysError> {
+        let f = Self::require(self.fn_setmod, "SETMODdll")?;
+        unsafe {
+            f(
+                nc,
+                htype,
+                hmix,
+                hcomp,
+                ierr,
+                herr,
+                htype_length,
+                hmix_length,
+                hcomp_length,
+                herr_length,
+            )
+        };
+        Ok(())
+    }
+
+    /// Saturation state and slope dP/dT (kPa/K) of pure component
+    /// `icomp` at `t`.
+    ///
+    /// `kph` selects the saturated liquid (`1`) or vapor (`2`); `rho`
+    /// is its density and `csat` the heat capacity along the
+    /// saturation line.
+    pub unsafe fn DPTSATKdll(
+        &self,
+        icomp: *const c_int,
+        t: *const c_double,
+        kph: *const c_int,
+        p: *mut c_double,
+        rho: *mut c_double,
+        csat: *mut c_double,
+        dpdt: *mut c_double,
+        ierr: *mut c_int,
+        herr: *mut c_char,
+        herr_length: c_long,
+    ) -> Result<(), RefpropSysError> {
+        let f = Self::require(self.fn_dptsatk, "DPTSATKdll")?;
+        unsafe { f(icomp, t, kph, p, rho, csat, dpdt, ierr, herr, herr_length) };
+        Ok(())
+    }
+
+    /// Model code (`hcode`, 3 chars) and literature citation (`hcite`)
+    /// of the sub-model `htype` (e.g. `"ETA"`, `"TCX"`, `"EOS"`) for
+    /// component `icomp`.
+    pub unsafe fn GETMODdll(
+        &self,
+        icomp: *const c_int,
+        htype: *const c_char,
+        hcode: *mut c_char,
+        hcite: *mut c_char,
+        htype_length: c_long,
+        hcode_length: c_long,
+        hcite_length: c_long,
+    ) {
+        unsafe {
+            (self.fn_getmod)(
+                icomp,
+                htype,
+                hcode,
+                hcite,
+                htype_length,
+                hcode_length,
+                hcite_length,
+            )
+        };
+    }
+
+    /// Version string of the loaded library, e.g. `"10.0"` (REFPROP 10+).
+    pub unsafe fn RPVersion(
+        &self,
+        hv: *mut c_char,
+        hv_length: c_long,
+    ) -> Result<(), RefpropSysError> {
+        let f = Self::require(self.fn_rpversion, "RPVersion")?;
+        unsafe { f(hv, hv_length) };
+        Ok(())
+    }
+
+    /// Get or set an internal REFPROP flag (REFPROP 10+).
+    ///
+    /// `jflag` is the new value (`-999` queries without changing);
+    /// `kflag` receives the value in effect after the call.
+    pub unsafe fn FLAGSdll(
+        &self,
+        hflag: *const c_char,
+        jflag: *const c_int,
+        kflag: *mut c_int,
+        ierr: *mut c_int,
+        herr: *mut c_char,
+        hflag_length: c_long,
+        herr_length: c_long,
+    ) -> Result<(), RefpropSysError> {
+        let f = Self::require(self.fn_flags, "FLAGSdll")?;
+        unsafe { f(hflag, jflag, kflag, ierr, herr, hflag_length, herr_length) };
+        Ok(())
+    }
+
+    /// True critical point of a mixture by direct solution of the
+    /// critical conditions (REFPROP 10+); same arguments as `CRITPdll`.
+    pub unsafe fn CRTPNTdll(
+        &self,
+        z: *const c_double,
+        tcrit: *mut c_double,
+        pcrit: *mut c_double,
+        dcrit: *mut c_double,
+        ierr: *mut c_int,
+        herr: *mut c_char,
+        herr_length: c_long,
+    ) -> Result<(), RefpropSysError> {
+        let f = Self::require(self.fn_crtpnt, "CRTPNTdll")?;
+        unsafe { f(z, tcrit, pcrit, dcrit, ierr, herr, herr_length) };
+        Ok(())
+    }
+
+    /// Standard message text for error code `ierr` (REFPROP 10+).
+    pub unsafe fn ERRMSGdll(
+        &self,
+        ierr: *const c_int,
+        herr: *mut c_char,
+        herr_length: c_long,
+    ) -> Result<(), RefpropSysError> {
+        let f = Self::require(self.fn_errmsg, "ERRMSGdll")?;
+        unsafe { f(ierr, herr, herr_length) };
+        Ok(())
+    }
+
+    /// Integer code of the name `henum` for the unified `REFPROPdll`
+    /// interface (REFPROP 10+).
+    ///
+    /// `iflag` selects the table searched (`0` all, `1` units, `2`
+    /// properties); an unknown name sets `ierr > 0`.
+    pub unsafe fn GETENUMdll(
+        &self,
+        iflag: *const c_int,
+        henum: *const c_char,
+        ienum: *mut c_int,
+        ierr: *mut c_int,
+        herr: *mut c_char,
+        henum_length: c_long,
+        herr_length: c_long,
+    ) -> Result<(), RefpropSysError> {
+        let f = Self::require(self.fn_getenum, "GETENUMdll")?;
+        unsafe { f(iflag, henum, ienum, ierr, herr, henum_length, herr_length) };
+        Ok(())
+    }
 }
 
 // ── String helpers ──────────────────────────────────────────────────
@@ -0,0 +1,428 @@
+//! In-process stand-in for the REFPROP library (feature `mock`).
+//!
+//! The routines below have REFPROP's calling convention, so a
+//! [`RefpropLibrary`] built from them goes through exactly the same
+//! wrappers, backend and unit conversion as a loaded DLL.  They model a
+//! handful of pure fluids as ideal gases with constant heat capacity
+//! and canned constants; anything else (mixtures, saturation,
+//! transport, …) is left unbound and fails with `SymbolNotFound`.
+//!
+//! State is process-global, like REFPROP's: `SETUPdll` selects the
+//! fluid every later call works on.
+
+use std::os::raw::{c_char, c_double, c_int, c_long};
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use super::{RefpropLibrary, RefpropSysError};
+
+/// Molar gas constant, J/(mol·K).
+const R: f64 = 8.314_462_618;
+/// Reference state of the ideal-gas enthalpy and entropy: h = 0 and
+/// s = 0 at 298.15 K and 101.325 kPa.
+const T0: f64 = 298.15;
+const P0: f64 = 101.325;
+/// REFPROP's quality code for a single-phase state.
+const SINGLE_PHASE: f64 = 998.0;
+
+/// Canned constants of one fluid, in REFPROP units.
+struct CannedFluid {
+    name: &'static str,
+    molar_mass: f64,
+    ttrp: f64,
+    tnbp: f64,
+    tc: f64,
+    pc: f64,
+    dc: f64,
+    acf: f64,
+    dipole: f64,
+    /// Ideal-gas heat capacity at 300 K, J/(mol·K), used at every T.
+    cp0: f64,
+}
+
+/// Fluids the mock knows, by `.FLD` stem (REFPROP 10 constants).
+const FLUIDS: [CannedFluid; 3] = [
+    CannedFluid {
+        name: "R134A",
+        molar_mass: 102.032,
+        ttrp: 169.85,
+        tnbp: 247.076,
+        tc: 374.21,
+        pc: 4059.28,
+        dc: 5.017053,
+        acf: 0.32684,
+        dipole: 2.058,
+        cp0: 87.0,
+    },
+    CannedFluid {
+        name: "NITROGEN",
+        molar_mass: 28.01348,
+        ttrp: 63.151,
+        tnbp: 77.355,
+        tc: 126.192,
+        pc: 3395.8,
+        dc: 11.1839,
+        acf: 0.0372,
+        dipole: 0.0,
+        cp0: 29.12,
+    },
+    CannedFluid {
+        name: "CO2",
+        molar_mass: 44.0098,
+        ttrp: 216.592,
+        tnbp: 194.686,
+        tc: 304.1282,
+        pc: 7377.3,
+        dc: 10.6249,
+        acf: 0.22394,
+        dipole: 0.0,
+        cp0: 37.22,
+    },
+];
+
+/// Index into [`FLUIDS`] of the fluid set up last.
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+
+fn fluid() -> &'static CannedFluid {
+    &FLUIDS[CURRENT.load(Ordering::Relaxed)]
+}
+
+/// Ideal-gas state at (T, D): (p, e, h, s, cv, cp, w).
+fn state_td(t: f64, d: f64) -> (f64, f64, f64, f64, f64, f64, f64) {
+    let f = fluid();
+    let p = d * R * t;
+    let cp = f.cp0;
+    let cv = cp - R;
+    let h = cp * (t - T0);
+    let e = h - R * t;
+    let s = cp * (t / T0).ln() - R * (p / P0).ln();
+    let w = (cp / cv * R * t / (f.molar_mass / 1000.0)).sqrt();
+    (p, e, h, s, cv, cp, w)
+}
+
+/// Copy `msg` into a Fortran error buffer of `len` bytes.
+unsafe fn write_herr(herr: *mut c_char, len: c_long, msg: &str) {
+    let len = len as usize;
+    for (i, b) in msg.bytes().take(len.saturating_sub(1)).enumerate() {
+        unsafe { *herr.add(i) = b as c_char };
+    }
+}
+
+/// The phase outputs shared by TPFLSHdll, PHFLSHdll and PSFLSHdll, for
+/// a single-phase state of density `d` with x = y = z.
+unsafe fn write_flash(
+    z: *const c_double,
+    d: f64,
+    dl: *mut c_double,
+    dv: *mut c_double,
+    x: *mut c_double,
+    y: *mut c_double,
+    q: *mut c_double,
+) {
+    unsafe {
+        *dl = d;
+        *dv = d;
+        *x = *z;
+        *y = *z;
+        *q = SINGLE_PHASE;
+    }
+}
+
+extern "C" fn setpath(_hpath: *const c_char, _length: c_long) {}
+
+unsafe extern "C" fn setup(
+    nc: *const c_int,
+    hfld: *const c_char,
+    _hfmix: *const c_char,
+    _hrf: *const c_char,
+    ierr: *mut c_int,
+    herr: *mut c_char,
+    hfld_length: c_long,
+    _hfmix_length: c_long,
+    _hrf_length: c_long,
+    herr_length: c_long,
+) {
+    let raw = unsafe { std::slice::from_raw_parts(hfld.cast::<u8>(), hfld_length as usize) };
+    let raw = raw.split(|&b| b == 0).next().unwrap_or_default();
+    let name = String::from_utf8_lossy(raw);
+    let stem = name.trim().trim_end_matches(".FLD");
+    let stem = stem.rsplit(['/', '\\']).next().unwrap_or(stem);
+
+    let found = FLUIDS.iter().position(|f| f.name == stem);
+    unsafe {
+        match found {
+            Some(i) if *nc == 1 => {
+                CURRENT.store(i, Ordering::Relaxed);
+                *ierr = 0;
+            }
+            _ => {
+                *ierr = 101;
+                write_herr(
+                    herr,
+                    herr_length,
+                    &format!("[mock] no canned data for {name}"),
+                );
+            }
+        }
+    }
+}
+
+unsafe extern "C" fn therm(
+    t: *const c_double,
+    d: *const c_double,
+    _z: *const c_double,
+    p: *mut c_double,
+    e: *mut c_double,
+    h: *mut c_double,
+    s: *mut c_double,
+    cv: *mut c_double,
+    cp: *mut c_double,
+    w: *mut c_double,
+    hjt: *mut c_double,
+) {
+    unsafe {
+        (*p, *e, *h, *s, *cv, *cp, *w) = state_td(*t, *d);
+        *hjt = 0.0;
+    }
+}
+
+unsafe extern "C" fn info(
+    _icomp: *const c_int,
+    wmm: *mut c_double,
+    ttrp: *mut c_double,
+    tnbpt: *mut c_double,
+    tc: *mut c_double,
+    pc: *mut c_double,
+    dc: *mut c_double,
+    zc: *mut c_double,
+    acf: *mut c_double,
+    dip: *mut c_double,
+    rgas: *mut c_double,
+) {
+    let f = fluid();
+    unsafe {
+        *wmm = f.molar_mass;
+        *ttrp = f.ttrp;
+        *tnbpt = f.tnbp;
+        *tc = f.tc;
+        *pc = f.pc;
+        *dc = f.dc;
+        *zc = f.pc / (f.dc * R * f.tc);
+        *acf = f.acf;
+        *dip = f.dipole;
+        *rgas = R;
+    }
+}
+
+unsafe extern "C" fn dpdd(
+    t: *const c_double,
+    _d: *const c_double,
+    _z: *const c_double,
+    dpdd: *mut c_double,
+) {
+    unsafe { *dpdd = R * *t };
+}
+
+extern "C" fn getmod(
+    _icomp: *const c_int,
+    _htype: *const c_char,
+    _hcode: *mut c_char,
+    _hcite: *mut c_char,
+    _htype_length: c_long,
+    _hcode_length: c_long,
+    _hcite_length: c_long,
+) {
+}
+
+unsafe extern "C" fn tpflsh(
+    t: *const c_double,
+    p: *const c_double,
+    z: *const c_double,
+    d: *mut c_double,
+    dl: *mut c_double,
+    dv: *mut c_double,
+    x: *mut c_double,
+    y: *mut c_double,
+    q: *mut c_double,
+    e: *mut c_double,
+    h: *mut c_double,
+    s: *mut c_double,
+    cv: *mut c_double,
+    cp: *mut c_double,
+    w: *mut c_double,
+    ierr: *mut c_int,
+    _herr: *mut c_char,
+    _herr_length: c_long,
+) {
+    unsafe {
+        let rho = *p / (R * *t);
+        *d = rho;
+        write_flash(z, rho, dl, dv, x, y, q);
+        (_, *e, *h, *s, *cv, *cp, *w) = state_td(*t, rho);
+        *ierr = 0;
+    }
+}
+
+unsafe extern "C" fn phflsh(
+    p: *const c_double,
+    h: *const c_double,
+    z: *const c_double,
+    t: *mut c_double,
+    d: *mut c_double,
+    dl: *mut c_double,
+    dv: *mut c_double,
+    x: *mut c_double,
+    y: *mut c_double,
+    q: *mut c_double,
+    e: *mut c_double,
+    s: *mut c_double,
+    cv: *mut c_double,
+    cp: *mut c_double,
+    w: *mut c_double,
+    ierr: *mut c_int,
+    _herr: *mut c_char,
+    _herr_length: c_long,
+) {
+    unsafe {
+        *t = T0 + *h / fluid().cp0;
+        let rho = *p / (R * *t);
+        *d = rho;
+        write_flash(z, rho, dl, dv, x, y, q);
+        (_, *e, _, *s, *cv, *cp, *w) = state_td(*t, rho);
+        *ierr = 0;
+    }
+}
+
+unsafe extern "C" fn psflsh(
+    p: *const c_double,
+    s: *const c_double,
+    z: *const c_double,
+    t: *mut c_double,
+    d: *mut c_double,
+    dl: *mut c_double,
+    dv: *mut c_double,
+    x: *mut c_double,
+    y: *mut c_double,
+    q: *mut c_double,
+    e: *mut c_double,
+    h: *mut c_double,
+    cv: *mut c_double,
+    cp: *mut c_double,
+    w: *mut c_double,
+    ierr: *mut c_int,
+    _herr: *mut c_char,
+    _herr_length: c_long,
+) {
+    unsafe {
+        *t = T0 * ((*s + R * (*p / P0).ln()) / fluid().cp0).exp();
+        let rho = *p / (R * *t);
+        *d = rho;
+        write_flash(z, rho, dl, dv, x, y, q);
+        (_, *e, *h, _, *cv, *cp, *w) = state_td(*t, rho);
+        *ierr = 0;
+    }
+}
+
+unsafe extern "C" fn critp(
+    _z: *const c_double,
+    tc: *mut c_double,
+    pc: *mut c_double,
+    dc: *mut c_double,
+    ierr: *mut c_int,
+    _herr: *mut c_char,
+    _herr_length: c_long,
+) {
+    let f = fluid();
+    unsafe {
+        *tc = f.tc;
+        *pc = f.pc;
+        *dc = f.dc;
+        *ierr = 0;
+    }
+}
+
+unsafe extern "C" fn press(
+    t: *const c_double,
+    d: *const c_double,
+    _z: *const c_double,
+    p: *mut c_double,
+) {
+    unsafe { *p = *d * R * *t };
+}
+
+unsafe extern "C" fn cp0(_t: *const c_double, _z: *const c_double, cp0: *mut c_double) {
+    unsafe { *cp0 = fluid().cp0 };
+}
+
+/// The mock library, with an empty `.FLD` file per canned fluid in
+/// `data_dir/fluids` so the usual fluid lookup finds them.
+pub(super) fn library(data_dir: &Path) -> Result<RefpropLibrary, RefpropSysError> {
+    let fluids = data_dir.join("fluids");
+    let create = |e: std::io::Error| {
+        RefpropSysError::LibraryLoadFailed(format!("mock data in {}: {e}", fluids.display()))
+    };
+    std::fs::create_dir_all(&fluids).map_err(create)?;
+    for f in &FLUIDS {
+        std::fs::write(fluids.join(format!("{}.FLD", f.name)), "").map_err(create)?;
+    }
+
+    let bound = [
+        "SETPATHdll",
+        "SETUPdll",
+        "TPFLSHdll",
+        "PHFLSHdll",
+        "PSFLSHdll",
+        "CRITPdll",
+        "THERMdll",
+        "INFOdll",
+        "DPDDdll",
+        "GETMODdll",
+        "CP0dll",
+        "PRESSdll",
+    ];
+    let missing = super::ALL_SYMBOLS
+        .into_iter()
+        .filter(|name| !bound.contains(name))
+        .collect();
+
+    Ok(RefpropLibrary {
+        _lib: None,
+        path: data_dir.join("REFPROP-MOCK"),
+        fn_setpath: setpath,
+        fn_setup: setup,
+        fn_tpflsh: Some(tpflsh),
+        fn_phflsh: Some(phflsh),
+        fn_psflsh: Some(psflsh),
+        fn_satt: None,
+        fn_satp: None,
+        fn_critp: Some(critp),
+        fn_trnprp: None,
+        fn_setmix: None,
+        fn_tdflsh: None,
+        fn_pdflsh: None,
+        fn_thflsh: None,
+        fn_pqflsh: None,
+        fn_tsflsh: None,
+        fn_dhflsh: None,
+        fn_dsflsh: None,
+        fn_hsflsh: None,
+        fn_therm: therm,
+        fn_info: info,
+        fn_tprho: None,
+        fn_hsfl1: None,
+        fn_dpdd: dpdd,
+        fn_getmod: getmod,
+        fn_b12: None,
+        fn_satd: None,
+        fn_cp0: Some(cp0),
+        fn_press: Some(press),
+        fn_setmod: None,
+        fn_dptsatk: None,
+        fn_flags: None,
+        fn_rpversion: None,
+        fn_errmsg: None,
+        fn_crtpnt: None,
+        fn_getenum: None,
+        missing,
+    })
+}
@@ -0,0 +1,125 @@
+//! Precomputed property tables on a (T, P) grid.
+//!
+//! [`Fluid::build_table`] flashes every node of a uniform grid once,
+//! under a single lock acquisition; [`PropertyTable::interpolate`] then
+//! answers queries by bilinear interpolation without touching REFPROP
+//! or its global lock.
+//!
+//! The table trades memory (`nt × np` values) and a one-time build cost
+//! for lookups in well under a microsecond.  Accuracy is set by the grid
+//! spacing: the bilinear error grows with the curvature of the property
+//! over a cell, so refine the grid near the saturation line and the
+//! critical point, where properties change steeply.  A cell that
+//! straddles a phase boundary blends liquid and vapor values and is not
+//! meaningful there.
+//!
+//! ```no_run
+//! use refprop::{Fluid, UnitSystem};
+//!
+//! let r134a = Fluid::with_units("R134A", UnitSystem::engineering())?;
+//! let table = r134a.build_table("H", 30.0..=80.0, 1.0..=5.0, 51, 41)?;
+//! let h = table.interpolate(42.3, 2.7)?;
+//! # Ok::<(), refprop::RefpropError>(())
+//! ```
+
+use std::ops::RangeInclusive;
+
+use crate::error::{RefpropError, Result};
+
+/// One property tabulated on a uniform (T, P) grid, in the units of the
+/// [`Fluid`](crate::Fluid) that built it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PropertyTable {
+    property: String,
+    temperatures: Vec<f64>,
+    pressures: Vec<f64>,
+    /// Row-major: row `i` holds the values at `temperatures[i]`.
+    values: Vec<f64>,
+}
+
+impl PropertyTable {
+    pub(crate) fn new(
+        property: &str,
+        temperatures: Vec<f64>,
+        pressures: Vec<f64>,
+        values: Vec<f64>,
+    ) -> Self {
+        debug_assert_eq!(values.len(), temperatures.len() * pressures.len());
+        Self {
+            property: property.to_uppercase(),
+            temperatures,
+            pressures,
+            values,
+        }
+    }
+
+    /// `n` evenly spaced nodes covering `range`, both ends included.
+    pub(crate) fn nodes(name: &str, range: &RangeInclusive<f64>, n: usize) -> Result<Vec<f64>> {
+        let (lo, hi) = (*range.start(), *range.end());
+        if n < 2 {
+            return Err(RefpropError::InvalidInput(format!(
+                "A property table needs at least 2 {name} nodes, got {n}"
+            )));
+        }
+        if !lo.is_finite() || !hi.is_finite() || lo >= hi {
+            return Err(RefpropError::InvalidInput(format!(
+                "Invalid {name} range {lo}..={hi}"
+            )));
+        }
+        let step = (hi - lo) / (n - 1) as f64;
+        Ok((0..n)
+            .map(|i| if i == n - 1 { hi } else { lo + step * i as f64 })
+            .collect())
+    }
+
+    /// Output key the table was built for (upper case).
+    pub fn property(&self) -> &str {
+        &self.property
+    }
+
+    /// Temperature nodes.
+    pub fn temperatures(&self) -> &[f64] {
+        &self.temperatures
+    }
+
+    /// Pressure nodes.
+    pub fn pressures(&self) -> &[f64] {
+        &self.pressures
+    }
+
+    /// Tabulated value at node `(i, j)` = (`temperatures[i]`, `pressures[j]`).
+    pub fn value(&self, i: usize, j: usize) -> f64 {
+        self.values[i * self.pressures.len() + j]
+    }
+
+    /// Bilinear interpolation at (T, P).
+    ///
+    /// Points outside the tabulated ranges return
+    /// [`InvalidInput`](RefpropError::InvalidInput); no extrapolation
+    /// is attempted.
+    pub fn interpolate(&self, t: f64, p: f64) -> Result<f64> {
+        let (i, ft) = Self::locate("T", &self.temperatures, t)?;
+        let (j, fp) = Self::locate("P", &self.pressures, p)?;
+        let v00 = self.value(i, j);
+        let v01 = self.value(i, j + 1);
+        let v10 = self.value(i + 1, j);
+        let v11 = self.value(i + 1, j + 1);
+        Ok((1.0 - ft) * ((1.0 - fp) * v00 + fp * v01) + ft * ((1.0 - fp) * v10 + fp * v11))
+    }
+
+    /// Lower node index of the cell holding `x` and the fractional
+    /// position of `x` within it.
+    fn locate(name: &str, nodes: &[f64], x: f64) -> Result<(usize, f64)> {
+        let (lo, hi) = (nodes[0], nodes[nodes.len() - 1]);
+        if !(x >= lo && x <= hi) {
+            return Err(RefpropError::InvalidInput(format!(
+                "{name} = {x} is outside the table range {lo}..={hi}"
+            )));
+        }
+        let last = nodes.len() - 2;
+        let step = (hi - lo) / (nodes.len() - 1) as f64;
+        let i = (((x - lo) / step) as usize).min(last);
+        let frac = (x - nodes[i]) / (nodes[i + 1] - nodes[i]);
+        Ok((i, frac.clamp(0.0, 1.0)))
+    }
+}
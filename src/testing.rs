@@ -0,0 +1,182 @@
+//! Running tests against REFPROP or an in-process mock (feature `mock`).
+//!
+//! [`TestBackend`] hands out [`Fluid`]s backed either by the real
+//! REFPROP library or by [`RefpropLibrary::mock`], which models a few
+//! fluids as ideal gases.  The mock exercises everything between the
+//! public API and the DLL — input validation, dispatch, unit
+//! conversion — so contributors without REFPROP can still run those
+//! tests.  The backend is picked with the `REFPROP_TEST_BACKEND`
+//! environment variable:
+//!
+//! | Value            | Fluids come from                               |
+//! |------------------|------------------------------------------------|
+//! | `real` (default) | the REFPROP library found by path discovery    |
+//! | `mock`           | the mock                                       |
+//! | `diff`           | the mock, checked against REFPROP when present |
+//!
+//! ```no_run
+//! use refprop::UnitSystem;
+//! use refprop::testing::TestBackend;
+//!
+//! let backend = TestBackend::from_env()?;
+//! let values = backend.evaluate("NITROGEN", UnitSystem::si(), 0.01, |n2| {
+//!     let props = n2.props_tp(300.0, 0.1)?;
+//!     Ok(vec![props.density, props.cp])
+//! })?;
+//! # Ok::<(), refprop::RefpropError>(())
+//! ```
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::converter::UnitSystem;
+use crate::error::{RefpropError, Result};
+use crate::fluid::Fluid;
+use crate::sys::RefpropLibrary;
+
+/// Where [`TestBackend`] fluids come from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TestBackend {
+    /// The REFPROP library found by
+    /// [`Fluid::discover_refprop_path`].
+    Real,
+    /// The in-process mock, see [`RefpropLibrary::mock`].
+    Mock,
+    /// The mock, with every [`evaluate`](Self::evaluate) also run on
+    /// REFPROP when it is installed and the two results compared.
+    Diff,
+}
+
+impl TestBackend {
+    /// Environment variable selecting the backend.
+    pub const ENV_VAR: &'static str = "REFPROP_TEST_BACKEND";
+
+    /// Backend named by `REFPROP_TEST_BACKEND` (`real`, `mock` or
+    /// `diff`, case-insensitive); [`Real`](Self::Real) when unset.
+    ///
+    /// # Errors
+    ///
+    /// [`InvalidInput`](RefpropError::InvalidInput) for any other value.
+    pub fn from_env() -> Result<Self> {
+        match std::env::var(Self::ENV_VAR) {
+            Ok(value) => value.parse(),
+            Err(_) => Ok(Self::Real),
+        }
+    }
+
+    /// A fluid in `units` from this backend; [`Diff`](Self::Diff)
+    /// returns the mock's.
+    pub fn fluid(self, name: &str, units: UnitSystem) -> Result<Fluid> {
+        match self {
+            Self::Real => Fluid::with_units(name, units),
+            Self::Mock | Self::Diff => mock_fluid(name, units),
+        }
+    }
+
+    /// Evaluate `f` on `name` and return its values.
+    ///
+    /// In [`Diff`](Self::Diff) mode the mock's values are returned, and
+    /// unless path discovery finds no REFPROP directory `f` runs on
+    /// REFPROP too: every mock value must then lie within the relative
+    /// `tolerance` of REFPROP's.  Only compare outputs the mock models
+    /// (gas-phase density, heat capacities, sound speed); its enthalpy
+    /// and entropy use their own reference state.
+    ///
+    /// # Errors
+    ///
+    /// Any error of `f` or of the fluid construction, or
+    /// [`CalculationFailed`](RefpropError::CalculationFailed) naming the
+    /// first value where the mock drifted from REFPROP.
+    pub fn evaluate<F>(
+        self,
+        name: &str,
+        units: UnitSystem,
+        tolerance: f64,
+        f: F,
+    ) -> Result<Vec<f64>>
+    where
+        F: Fn(&Fluid) -> Result<Vec<f64>>,
+    {
+        let values = f(&self.fluid(name, units.clone())?)?;
+        if self == Self::Diff {
+            match Self::Real.fluid(name, units) {
+                Ok(real) => check_drift(name, &f(&real)?, &values, tolerance)?,
+                Err(RefpropError::LibraryNotFound(_)) => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(values)
+    }
+}
+
+impl std::str::FromStr for TestBackend {
+    type Err = RefpropError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "real" => Ok(Self::Real),
+            "mock" => Ok(Self::Mock),
+            "diff" => Ok(Self::Diff),
+            other => Err(RefpropError::InvalidInput(format!(
+                "{}={other}: expected real, mock or diff",
+                Self::ENV_VAR
+            ))),
+        }
+    }
+}
+
+/// A mock fluid.  The placeholder fluid files are only read while the
+/// fluid is resolved, so each call writes them to a directory of its
+/// own and removes it before returning.
+fn mock_fluid(name: &str, units: UnitSystem) -> Result<Fluid> {
+    static NEXT_DIR: AtomicUsize = AtomicUsize::new(0);
+
+    let n = NEXT_DIR.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("refprop-mock-{}-{n}", std::process::id()));
+    let fluid = RefpropLibrary::mock(&dir)
+        .map_err(RefpropError::from)
+        .and_then(|lib| Fluid::from_library(Arc::new(lib), name, units));
+    let _ = std::fs::remove_dir_all(&dir);
+    fluid
+}
+
+/// `Err` naming the first of `mock` outside the relative `tolerance`
+/// of the matching `real` value.
+fn check_drift(name: &str, real: &[f64], mock: &[f64], tolerance: f64) -> Result<()> {
+    if real.len() != mock.len() {
+        return Err(RefpropError::CalculationFailed(format!(
+            "{name}: mock returned {} values, REFPROP {}",
+            mock.len(),
+            real.len()
+        )));
+    }
+    for (i, (r, m)) in real.iter().zip(mock).enumerate() {
+        if (m - r).abs() > tolerance * r.abs() {
+            return Err(RefpropError::CalculationFailed(format!(
+                "{name}: mock drifted from REFPROP at value {i}: {m} vs {r} \
+                 (relative tolerance {tolerance})"
+            )));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drift_beyond_tolerance_is_reported() {
+        assert!(check_drift("N2", &[1.0, 29.1], &[1.005, 29.2], 0.01).is_ok());
+
+        let err = check_drift("N2", &[1.0, 29.1], &[1.0, 31.0], 0.01).unwrap_err();
+        assert!(
+            matches!(&err, RefpropError::CalculationFailed(msg) if msg.contains("value 1")),
+            "The drifting value should be named, got {err:?}"
+        );
+        assert!(
+            check_drift("N2", &[1.0], &[1.0, 2.0], 0.01).is_err(),
+            "A different number of values should be reported"
+        );
+    }
+}
@@ -0,0 +1,90 @@
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+// ═══════════════════════════════════════════════════════════════════
+//  Chemin REFPROP figé à la compilation (REFPROP_BUILD_PATH)
+// ═══════════════════════════════════════════════════════════════════
+//
+// La valeur est lue par option_env! : pour la contrôler, on recompile
+// l'exemple refprop_path avec REFPROP_BUILD_PATH fixé (dans un
+// répertoire cible séparé) et on l'exécute hors du dépôt, sans .env.
+// Aucune installation de REFPROP n'est nécessaire : la découverte ne
+// vérifie que l'existence des répertoires.
+
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join(name);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// Build `examples/refprop_path.rs` with `baked` as REFPROP_BUILD_PATH.
+fn build_probe(baked: &Path) -> PathBuf {
+    let target_dir = scratch_dir("baked-path-target");
+    let status = Command::new(env!("CARGO"))
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args(["build", "--offline", "--quiet", "--example", "refprop_path"])
+        .arg("--target-dir")
+        .arg(&target_dir)
+        .env("REFPROP_BUILD_PATH", baked)
+        .status()
+        .expect("cargo should be available to build the probe");
+    assert!(status.success(), "building the refprop_path example failed");
+    target_dir
+        .join("debug/examples")
+        .join(format!("refprop_path{}", std::env::consts::EXE_SUFFIX))
+}
+
+/// Run the probe outside the repository, with `refprop_path` as the
+/// run-time REFPROP_PATH (or none).
+fn run_probe(probe: &Path, refprop_path: Option<&Path>) -> Output {
+    let mut cmd = Command::new(probe);
+    cmd.current_dir(scratch_dir("baked-path-cwd"))
+        .env_remove("REFPROP_PATH")
+        .env_remove("CARGO_MANIFEST_DIR");
+    if let Some(path) = refprop_path {
+        cmd.env("REFPROP_PATH", path);
+    }
+    cmd.output().expect("the probe should run")
+}
+
+fn stdout_path(out: &Output) -> String {
+    assert!(
+        out.status.success(),
+        "probe failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8(out.stdout.clone())
+        .unwrap()
+        .trim()
+        .to_string()
+}
+
+#[test]
+fn baked_path_is_used_when_refprop_path_is_unset_or_missing() {
+    let baked = scratch_dir("baked-refprop");
+    let runtime = scratch_dir("runtime-refprop");
+    let probe = build_probe(&baked);
+    let baked = baked.to_str().unwrap();
+
+    // Sans REFPROP_PATH : le chemin figé
+    assert_eq!(
+        stdout_path(&run_probe(&probe, None)),
+        baked,
+        "Without REFPROP_PATH the baked-in path should be used"
+    );
+
+    // REFPROP_PATH existant : il l'emporte
+    assert_eq!(
+        stdout_path(&run_probe(&probe, Some(&runtime))),
+        runtime.to_str().unwrap(),
+        "An existing REFPROP_PATH should win over the baked-in path"
+    );
+
+    // REFPROP_PATH inexistant : retour au chemin figé
+    let missing = runtime.join("does-not-exist");
+    assert_eq!(
+        stdout_path(&run_probe(&probe, Some(&missing))),
+        baked,
+        "A missing REFPROP_PATH should fall back to the baked-in path"
+    );
+}
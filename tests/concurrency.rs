@@ -0,0 +1,96 @@
+#![cfg(feature = "refprop")]
+
+use std::sync::OnceLock;
+use std::thread;
+
+use refprop::{Fluid, UnitSystem};
+
+mod fixtures;
+use fixtures::fluid;
+
+// ═══════════════════════════════════════════════════════════════════
+//  Verrou global — progression et statistiques
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn many_threads_all_complete_and_lock_stats_are_populated() {
+    const THREADS: usize = 16;
+    const CALLS: usize = 25;
+
+    let before = Fluid::lock_stats();
+
+    let handles: Vec<_> = (0..THREADS)
+        .map(|i| {
+            thread::spawn(move || {
+                let name = if i % 2 == 0 { "R134A" } else { "CO2" };
+                let fluid = fluid(name, UnitSystem::engineering());
+                // Gaz à 1 bar : le mock de REFPROP suffit
+                for k in 0..CALLS {
+                    let t = -20.0 + k as f64;
+                    let d = fluid.get("D", "T", t, "P", 1.0).unwrap();
+                    assert!(
+                        d > 0.0,
+                        "{name}: D({t} °C, 1 bar) should be positive, got {d}"
+                    );
+                }
+                CALLS
+            })
+        })
+        .collect();
+
+    let done: usize = handles
+        .into_iter()
+        .map(|h| h.join().expect("worker thread panicked"))
+        .sum();
+    assert_eq!(
+        done,
+        THREADS * CALLS,
+        "every thread should finish all calls"
+    );
+
+    let after = Fluid::lock_stats();
+    assert!(
+        after.acquisitions >= before.acquisitions + (THREADS * CALLS) as u64,
+        "expected at least {} new acquisitions, got {}",
+        THREADS * CALLS,
+        after.acquisitions - before.acquisitions
+    );
+    assert!(after.total_wait >= before.total_wait);
+    assert!(after.max_wait >= before.max_wait);
+    assert!(after.max_wait >= after.mean_wait());
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  Fluid partagé dans un static
+// ═══════════════════════════════════════════════════════════════════
+
+static SHARED_R134A: OnceLock<Fluid> = OnceLock::new();
+
+fn shared_r134a() -> &'static Fluid {
+    SHARED_R134A.get_or_init(|| fluid("R134A", UnitSystem::engineering()))
+}
+
+#[test]
+fn fluid_in_static_once_lock_is_usable_from_many_threads() {
+    const THREADS: usize = 8;
+
+    let expected = shared_r134a().get("D", "T", 0.0, "P", 1.0).unwrap();
+    let handles: Vec<_> = (0..THREADS)
+        .map(|i| {
+            thread::spawn(move || {
+                // Un autre fluide chargé entre-temps force un nouveau SETUPdll
+                let co2 = fluid("CO2", UnitSystem::engineering());
+                co2.get("D", "T", i as f64, "P", 1.0).unwrap();
+                shared_r134a().get("D", "T", 0.0, "P", 1.0).unwrap()
+            })
+        })
+        .collect();
+
+    for h in handles {
+        let d = h.join().expect("worker thread panicked");
+        assert!(
+            (d - expected).abs() < 1e-9 * expected,
+            "shared fluid gave D = {d} kg/m³, expected {expected}"
+        );
+    }
+}
@@ -0,0 +1,46 @@
+#![cfg(all(unix, feature = "refprop"))]
+
+use std::sync::Arc;
+
+use refprop::sys::RefpropLibrary;
+use refprop::{Fluid, UnitSystem, capture};
+
+mod support;
+
+// ═══════════════════════════════════════════════════════════════════
+//  Sortie console de REFPROP pendant le chargement
+// ═══════════════════════════════════════════════════════════════════
+//
+// Pas besoin de REFPROP : la bibliothèque tests/support/
+// refprop_console_stub.rs affiche une bannière sur le descripteur 1 à
+// chaque SETUPdll, sauf si FLAGSdll a coupé "Write to console".  Un
+// seul test dans ce binaire, car set_quiet_setup est global.
+
+const BANNER: &str = "REFPROP stub banner";
+
+#[test]
+fn quiet_setup_keeps_the_banner_off_stdout() {
+    let path = support::build_stub("refprop_console_stub");
+    std::fs::create_dir_all(path.parent().unwrap().join("fluids")).unwrap();
+    std::fs::write(path.parent().unwrap().join("fluids/STUB.FLD"), "").unwrap();
+    let lib = Arc::new(RefpropLibrary::load_lenient_from_file(&path).unwrap());
+
+    let capture = Fluid::capture_output().unwrap();
+    let loud = Fluid::from_library(Arc::clone(&lib), "STUB", UnitSystem::refprop());
+    capture::set_quiet_setup(true);
+    let quiet = Fluid::from_library(lib, "STUB", UnitSystem::refprop());
+    capture::set_quiet_setup(false);
+    let log = capture.finish();
+
+    assert!(loud.is_ok(), "Stub fluid should load: {:?}", loud.err());
+    assert!(
+        quiet.is_ok(),
+        "Stub fluid should load quietly: {:?}",
+        quiet.err()
+    );
+    assert_eq!(
+        log.matches(BANNER).count(),
+        1,
+        "Only the setup without quiet mode should print the banner, got {log:?}"
+    );
+}
@@ -0,0 +1,305 @@
+use refprop::{
+    Basis, Converter, DensityUnit, DipoleUnit, EnergyUnit, EntropyUnit, HeatCapacityUnit,
+    MassFlowUnit, PressUnit, Property, ReferenceState, TempUnit, UnitConfig, UnitSystem,
+    ViscosityUnit,
+};
+
+// ═══════════════════════════════════════════════════════════════════
+//  Conversions pures — aucune DLL REFPROP nécessaire
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn density_mol_per_l_to_amagat() {
+    // 1 amagat = 44.615 mol/m³ = 0.044615 mol/L
+    let conv = Converter::new(UnitSystem::new().density(DensityUnit::Amagat), 28.0134);
+    let amg = conv.d_from_rp(0.044_615);
+    assert!((amg - 1.0).abs() < 1e-12, "Expected 1 amagat, got {amg}");
+    assert!((conv.d_to_rp(amg) - 0.044_615).abs() < 1e-15);
+}
+
+#[test]
+fn density_mol_per_l_to_number_density() {
+    // 1 mol/L = 1000 mol/m³ → 6.02214076e26 particles/m³
+    let conv = Converter::new(UnitSystem::new().density(DensityUnit::PerM3), 28.0134);
+    let n = conv.d_from_rp(1.0);
+    assert!(
+        (n / 6.022_140_76e26 - 1.0).abs() < 1e-12,
+        "Expected 6.022e26 1/m³, got {n:e}"
+    );
+    assert!((conv.d_to_rp(n) - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn density_mol_per_l_to_mol_per_m3() {
+    let conv = Converter::new(UnitSystem::si_molar(), 102.032);
+    assert!((conv.d_from_rp(1.5) - 1500.0).abs() < 1e-9);
+    assert!((conv.d_to_rp(1500.0) - 1.5).abs() < 1e-12);
+}
+
+#[test]
+fn dipole_debye_to_coulomb_meter() {
+    // R134A : μ ≈ 2.058 D
+    let conv = Converter::new(UnitSystem::si().dipole(DipoleUnit::CoulombMeter), 102.032);
+    let mu = conv.dipole_from_rp(2.058);
+    assert!(
+        (mu - 2.058 * 3.33564e-30).abs() < 1e-36,
+        "Expected 6.865e-30 C·m, got {mu:e}"
+    );
+    assert_eq!(
+        Converter::new(UnitSystem::si(), 102.032).dipole_from_rp(2.058),
+        2.058
+    );
+}
+
+#[test]
+fn mass_flow_to_kg_per_s() {
+    let kg_h = Converter::new(UnitSystem::new().mass_flow(MassFlowUnit::KgPerH), 102.032);
+    assert!((kg_h.mass_flow_to_kg_s(3600.0) - 1.0).abs() < 1e-12);
+    // 1 lbm/h = 0.45359237 kg / 3600 s
+    let lbm_h = Converter::new(UnitSystem::new().mass_flow(MassFlowUnit::LbmPerH), 102.032);
+    assert!((lbm_h.mass_flow_to_kg_s(3600.0) - 0.453_592_37).abs() < 1e-12);
+    assert_eq!(Converter::identity().mass_flow_to_kg_s(2.5), 2.5);
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  Base massique / molaire
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn mass_basis_selects_kg_per_m3_and_kj_per_kg() {
+    let units = UnitSystem::refprop().basis(Basis::Mass);
+    assert_eq!(units.density, DensityUnit::KgPerM3);
+    assert_eq!(units.energy, EnergyUnit::KJPerKg);
+    assert_eq!(units.entropy, EntropyUnit::KJPerKgK);
+    assert!(units.validate().is_ok());
+
+    // Les unités déjà massiques sont conservées
+    let si = UnitSystem::si().basis(Basis::Mass);
+    assert_eq!(si.energy, EnergyUnit::JPerKg);
+}
+
+#[test]
+fn basis_rejects_inconsistent_units() {
+    let units = UnitSystem::engineering()
+        .basis(Basis::Mass)
+        .energy(EnergyUnit::JPerMol);
+    assert!(
+        units.validate().is_err(),
+        "kg/m³ with J/mol should be rejected"
+    );
+
+    // Sans base imposée, la combinaison reste permise
+    let free = UnitSystem::engineering().energy(EnergyUnit::JPerMol);
+    assert!(free.validate().is_ok());
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  Capacité thermique distincte de l'entropie
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn heat_capacity_unit_is_independent_of_entropy_unit() {
+    let m = 102.032; // R134A, g/mol
+    let units = UnitSystem::si().heat_capacity(HeatCapacityUnit::JPerGK);
+    assert!(units.validate().is_ok());
+    let conv = Converter::new(units, m);
+
+    // 1 J/(mol·K) : entropie en J/(kg·K), Cp en J/(g·K)
+    let s = conv.from_rp(Property::Entropy, 1.0);
+    let cp = conv.from_rp(Property::Cp, 1.0);
+    assert!((s - 1000.0 / m).abs() < 1e-12, "S = {s}");
+    assert!((cp - 1.0 / m).abs() < 1e-12, "Cp = {cp}");
+    assert!((conv.output_from_rp("CV", 1.0) - cp).abs() < 1e-15);
+    assert!((conv.to_rp(Property::Cp, cp).unwrap() - 1.0).abs() < 1e-12);
+
+    // Sans réglage, Cp suit l'unité d'entropie
+    let mirrored = Converter::new(UnitSystem::si(), m);
+    assert_eq!(
+        mirrored.unit_system().heat_capacity_unit(),
+        HeatCapacityUnit::JPerKgK
+    );
+    assert_eq!(mirrored.from_rp(Property::Cp, 1.0), s);
+
+    // Une unité molaire de Cp viole une base massique imposée
+    let strict = UnitSystem::si()
+        .basis(Basis::Mass)
+        .heat_capacity(HeatCapacityUnit::JPerMolK);
+    assert!(strict.validate().is_err());
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  Entrées texte avec unité
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn parse_input_with_unit_suffix_overrides_configured_unit() {
+    let conv = Converter::new(UnitSystem::refprop(), 102.032);
+    let cases = [
+        ("T", "25 degC", 298.15),
+        ("T", "77°F", 298.15),
+        ("T", "300 K", 300.0),
+        ("P", "5 bar", 500.0),
+        ("P", "0.5MPa", 500.0),
+        ("P", "1 atm", 101.325),
+        ("D", "1020.32 kg/m3", 10.0),
+        ("D", "2 mol/L", 2.0),
+        ("H", "1 kJ/kg", 102.032),
+        ("Q", "50 %", 0.5),
+    ];
+    for (key, text, expected) in cases {
+        let v = conv.parse_input_to_rp(key, text).unwrap();
+        assert!(
+            (v - expected).abs() < 1e-9,
+            "{key} = {text:?} should be {expected} in REFPROP units, got {v}"
+        );
+    }
+}
+
+#[test]
+fn parse_input_without_unit_uses_configured_unit() {
+    let conv = Converter::new(UnitSystem::engineering(), 102.032);
+    assert_eq!(
+        conv.parse_input_to_rp("T", " 25 ").unwrap(),
+        conv.input_to_rp("T", 25.0).unwrap()
+    );
+    assert_eq!(conv.parse_input_to_rp("P", "1e1").unwrap(), 1000.0);
+}
+
+#[test]
+fn parse_input_rejects_bad_text_and_mismatched_units() {
+    let conv = Converter::new(UnitSystem::engineering(), 102.032);
+    assert!(conv.parse_input_to_rp("T", "warm").is_err());
+    assert!(conv.parse_input_to_rp("T", "5 bar").is_err());
+    assert!(conv.parse_input_to_rp("P", "5 furlongs").is_err());
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  Configuration sérialisée (UnitConfig)
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn unit_config_from_json_builds_unit_system() {
+    let config: UnitConfig = serde_json::from_str(
+        r#"{
+            "temperature": "degC",
+            "pressure": "bar",
+            "basis": "mass",
+            "viscosity": "mPa.s",
+            "reference": "ash"
+        }"#,
+    )
+    .unwrap();
+    let units = UnitSystem::from_config(&config).unwrap();
+    assert_eq!(units.temperature, TempUnit::Celsius);
+    assert_eq!(units.pressure, PressUnit::Bar);
+    assert_eq!(units.density, DensityUnit::KgPerM3);
+    assert_eq!(units.energy, EnergyUnit::KJPerKg);
+    assert_eq!(units.viscosity, ViscosityUnit::MilliPaS);
+    assert_eq!(config.reference_state().unwrap(), ReferenceState::Ashrae);
+
+    let conv = Converter::new(units, 102.032);
+    assert!((conv.t_to_rp(25.0) - 298.15).abs() < 1e-12);
+    assert!((conv.p_to_rp(1.0) - 100.0).abs() < 1e-12);
+}
+
+#[test]
+fn unit_config_defaults_to_refprop_units() {
+    let config: UnitConfig = serde_json::from_str("{}").unwrap();
+    let units = UnitSystem::from_config(&config).unwrap();
+    assert_eq!(units.temperature, TempUnit::Kelvin);
+    assert_eq!(units.density, DensityUnit::MolPerL);
+    assert_eq!(config.reference_state().unwrap(), ReferenceState::Default);
+}
+
+#[test]
+fn unit_config_error_names_the_offending_field() {
+    let config = UnitConfig {
+        pressure: Some("furlongs".into()),
+        ..UnitConfig::default()
+    };
+    let err = UnitSystem::from_config(&config).unwrap_err().to_string();
+    assert!(
+        err.contains("`pressure`") && err.contains("furlongs"),
+        "Error should name the field and the value, got: {err}"
+    );
+
+    let config = UnitConfig {
+        reference: Some("XYZ".into()),
+        ..UnitConfig::default()
+    };
+    let err = config.reference_state().unwrap_err().to_string();
+    assert!(err.contains("`reference`"), "got: {err}");
+
+    // Explicit unit conflicting with the pinned basis
+    let config = UnitConfig {
+        basis: Some("molar".into()),
+        energy: Some("kJ/kg".into()),
+        ..UnitConfig::default()
+    };
+    assert!(UnitSystem::from_config(&config).is_err());
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  Conversions typées (Property) vs clés texte
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn typed_and_string_conversions_agree() {
+    let mut conv = Converter::new(UnitSystem::engineering(), 102.032);
+    conv.h_offset = 1234.5;
+    conv.s_offset = 6.7;
+    for prop in Property::ALL {
+        let key = prop.key();
+        assert_eq!(key.parse::<Property>().unwrap(), prop);
+        assert_eq!(key.to_lowercase().parse::<Property>().unwrap(), prop);
+        let val = 42.0;
+        assert_eq!(
+            conv.to_rp(prop, val).unwrap(),
+            conv.input_to_rp(key, val).unwrap(),
+            "{prop:?} input"
+        );
+        assert_eq!(
+            conv.from_rp(prop, val),
+            conv.output_from_rp(key, val),
+            "{prop:?} output"
+        );
+    }
+    assert_eq!(
+        "LAMBDA".parse::<Property>().unwrap(),
+        Property::ThermalConductivity
+    );
+    assert_eq!("e".parse::<Property>().unwrap(), Property::InternalEnergy);
+    assert!("XYZ".parse::<Property>().is_err());
+    assert!(conv.to_rp(Property::Quality, 150.0).is_err());
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  Converter autonome : seulement une masse molaire, aucune DLL
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn standalone_converter_from_molar_mass_only() {
+    // R134A, 102.032 g/mol
+    let conv = Converter::for_fluid(102.032).with_units(UnitSystem::engineering());
+    assert_eq!(conv.unit_system().pressure, PressUnit::Bar);
+
+    assert!((conv.t_to_rp(25.0) - 298.15).abs() < 1e-12);
+    assert!((conv.p_to_rp(5.0) - 500.0).abs() < 1e-12);
+    // 12 mol/L × 102.032 g/mol = 1224.384 kg/m³
+    assert!((conv.d_from_rp(12.0) - 1224.384).abs() < 1e-9);
+    // 25 000 J/mol ÷ 102.032 g/mol = 245.02 kJ/kg
+    assert!((conv.h_from_rp(25_000.0) - 25_000.0 / 102.032).abs() < 1e-9);
+    assert!((conv.s_from_rp(102.032) - 1.0).abs() < 1e-12);
+
+    // Aller-retour pour toutes les propriétés typées
+    for prop in Property::ALL {
+        let val = if prop == Property::Quality { 40.0 } else { 3.5 };
+        let back = conv.from_rp(prop, conv.to_rp(prop, val).unwrap());
+        assert!((back - val).abs() < 1e-9 * val, "{prop:?}: {val} -> {back}");
+    }
+
+    // Par défaut : unités REFPROP, donc identité
+    let native = Converter::for_fluid(102.032);
+    assert_eq!(native.t_to_rp(300.0), 300.0);
+    assert_eq!(native.d_from_rp(12.0), 12.0);
+}
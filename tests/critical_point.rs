@@ -1,5 +1,10 @@
+#![cfg(feature = "refprop")]
+
 use refprop::{Fluid, UnitSystem};
 
+mod fixtures;
+use fixtures::{fluid, mock_lacks};
+
 // ═══════════════════════════════════════════════════════════════════
 //  Point critique — valeurs connues
 // ═══════════════════════════════════════════════════════════════════
@@ -7,7 +12,7 @@ use refprop::{Fluid, UnitSystem};
 #[test]
 fn r134a_critical_point() {
     // R134A: Tc ≈ 101.06 °C, Pc ≈ 40.59 bar, Dc ≈ 511.9 kg/m³
-    let r134a = Fluid::with_units("R134A", UnitSystem::engineering()).unwrap();
+    let r134a = fluid("R134A", UnitSystem::engineering());
     let crit = r134a.critical_point().unwrap();
 
     assert!(
@@ -30,7 +35,7 @@ fn r134a_critical_point() {
 #[test]
 fn co2_critical_point() {
     // CO2: Tc ≈ 30.98 °C, Pc ≈ 73.77 bar
-    let co2 = Fluid::with_units("CO2", UnitSystem::engineering()).unwrap();
+    let co2 = fluid("CO2", UnitSystem::engineering());
     let crit = co2.critical_point().unwrap();
 
     assert!(
@@ -47,8 +52,11 @@ fn co2_critical_point() {
 
 #[test]
 fn water_critical_point() {
+    if mock_lacks("WATER data") {
+        return;
+    }
     // Water: Tc ≈ 373.95 °C, Pc ≈ 220.64 bar
-    let water = Fluid::with_units("WATER", UnitSystem::engineering()).unwrap();
+    let water = fluid("WATER", UnitSystem::engineering());
     let crit = water.critical_point().unwrap();
 
     assert!(
@@ -65,7 +73,7 @@ fn water_critical_point() {
 
 #[test]
 fn critical_values_are_positive() {
-    let r134a = Fluid::with_units("R134A", UnitSystem::engineering()).unwrap();
+    let r134a = fluid("R134A", UnitSystem::engineering());
     let crit = r134a.critical_point().unwrap();
 
     assert!(crit.pressure > 0.0, "Pc must be positive");
@@ -73,3 +81,143 @@ fn critical_values_are_positive() {
     // En °C, Tc peut être négatif pour certains fluides, mais pas pour R134A
     assert!(crit.temperature > 0.0, "R134A Tc must be > 0 °C");
 }
+
+// ═══════════════════════════════════════════════════════════════════
+//  Spinodales
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn r134a_spinodal_branches_meet_at_critical_point() {
+    if mock_lacks("saturation data") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::engineering());
+    let crit = r134a.critical_point().unwrap();
+    let spinodal = r134a.spinodal(30).unwrap();
+    assert_eq!(spinodal.liquid.len(), 30);
+    assert_eq!(spinodal.vapor.len(), 30);
+
+    for (&(t, p_liq), &(_, p_vap)) in spinodal.liquid.iter().zip(&spinodal.vapor) {
+        let p_sat = r134a.get("P", "T", t, "Q", 0.0).unwrap();
+        assert!(
+            p_liq < p_sat && p_sat < p_vap,
+            "At {t:.2} °C expected P_liq,sp < Psat < P_vap,sp, got {p_liq:.4} / {p_sat:.4} / {p_vap:.4}"
+        );
+    }
+
+    let &(_, p_liq) = spinodal.liquid.last().unwrap();
+    let &(_, p_vap) = spinodal.vapor.last().unwrap();
+    for p in [p_liq, p_vap] {
+        assert!(
+            (p - crit.pressure).abs() / crit.pressure < 0.02,
+            "Spinodal should approach Pc = {:.4} bar near Tc, got {p:.4}",
+            crit.pressure
+        );
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  Inversion de Joule-Thomson
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn nitrogen_maximum_inversion_temperature() {
+    if mock_lacks("Joule-Thomson inversion") {
+        return;
+    }
+    // N2 : T_inv,max ≈ 621 K, P_inv,max ≈ 37–40 MPa
+    let n2 = fluid("NITROGEN", UnitSystem::refprop());
+    let t_max = n2.maximum_inversion_temperature().unwrap();
+    assert!(
+        (t_max - 621.0).abs() < 25.0,
+        "N2 maximum inversion temperature expected ≈ 621 K, got {t_max:.2}"
+    );
+
+    let p_max = n2.maximum_inversion_pressure().unwrap();
+    assert!(
+        (30_000.0..45_000.0).contains(&p_max),
+        "N2 maximum inversion pressure expected ≈ 38 MPa, got {p_max:.0} kPa"
+    );
+
+    let curve = n2.jt_inversion_curve(50).unwrap();
+    assert!(
+        curve.len() > 10,
+        "Inversion curve too short: {}",
+        curve.len()
+    );
+    assert!(curve.iter().all(|&(t, _)| t <= t_max + 1e-6));
+
+    // Les maxima viennent d'une seule courbe, gardée pour les appels suivants
+    assert_eq!(n2.maximum_inversion_temperature().unwrap(), t_max);
+    assert_eq!(n2.maximum_inversion_pressure().unwrap(), p_max);
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  Lieu critique d'un binaire
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn r32_r125_critical_locus_endpoints_match_pure_fluids() {
+    if mock_lacks("mixtures") {
+        return;
+    }
+    let units = UnitSystem::engineering();
+    let locus = Fluid::critical_locus_with_units(("R32", "R125"), 5, units.clone()).unwrap();
+    assert_eq!(locus.len(), 5);
+    assert_eq!(locus[0].0, 1.0);
+    assert_eq!(locus[4].0, 0.0);
+
+    let r32 = fluid("R32", units.clone()).critical_point().unwrap();
+    let r125 = fluid("R125", units).critical_point().unwrap();
+    for (crit, pure, name) in [(&locus[0].1, &r32, "R32"), (&locus[4].1, &r125, "R125")] {
+        assert!(
+            (crit.temperature - pure.temperature).abs() < 1e-6,
+            "Locus endpoint Tc should match pure {name}: {} vs {}",
+            crit.temperature,
+            pure.temperature
+        );
+        assert!((crit.pressure - pure.pressure).abs() < 1e-6);
+    }
+
+    // Les points intermédiaires restent entre les Tc des corps purs
+    for (x, crit) in &locus[1..4] {
+        assert!(
+            crit.temperature < r32.temperature && crit.temperature > r125.temperature - 1.0,
+            "Tc at x_R32 = {x:.2} should lie between the pure values, got {:.3}",
+            crit.temperature
+        );
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  Propriétés réduites (états correspondants)
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn reduced_properties_are_one_at_critical_point() {
+    if mock_lacks("real-fluid densities") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::engineering());
+    let crit = r134a.critical_point().unwrap();
+
+    let at_crit = r134a
+        .reduced_properties(crit.temperature, crit.pressure)
+        .unwrap();
+    assert!((at_crit.t_r - 1.0).abs() < 1e-9, "t_r = {}", at_crit.t_r);
+    assert!((at_crit.p_r - 1.0).abs() < 1e-9, "p_r = {}", at_crit.p_r);
+    // La densité est très sensible à P au point critique
+    assert!(
+        (at_crit.rho_r - 1.0).abs() < 0.05,
+        "rho_r = {}",
+        at_crit.rho_r
+    );
+
+    // Liquide sous-refroidi à 25 °C, 10 bar
+    let liquid = r134a.reduced_properties(25.0, 10.0).unwrap();
+    assert!(liquid.t_r < 1.0 && liquid.p_r < 1.0, "{liquid:?}");
+    assert!(
+        liquid.rho_r > 1.0,
+        "compressed liquid should be denser than ρc"
+    );
+}
@@ -0,0 +1,81 @@
+#![cfg(feature = "refprop")]
+
+use std::error::Error;
+
+use refprop::sys::RefpropSysError;
+use refprop::{Fluid, RefpropError};
+
+// ═══════════════════════════════════════════════════════════════════
+//  RefpropError::Sys — chaînage de la cause
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn symbol_not_found_is_kept_as_source() {
+    let err = RefpropError::from(RefpropSysError::SymbolNotFound("RPVersion".into()));
+    assert!(matches!(err, RefpropError::Sys(_)));
+
+    let source = err.source().expect("Sys error should expose its cause");
+    match source.downcast_ref::<RefpropSysError>() {
+        Some(RefpropSysError::SymbolNotFound(sym)) => assert_eq!(sym, "RPVersion"),
+        other => panic!("expected SymbolNotFound source, got {other:?}"),
+    }
+    assert!(
+        !err.to_string().contains("RPVersion"),
+        "Display should leave the missing symbol to the source, got {err}"
+    );
+}
+
+#[test]
+fn load_failure_is_distinguishable_from_missing_symbol() {
+    let err = RefpropError::from(RefpropSysError::LibraryLoadFailed("no DLL".into()));
+    let source = err
+        .source()
+        .and_then(|e| e.downcast_ref::<RefpropSysError>());
+    assert!(
+        matches!(source, Some(RefpropSysError::LibraryLoadFailed(_))),
+        "expected LibraryLoadFailed source, got {source:?}"
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  RefpropError::Io — erreurs d'écriture des exports
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn io_error_is_kept_as_source() {
+    let err = RefpropError::from(std::io::Error::other("disk full"));
+    assert!(matches!(err, RefpropError::Io(_)));
+    let source = err
+        .source()
+        .and_then(|e| e.downcast_ref::<std::io::Error>());
+    assert!(
+        source.is_some_and(|e| e.to_string() == "disk full"),
+        "expected the io::Error as source, got {source:?}"
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  Messages de get() — listes des clés acceptées
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn unknown_output_key_lists_every_get_output() {
+    let msg = RefpropError::UnknownOutputKey("X".into()).to_string();
+    for key in Fluid::supported_outputs() {
+        assert!(
+            msg.split_whitespace().any(|word| word == *key),
+            "{key} should be listed, got {msg}"
+        );
+    }
+}
+
+#[test]
+fn unsupported_input_pair_lists_every_get_pair() {
+    let msg = RefpropError::UnsupportedInputPair("H".into(), "Q".into()).to_string();
+    for (a, b) in Fluid::supported_input_pairs() {
+        assert!(
+            msg.contains(&format!("({a},{b})")),
+            "({a},{b}) should be listed, got {msg}"
+        );
+    }
+}
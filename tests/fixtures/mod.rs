@@ -0,0 +1,35 @@
+//! Fluids for the integration tests, from REFPROP or the mock.
+//!
+//! With the `mock` feature, `REFPROP_TEST_BACKEND` picks where fluids
+//! come from (see `refprop::testing`); without it they always come from
+//! REFPROP.  Tests needing data the mock does not model return early
+//! through [`mock_lacks`].
+
+// Chaque fichier de test n'utilise qu'une partie de ces fonctions.
+#![allow(dead_code)]
+
+use refprop::{Fluid, UnitSystem};
+
+/// `name` in `units` from the selected backend.
+pub fn fluid(name: &str, units: UnitSystem) -> Fluid {
+    #[cfg(feature = "mock")]
+    let fluid = refprop::testing::TestBackend::from_env()
+        .unwrap()
+        .fluid(name, units);
+    #[cfg(not(feature = "mock"))]
+    let fluid = Fluid::with_units(name, units);
+    fluid.unwrap()
+}
+
+/// `true`, with a note on stderr, when fluids come from the mock, which
+/// has no `what` (saturation, transport, mixtures, …); the calling test
+/// then returns without checking anything.
+pub fn mock_lacks(what: &str) -> bool {
+    #[cfg(feature = "mock")]
+    if refprop::testing::TestBackend::from_env().unwrap() != refprop::testing::TestBackend::Real {
+        eprintln!("skipped: the mock has no {what}");
+        return true;
+    }
+    let _ = what;
+    false
+}
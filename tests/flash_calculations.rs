@@ -1,4 +1,12 @@
-use refprop::{Fluid, UnitSystem};
+#![cfg(feature = "refprop")]
+
+use std::ffi::{c_char, c_long};
+
+use refprop::sys::{REFPROP_STRLEN, to_c_string};
+use refprop::{Fluid, MassFlowUnit, Phase, PressUnit, RefpropError, TempUnit, UnitSystem};
+
+mod fixtures;
+use fixtures::{fluid, mock_lacks};
 
 // ═══════════════════════════════════════════════════════════════════
 //  Flash TP (Temperature-Pressure)
@@ -6,7 +14,10 @@ use refprop::{Fluid, UnitSystem};
 
 #[test]
 fn r134a_tp_flash_subcooled() {
-    let r134a = Fluid::with_units("R134A", UnitSystem::engineering()).unwrap();
+    if mock_lacks("liquid states") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::engineering());
     let props = r134a.props_tp(20.0, 10.0).unwrap();
     // À 20 °C et 10 bar, R134A est liquide sous-refroidi
     // quality < 0 ou > 100 signifie monophasique
@@ -25,7 +36,7 @@ fn r134a_tp_flash_subcooled() {
 
 #[test]
 fn r134a_tp_flash_superheated() {
-    let r134a = Fluid::with_units("R134A", UnitSystem::engineering()).unwrap();
+    let r134a = fluid("R134A", UnitSystem::engineering());
     let props = r134a.props_tp(50.0, 2.0).unwrap();
     // À 50 °C et 2 bar, R134A est vapeur surchauffée
     assert!(
@@ -44,7 +55,7 @@ fn r134a_tp_flash_superheated() {
 #[test]
 fn tp_flash_cp_greater_than_cv() {
     // Cp ≥ Cv pour tout fluide
-    let r134a = Fluid::with_units("R134A", UnitSystem::engineering()).unwrap();
+    let r134a = fluid("R134A", UnitSystem::engineering());
     let props = r134a.props_tp(25.0, 5.0).unwrap();
     assert!(
         props.cp >= props.cv,
@@ -56,7 +67,7 @@ fn tp_flash_cp_greater_than_cv() {
 
 #[test]
 fn tp_flash_positive_sound_speed() {
-    let r134a = Fluid::with_units("R134A", UnitSystem::engineering()).unwrap();
+    let r134a = fluid("R134A", UnitSystem::engineering());
     let props = r134a.props_tp(25.0, 5.0).unwrap();
     assert!(
         props.sound_speed > 0.0,
@@ -71,7 +82,10 @@ fn tp_flash_positive_sound_speed() {
 
 #[test]
 fn r134a_tq_flash_saturated_liquid() {
-    let r134a = Fluid::with_units("R134A", UnitSystem::engineering()).unwrap();
+    if mock_lacks("saturation data") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::engineering());
     let props = r134a.props_tq(0.0, 0.0).unwrap();
     // Pression de saturation ~2.93 bar
     assert!(
@@ -89,7 +103,10 @@ fn r134a_tq_flash_saturated_liquid() {
 
 #[test]
 fn r134a_tq_flash_saturated_vapor() {
-    let r134a = Fluid::with_units("R134A", UnitSystem::engineering()).unwrap();
+    if mock_lacks("saturation data") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::engineering());
     let props = r134a.props_tq(0.0, 100.0).unwrap();
     // Qualité = 100%
     assert!(
@@ -101,7 +118,10 @@ fn r134a_tq_flash_saturated_vapor() {
 
 #[test]
 fn r134a_tq_flash_two_phase() {
-    let r134a = Fluid::with_units("R134A", UnitSystem::engineering()).unwrap();
+    if mock_lacks("saturation data") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::engineering());
     let liq = r134a.props_tq(0.0, 0.0).unwrap();
     let vap = r134a.props_tq(0.0, 100.0).unwrap();
     // Le liquide doit être plus dense que la vapeur
@@ -128,7 +148,10 @@ fn r134a_tq_flash_two_phase() {
 /// by first computing H via TP, then recovering P via TH.
 #[test]
 fn r134a_th_flash_superheated() {
-    let r134a = Fluid::with_units("R134A", UnitSystem::engineering()).unwrap();
+    if mock_lacks("(T, H) flash") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::engineering());
     // Get reference properties at 50 °C, 5 bar (superheated vapor)
     let ref_props = r134a.props_tp(50.0, 5.0).unwrap();
     // Now recover state from (T, H)
@@ -149,7 +172,10 @@ fn r134a_th_flash_superheated() {
 /// TH flash with a different superheated state to verify consistency.
 #[test]
 fn r134a_th_flash_superheated_high_pressure() {
-    let r134a = Fluid::with_units("R134A", UnitSystem::engineering()).unwrap();
+    if mock_lacks("(T, H) flash") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::engineering());
     // 80 °C, 15 bar — well into superheated vapor
     let ref_props = r134a.props_tp(80.0, 15.0).unwrap();
     let props = r134a.props_th(80.0, ref_props.enthalpy).unwrap();
@@ -163,7 +189,10 @@ fn r134a_th_flash_superheated_high_pressure() {
 /// TH flash via get() — order-independent.
 #[test]
 fn r134a_th_get_pressure() {
-    let r134a = Fluid::with_units("R134A", UnitSystem::engineering()).unwrap();
+    if mock_lacks("(T, H) flash") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::engineering());
     let ref_props = r134a.props_tp(50.0, 5.0).unwrap();
     let p = r134a.get("P", "T", 50.0, "H", ref_props.enthalpy).unwrap();
     assert!(
@@ -188,7 +217,10 @@ fn r134a_th_get_pressure() {
 /// by first computing S via TP, then recovering P via TS.
 #[test]
 fn r134a_ts_flash_superheated() {
-    let r134a = Fluid::with_units("R134A", UnitSystem::engineering()).unwrap();
+    if mock_lacks("(T, S) flash") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::engineering());
     let ref_props = r134a.props_tp(50.0, 5.0).unwrap();
     let props = r134a.props_ts(50.0, ref_props.entropy).unwrap();
     assert!(
@@ -201,7 +233,10 @@ fn r134a_ts_flash_superheated() {
 /// TS flash via get() — order-independent.
 #[test]
 fn r134a_ts_get_pressure() {
-    let r134a = Fluid::with_units("R134A", UnitSystem::engineering()).unwrap();
+    if mock_lacks("(T, S) flash") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::engineering());
     let ref_props = r134a.props_tp(25.0, 8.0).unwrap();
     let p = r134a.get("P", "T", 25.0, "S", ref_props.entropy).unwrap();
     assert!(
@@ -224,7 +259,10 @@ fn r134a_ts_get_pressure() {
 
 #[test]
 fn r134a_td_flash_round_trip() {
-    let r134a = Fluid::with_units("R134A", UnitSystem::engineering()).unwrap();
+    if mock_lacks("(T, D) flash") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::engineering());
     let ref_props = r134a.props_tp(50.0, 5.0).unwrap();
     let props = r134a.props_td(50.0, ref_props.density).unwrap();
     assert!(
@@ -236,7 +274,10 @@ fn r134a_td_flash_round_trip() {
 
 #[test]
 fn r134a_td_get_enthalpy() {
-    let r134a = Fluid::with_units("R134A", UnitSystem::engineering()).unwrap();
+    if mock_lacks("(T, D) flash") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::engineering());
     let ref_props = r134a.props_tp(25.0, 8.0).unwrap();
     let h = r134a.get("H", "T", 25.0, "D", ref_props.density).unwrap();
     assert!(
@@ -253,7 +294,10 @@ fn r134a_td_get_enthalpy() {
 
 #[test]
 fn r134a_pd_flash_round_trip() {
-    let r134a = Fluid::with_units("R134A", UnitSystem::engineering()).unwrap();
+    if mock_lacks("(P, D) flash") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::engineering());
     let ref_props = r134a.props_tp(50.0, 5.0).unwrap();
     let props = r134a.props_pd(5.0, ref_props.density).unwrap();
     assert!(
@@ -269,7 +313,10 @@ fn r134a_pd_flash_round_trip() {
 
 #[test]
 fn r134a_dh_flash_round_trip() {
-    let r134a = Fluid::with_units("R134A", UnitSystem::engineering()).unwrap();
+    if mock_lacks("(D, H) flash") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::engineering());
     let ref_props = r134a.props_tp(50.0, 5.0).unwrap();
     let props = r134a
         .props_dh(ref_props.density, ref_props.enthalpy)
@@ -292,7 +339,10 @@ fn r134a_dh_flash_round_trip() {
 
 #[test]
 fn r134a_ds_flash_round_trip() {
-    let r134a = Fluid::with_units("R134A", UnitSystem::engineering()).unwrap();
+    if mock_lacks("(D, S) flash") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::engineering());
     let ref_props = r134a.props_tp(50.0, 5.0).unwrap();
     let props = r134a
         .props_ds(ref_props.density, ref_props.entropy)
@@ -310,7 +360,10 @@ fn r134a_ds_flash_round_trip() {
 
 #[test]
 fn r134a_hs_flash_round_trip() {
-    let r134a = Fluid::with_units("R134A", UnitSystem::engineering()).unwrap();
+    if mock_lacks("(H, S) flash") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::engineering());
     let ref_props = r134a.props_tp(50.0, 5.0).unwrap();
     let props = r134a
         .props_hs(ref_props.enthalpy, ref_props.entropy)
@@ -329,7 +382,10 @@ fn r134a_hs_flash_round_trip() {
 
 #[test]
 fn r134a_hs_get_temperature() {
-    let r134a = Fluid::with_units("R134A", UnitSystem::engineering()).unwrap();
+    if mock_lacks("(H, S) flash") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::engineering());
     let ref_props = r134a.props_tp(30.0, 6.0).unwrap();
     let t = r134a
         .get("T", "H", ref_props.enthalpy, "S", ref_props.entropy)
@@ -347,7 +403,10 @@ fn r134a_hs_get_temperature() {
 
 #[test]
 fn r134a_pq_flash_at_3bar() {
-    let r134a = Fluid::with_units("R134A", UnitSystem::engineering()).unwrap();
+    if mock_lacks("saturation data") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::engineering());
     let props = r134a.props_pq(3.0, 0.0).unwrap();
     // R134A Tsat(3 bar) ≈ 0.7 °C
     assert!(
@@ -356,3 +415,809 @@ fn r134a_pq_flash_at_3bar() {
         props.temperature
     );
 }
+
+#[test]
+fn r134a_quality_bounds_are_accepted() {
+    if mock_lacks("saturation data") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::refprop());
+    assert!(
+        r134a.props_tq(273.15, 0.0).is_ok(),
+        "Q = 0 % is a saturated liquid"
+    );
+    assert!(
+        r134a.props_pq(300.0, 100.0).is_ok(),
+        "Q = 100 % is a saturated vapor"
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  Évaluation directe (T, D) sans flash
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn r134a_td_direct_matches_td_flash_superheated() {
+    if mock_lacks("(T, D) flash") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::engineering());
+    let ref_props = r134a.props_tp(50.0, 5.0).unwrap();
+    let flash = r134a.props_td(50.0, ref_props.density).unwrap();
+    let direct = r134a.props_td_direct(50.0, ref_props.density).unwrap();
+    assert!(
+        (direct.pressure - flash.pressure).abs() < 1e-6,
+        "Direct P ({:.6}) should match TD flash P ({:.6})",
+        direct.pressure,
+        flash.pressure
+    );
+    assert!(
+        (direct.enthalpy - flash.enthalpy).abs() < 1e-6,
+        "Direct H ({:.6}) should match TD flash H ({:.6})",
+        direct.enthalpy,
+        flash.enthalpy
+    );
+}
+
+#[test]
+fn r134a_pressure_td_matches_td_flash_superheated() {
+    if mock_lacks("(T, D) flash") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::engineering());
+    for (t, p) in [(50.0, 5.0), (80.0, 12.0), (20.0, 1.0)] {
+        let d = r134a.props_tp(t, p).unwrap().density;
+        let flash = r134a.props_td(t, d).unwrap();
+        let p_eos = r134a.pressure_td(t, d).unwrap();
+        assert!(
+            (p_eos - flash.pressure).abs() < 1e-6 * flash.pressure,
+            "PRESSdll P ({p_eos:.6}) should match TD flash P ({:.6}) at {t} °C",
+            flash.pressure
+        );
+    }
+}
+
+#[test]
+fn td_direct_pathological_density_is_a_clean_error() {
+    let r134a = fluid("R134A", UnitSystem::refprop());
+    let result = r134a.props_td_direct(300.0, -1.0);
+    assert!(
+        matches!(result, Err(RefpropError::CalculationFailed(_))),
+        "A negative density should fail cleanly, got {result:?}"
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  Extrapolation au-delà des limites de validité
+// ═══════════════════════════════════════════════════════════════════
+
+/// R134A is validated up to Tmax = 455 K; sweep a little past it.
+#[test]
+fn r134a_tp_sweep_past_tmax_with_extrapolation() {
+    if mock_lacks("validity limits") {
+        return;
+    }
+    let mut r134a = fluid("R134A", UnitSystem::refprop());
+    r134a.allow_extrapolation(true).unwrap();
+
+    // FLAGSdll avec jflag = -999 lit l'état du drapeau sans le changer
+    let (ierr, kflag) = r134a
+        .with_raw_library(|lib| {
+            let hflag = to_c_string("Extrapolate", REFPROP_STRLEN);
+            let (jflag, mut kflag, mut ierr) = (-999, 0, 0);
+            let mut herr = [0 as c_char; REFPROP_STRLEN];
+            unsafe {
+                lib.FLAGSdll(
+                    hflag.as_ptr(),
+                    &jflag,
+                    &mut kflag,
+                    &mut ierr,
+                    herr.as_mut_ptr(),
+                    REFPROP_STRLEN as c_long,
+                    REFPROP_STRLEN as c_long,
+                )
+            }
+            .unwrap();
+            (ierr, kflag)
+        })
+        .unwrap();
+    assert_eq!(ierr, 0, "REFPROP should know the \"Extrapolate\" flag");
+    assert_eq!(kflag, 1, "The \"Extrapolate\" flag should be on");
+
+    let before = r134a.warning_count();
+    for i in 0..=10 {
+        let t = 450.0 + 2.0 * i as f64;
+        let props = r134a.props_tp(t, 1000.0).unwrap();
+        assert!(
+            props.density.is_finite() && props.density > 0.0,
+            "Extrapolated TP flash at T = {t} K should succeed, got D = {}",
+            props.density
+        );
+    }
+    assert_eq!(
+        r134a.warning_count(),
+        before,
+        "No range warning should be logged past Tmax with extrapolation on"
+    );
+}
+
+#[test]
+fn ignored_warning_codes_are_not_logged() {
+    if mock_lacks("validity limits") {
+        return;
+    }
+    // 470 K > Tmax = 455 K : TPFLSHdll renvoie l'avertissement -1
+    let mut r134a = fluid("R134A", UnitSystem::refprop());
+    let before = r134a.warning_count();
+    r134a.props_tp(470.0, 1000.0).unwrap();
+    assert!(
+        r134a.warning_count() > before,
+        "T above Tmax should log a warning"
+    );
+
+    // Un autre code dans la liste : l'avertissement -1 est toujours émis
+    r134a.ignore_warnings(&[-2]).unwrap();
+    let before = r134a.warning_count();
+    r134a.props_tp(470.0, 1000.0).unwrap();
+    assert!(r134a.warning_count() > before);
+
+    r134a.ignore_warnings(&[-1, -2]).unwrap();
+    assert_eq!(r134a.ignored_warnings(), &[-2, -1]);
+    let before = r134a.warning_count();
+    let props = r134a.props_tp(470.0, 1000.0).unwrap();
+    assert!(props.density > 0.0);
+    assert_eq!(r134a.warning_count(), before, "-1 should now be silent");
+
+    assert!(matches!(
+        r134a.ignore_warnings(&[1]),
+        Err(RefpropError::InvalidInput(_))
+    ));
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  États multiples en (T, P) sur la ligne de saturation
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn r134a_states_tp_on_saturation_line() {
+    if mock_lacks("saturation data") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::engineering());
+    let p_sat = r134a.saturation_t(0.0).unwrap().pressure;
+    let states = r134a.states_tp(0.0, p_sat).unwrap();
+    assert_eq!(states.len(), 2, "Saturated (T, P) should return two states");
+    assert!(
+        states[0].density > 10.0 * states[1].density,
+        "Liquid density ({:.2}) should be far above vapor density ({:.2})",
+        states[0].density,
+        states[1].density
+    );
+}
+
+#[test]
+fn r134a_states_tp_single_phase() {
+    let r134a = fluid("R134A", UnitSystem::engineering());
+    let states = r134a.states_tp(50.0, 2.0).unwrap();
+    assert_eq!(states.len(), 1, "Superheated (T, P) should return one state");
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  quality_ph / quality_th
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn r134a_quality_ph_two_phase() {
+    if mock_lacks("saturation data") {
+        return;
+    }
+    // 3 bar : h' ≈ 200.9 kJ/kg, h'' ≈ 398.6 kJ/kg → Q ≈ 25 %
+    let r134a = fluid("R134A", UnitSystem::engineering());
+    let q = r134a
+        .quality_ph(3.0, 250.0)
+        .unwrap()
+        .expect("Two-phase (P, H) should have a quality");
+    assert!(
+        q > 0.0 && q < 100.0,
+        "Quality should be in (0, 100), got {q}"
+    );
+    assert!(
+        (q - 25.0).abs() < 2.0,
+        "Quality should be ≈ 25 %, got {q:.3}"
+    );
+}
+
+#[test]
+fn r134a_quality_th_matches_quality_ph() {
+    if mock_lacks("saturation data") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::engineering());
+    let q_ph = r134a.quality_ph(3.0, 250.0).unwrap().unwrap();
+    let t = r134a.get("T", "P", 3.0, "H", 250.0).unwrap();
+    let q_th = r134a.quality_th(t, 250.0).unwrap().unwrap();
+    assert!(
+        (q_ph - q_th).abs() < 1e-3,
+        "quality_th and quality_ph should agree: {q_th} vs {q_ph}"
+    );
+}
+
+#[test]
+fn r134a_quality_ph_single_phase_is_none() {
+    let r134a = fluid("R134A", UnitSystem::engineering());
+    let q = r134a.quality_ph(3.0, 450.0).unwrap();
+    assert_eq!(q, None, "Superheated vapor should have no quality");
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  Erreurs typées pour les clés de get()
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn get_unknown_output_key() {
+    let r134a = fluid("R134A", UnitSystem::engineering());
+    match r134a.get("X", "T", 0.0, "P", 1.0) {
+        Err(RefpropError::UnknownOutputKey(key)) => assert_eq!(key, "X"),
+        other => panic!("Expected UnknownOutputKey, got {other:?}"),
+    }
+}
+
+#[test]
+fn get_unsupported_input_pair() {
+    let r134a = fluid("R134A", UnitSystem::engineering());
+    match r134a.get("T", "h", 250.0, "q", 50.0) {
+        Err(RefpropError::UnsupportedInputPair(k1, k2)) => {
+            assert_eq!((k1.as_str(), k2.as_str()), ("H", "Q"));
+        }
+        other => panic!("Expected UnsupportedInputPair, got {other:?}"),
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  Vitesse du son diphasique (équation de Wood)
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn r134a_two_phase_sound_speed_below_both_branches() {
+    if mock_lacks("saturation data") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::engineering());
+    let liq = r134a.props_tq(0.0, 0.0).unwrap();
+    let vap = r134a.props_tq(0.0, 100.0).unwrap();
+    let mix = r134a.props_tq(0.0, 50.0).unwrap();
+    assert!(
+        mix.sound_speed < liq.sound_speed && mix.sound_speed < vap.sound_speed,
+        "Two-phase W ({:.2}) should be below W_liq ({:.2}) and W_vap ({:.2})",
+        mix.sound_speed,
+        liq.sound_speed,
+        vap.sound_speed
+    );
+    assert!(mix.sound_speed > 0.0, "Two-phase W should stay positive");
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  Dérivées isothermes (∂H/∂P)_T et (∂ρ/∂P)_T
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn r134a_drhodp_t_positive_single_phase() {
+    let r134a = fluid("R134A", UnitSystem::engineering());
+    // (T °C, P bar) : liquide sous-refroidi, vapeur surchauffée, supercritique
+    let states = [
+        (0.0, 10.0),
+        (20.0, 30.0),
+        (50.0, 2.0),
+        (80.0, 10.0),
+        (120.0, 50.0),
+    ];
+    for (t, p) in states {
+        let dddp = r134a.drhodp_t(t, p).unwrap();
+        assert!(
+            dddp > 0.0,
+            "(∂ρ/∂P)_T should be > 0 at ({t}, {p}), got {dddp}"
+        );
+    }
+}
+
+#[test]
+fn r134a_drhodp_t_stable_under_step_halving() {
+    // Différence centrée manuelle avec un pas deux fois plus petit (1e-4·p / 2)
+    let r134a = fluid("R134A", UnitSystem::engineering());
+    let (t, p) = (50.0, 2.0);
+    let half = 0.5e-4 * p;
+    let lo = r134a.props_tp(t, p - half).unwrap();
+    let hi = r134a.props_tp(t, p + half).unwrap();
+    let manual = (hi.density - lo.density) / (2.0 * half);
+
+    let dddp = r134a.drhodp_t(t, p).unwrap();
+    assert!(
+        (dddp - manual).abs() < 1e-4 * manual.abs(),
+        "Derivative should be step-independent: {dddp} vs {manual}"
+    );
+
+    let dhdp = r134a.dhdp_t(t, p).unwrap();
+    let manual_h = (hi.enthalpy - lo.enthalpy) / (2.0 * half);
+    assert!(
+        (dhdp - manual_h).abs() <= 1e-3 * manual_h.abs(),
+        "dH/dP should be step-independent: {dhdp} vs {manual_h}"
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  Flash (H, S) avec indication de phase
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn r134a_hs_hint_selects_liquid_branch() {
+    if mock_lacks("(H, S) flash") {
+        return;
+    }
+    // État A : liquide sous-refroidi à 20 °C, 30 bar
+    let r134a = fluid("R134A", UnitSystem::engineering());
+    let a = r134a.props_tp(20.0, 30.0).unwrap();
+
+    let liq = r134a
+        .props_hs_hint(a.enthalpy, a.entropy, Phase::Liquid)
+        .unwrap();
+    assert!(
+        (liq.temperature - 20.0).abs() < 0.01 && (liq.pressure - 30.0).abs() < 0.05,
+        "Liquid hint should recover (20 °C, 30 bar), got ({:.4}, {:.4})",
+        liq.temperature,
+        liq.pressure
+    );
+
+    // L'autre branche ne doit jamais renvoyer l'état liquide A
+    if let Ok(vap) = r134a.props_hs_hint(a.enthalpy, a.entropy, Phase::Vapor) {
+        assert!(
+            (vap.pressure - 30.0).abs() > 0.5,
+            "Vapor hint should not return the liquid state, got P = {:.4}",
+            vap.pressure
+        );
+    }
+    assert!(
+        r134a
+            .props_hs_hint(a.enthalpy, a.entropy, Phase::TwoPhase)
+            .is_err(),
+        "A subcooled state is not two-phase"
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  Évaluation d'un chemin de points (paires d'entrée mixtes)
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn r134a_evaluate_path_mixed_pairs() {
+    if mock_lacks("saturation data") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::engineering());
+    let path = vec![
+        ("T".to_string(), 10.0, "P".to_string(), 3.0),
+        ("P".to_string(), 12.0, "Q".to_string(), 100.0),
+        ("p".to_string(), 12.0, "h".to_string(), 450.0),
+    ];
+    let states = r134a.evaluate_path(&path).unwrap();
+    assert_eq!(states.len(), 3);
+
+    let tp = r134a.props_tp(10.0, 3.0).unwrap();
+    assert!((states[0].enthalpy - tp.enthalpy).abs() < 1e-9);
+
+    let pq = r134a.props_pq(12.0, 100.0).unwrap();
+    assert!((states[1].temperature - pq.temperature).abs() < 1e-9);
+    assert!((states[1].quality - 100.0).abs() < 1e-9);
+
+    assert!((states[2].enthalpy - 450.0).abs() < 1e-6);
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  Paires d'entrée et sorties supportées par get()
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn every_supported_pair_and_output_succeeds_for_r134a() {
+    if mock_lacks("saturation data") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::engineering());
+    // Vapeur surchauffée pour les paires monophasiques, Q = 50 % sinon
+    let vapor = r134a.props_tp(25.0, 5.0).unwrap();
+    let wet = r134a.props_tq(0.0, 50.0).unwrap();
+
+    for &(k1, k2) in Fluid::supported_input_pairs() {
+        let state = if k1 == "Q" || k2 == "Q" { &wet } else { &vapor };
+        let value = |key: &str| match key {
+            "T" => state.temperature,
+            "P" => state.pressure,
+            "D" => state.density,
+            "H" => state.enthalpy,
+            "S" => state.entropy,
+            "Q" => state.quality,
+            other => panic!("unexpected input key {other}"),
+        };
+        for (a, b) in [(k1, k2), (k2, k1)] {
+            let result = r134a.get("T", a, value(a), b, value(b));
+            assert!(result.is_ok(), "get with ({a}, {b}) failed: {result:?}");
+        }
+    }
+
+    for &output in Fluid::supported_outputs() {
+        let result = r134a.get(output, "T", 25.0, "P", 5.0);
+        assert!(result.is_ok(), "get({output}) failed: {result:?}");
+    }
+}
+
+#[test]
+fn get_str_matches_numeric_get() {
+    if mock_lacks("saturation data") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::refprop());
+    let from_text = r134a.get_str("H", "T", "25 degC", "P", "5 bar").unwrap();
+    let numeric = r134a.get("H", "T", 298.15, "P", 500.0).unwrap();
+    assert!(
+        (from_text - numeric).abs() < 1e-9,
+        "get_str should match get: {from_text} vs {numeric}"
+    );
+
+    // Sans unité, la valeur suit les unités configurées
+    let eng = fluid("R134A", UnitSystem::engineering());
+    let d_text = eng.get_str("D", "T", "0", "Q", "100 %").unwrap();
+    let d_num = eng.get("D", "T", 0.0, "Q", 100.0).unwrap();
+    assert!((d_text - d_num).abs() < 1e-9);
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  Point d'état mis en cache (un seul flash)
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn at_tp_reads_five_properties_from_one_flash() {
+    if mock_lacks("transport properties") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::engineering());
+    let (t, p) = (25.0, 1.0);
+
+    let before = r134a.flash_count();
+    let state = r134a.at_tp(t, p).unwrap();
+    let cached = [
+        state.density(),
+        state.enthalpy(),
+        state.entropy(),
+        state.cp(),
+        state.viscosity().unwrap(),
+    ];
+    assert_eq!(
+        r134a.flash_count() - before,
+        1,
+        "at_tp and five reads should flash exactly once"
+    );
+
+    let before = r134a.flash_count();
+    let separate: Vec<f64> = ["D", "H", "S", "CP", "ETA"]
+        .iter()
+        .map(|key| r134a.get(key, "T", t, "P", p).unwrap())
+        .collect();
+    assert_eq!(r134a.flash_count() - before, 5);
+
+    for (c, g) in cached.iter().zip(&separate) {
+        assert!(
+            (c - g).abs() <= 1e-9 * g.abs(),
+            "StatePoint value {c} should match get() {g}"
+        );
+    }
+}
+
+#[test]
+fn get_outputs_flashes_once_and_evaluates_transport_once() {
+    if mock_lacks("transport properties") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::engineering());
+    let (t, p) = (25.0, 1.0);
+    let keys = ["D", "H", "ETA", "S", "TCX", "CP"];
+
+    let (flashes, transports) = (r134a.flash_count(), r134a.transport_count());
+    let combined = r134a.get_outputs(&keys, "T", t, "P", p).unwrap();
+    assert_eq!(r134a.flash_count() - flashes, 1, "one flash per request");
+    assert_eq!(
+        r134a.transport_count() - transports,
+        1,
+        "ETA and TCX should share one TRNPRPdll call"
+    );
+
+    for (key, c) in keys.iter().zip(&combined) {
+        let g = r134a.get(key, "T", t, "P", p).unwrap();
+        assert!(
+            (c - g).abs() <= 1e-9 * g.abs(),
+            "{key}: combined {c} should match get() {g}"
+        );
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  Évaluation par lots et sur grille
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn grid_and_batch_match_individual_get() {
+    let r134a = fluid("R134A", UnitSystem::engineering());
+    let temps = [20.0, 40.0, 60.0];
+    let pressures = [1.0, 2.0];
+
+    let grid = r134a.grid("H", "T", &temps, "P", &pressures).unwrap();
+    assert_eq!(grid.len(), temps.len());
+    for (row, &t) in grid.iter().zip(&temps) {
+        assert_eq!(row.len(), pressures.len());
+        for (&h, &p) in row.iter().zip(&pressures) {
+            let expected = r134a.get("H", "T", t, "P", p).unwrap();
+            assert!(
+                (h - expected).abs() < 1e-9,
+                "H({t}, {p}) = {h} vs {expected}"
+            );
+        }
+    }
+
+    let batch = r134a
+        .get_batch("D", "T", &temps[..2], "P", &pressures)
+        .unwrap();
+    assert_eq!(batch.len(), 2);
+    assert!(r134a.get_batch("D", "T", &temps, "P", &pressures).is_err());
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  Sorties molaires explicites (HMOLAR, DMOLAR, …)
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn molar_outputs_bypass_mass_units() {
+    let r134a = fluid("R134A", UnitSystem::engineering());
+    let mm = r134a.info().unwrap().molar_mass; // g/mol
+    let (t, p) = (25.0, 5.0); // °C, bar
+
+    // kJ/kg × g/mol = J/mol
+    let h = r134a.get("H", "T", t, "P", p).unwrap();
+    let h_molar = r134a.get("HMOLAR", "T", t, "P", p).unwrap();
+    assert!(
+        (h_molar - h * mm).abs() <= 1e-6 * h_molar.abs(),
+        "HMOLAR should equal H × M: {h_molar} vs {}",
+        h * mm
+    );
+
+    // kg/m³ ÷ g/mol = mol/L
+    let d = r134a.get("D", "T", t, "P", p).unwrap();
+    let d_molar = r134a.get("DMOLAR", "T", t, "P", p).unwrap();
+    assert!((d_molar - d / mm).abs() < 1e-9 * d_molar);
+
+    let cp = r134a.get("CP", "T", t, "P", p).unwrap();
+    let cp_molar = r134a.get("cpmolar", "T", t, "P", p).unwrap();
+    assert!((cp_molar - cp * mm).abs() < 1e-6 * cp_molar);
+
+    // Under REFPROP units the molar keys equal the plain ones
+    let native = fluid("R134A", UnitSystem::refprop());
+    let s = native.get("S", "T", 298.15, "P", 500.0).unwrap();
+    let s_molar = native.get("SMOLAR", "T", 298.15, "P", 500.0).unwrap();
+    assert_eq!(s, s_molar);
+}
+
+#[test]
+fn molar_ph_and_ps_flashes_recover_the_tp_state() {
+    let r134a = fluid("R134A", UnitSystem::engineering());
+    let (t, p) = (40.0, 5.0); // °C, bar — vapeur surchauffée
+    let (h_molar, _) = r134a.enthalpy_both(t, p).unwrap();
+
+    let state = r134a.props_ph_molar(p, h_molar).unwrap();
+    let reference = r134a.props_tp(t, p).unwrap();
+    assert!(
+        (state.temperature - t).abs() < 1e-6,
+        "PH flash at the molar enthalpy should give {t} °C, got {}",
+        state.temperature
+    );
+    assert!((state.enthalpy - reference.enthalpy).abs() < 1e-6 * reference.enthalpy.abs());
+
+    let s_molar = r134a.get("SMOLAR", "T", t, "P", p).unwrap();
+    let state = r134a.props_ps_molar(p, s_molar).unwrap();
+    assert!((state.temperature - t).abs() < 1e-6);
+    assert!((state.density - reference.density).abs() < 1e-6 * reference.density);
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  Table de propriétés précalculée (build_table / interpolate)
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn property_table_matches_flash_mid_cell() {
+    let r134a = fluid("R134A", UnitSystem::engineering());
+    // Vapeur surchauffée : 40–80 °C, 1–5 bar
+    let table = r134a
+        .build_table("H", 40.0..=80.0, 1.0..=5.0, 41, 41)
+        .unwrap();
+    assert_eq!(table.temperatures().len(), 41);
+    assert_eq!(table.pressures().len(), 41);
+
+    // Nodes reproduce the flash exactly
+    let h_node = r134a.get("H", "T", 40.0, "P", 1.0).unwrap();
+    assert_eq!(table.interpolate(40.0, 1.0).unwrap(), h_node);
+
+    // Middle of a cell (1 K × 0.1 bar)
+    for &(t, p) in &[(55.5, 2.35), (72.5, 4.05), (40.5, 1.05)] {
+        let direct = r134a.get("H", "T", t, "P", p).unwrap();
+        let interp = table.interpolate(t, p).unwrap();
+        assert!(
+            (interp - direct).abs() < 1e-3 * direct.abs(),
+            "interpolated H({t}, {p}) = {interp} vs flash {direct}"
+        );
+    }
+
+    assert!(matches!(
+        table.interpolate(85.0, 2.0),
+        Err(RefpropError::InvalidInput(_))
+    ));
+    assert!(table.interpolate(50.0, 0.5).is_err());
+    assert!(
+        r134a
+            .build_table("H", 40.0..=80.0, 1.0..=5.0, 1, 10)
+            .is_err()
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  Flux de matière (stream_tp)
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn stream_enthalpy_flow_scales_with_mass_flow() {
+    let r134a = fluid("R134A", UnitSystem::engineering());
+    let one = r134a.stream_tp(40.0, 5.0, 0.5).unwrap();
+    let two = r134a.stream_tp(40.0, 5.0, 1.0).unwrap();
+    assert!(
+        (two.enthalpy_flow - 2.0 * one.enthalpy_flow).abs() < 1e-9 * two.enthalpy_flow.abs(),
+        "doubling ṁ should double Ḣ: {} vs {}",
+        two.enthalpy_flow,
+        one.enthalpy_flow
+    );
+    assert!((two.entropy_flow - 2.0 * one.entropy_flow).abs() < 1e-9 * two.entropy_flow.abs());
+    // kJ/kg × kg/s = kW
+    let h = r134a.get("H", "T", 40.0, "P", 5.0).unwrap();
+    assert!((one.enthalpy_flow - 0.5 * h).abs() < 1e-9 * h.abs());
+    assert_eq!(one.density, one.state.density);
+
+    // Same stream in kg/h under molar units: flows stay in kW
+    let molar = fluid(
+        "R134A",
+        UnitSystem::new()
+            .temperature(TempUnit::Celsius)
+            .pressure(PressUnit::Bar)
+            .mass_flow(MassFlowUnit::KgPerH),
+    );
+    let per_hour = molar.stream_tp(40.0, 5.0, 1800.0).unwrap();
+    assert!((per_hour.mass_flow - 0.5).abs() < 1e-12);
+    assert!((per_hour.enthalpy_flow - one.enthalpy_flow).abs() < 1e-9 * h.abs());
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  Différence d'enthalpie et puissance échangée
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn r134a_superheating_enthalpy_difference() {
+    if mock_lacks("saturation data") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::engineering());
+    // Vapeur saturée à 0 °C, puis 20 K de surchauffe à la même pression
+    let p_sat = r134a.get("P", "T", 0.0, "Q", 100.0).unwrap();
+    let inlet = ("T", 0.0, "Q", 100.0);
+    let outlet = ("T", 20.0, "P", p_sat);
+
+    let dh = r134a.enthalpy_difference(inlet, outlet).unwrap();
+    let h_in = r134a.get("H", "T", 0.0, "Q", 100.0).unwrap();
+    let h_out = r134a.get("H", "T", 20.0, "P", p_sat).unwrap();
+    assert!(dh > 0.0, "superheating must raise the enthalpy, got {dh}");
+    assert!(
+        (dh - (h_out - h_in)).abs() < 1e-9 * h_out.abs(),
+        "Δh = {dh} kJ/kg vs {} from two get calls",
+        h_out - h_in
+    );
+
+    // 0.2 kg/s × Δh (kJ/kg) = kW
+    let q = r134a.heat_duty(inlet, outlet, 0.2).unwrap();
+    assert!((q - 0.2 * dh).abs() < 1e-9 * q.abs());
+    assert!(r134a.heat_duty(outlet, inlet, 0.2).unwrap() < 0.0);
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  Titre hors plage : erreur plutôt que saturation forcée
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn quality_outside_range_is_rejected() {
+    if mock_lacks("saturation data") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::engineering());
+    // Titres en % hors bornes, rejetés par le convertisseur (la garde du
+    // backend sur la fraction molaire est testée dans src/backend)
+    for q in [-10.0, 110.0, -0.1] {
+        assert!(
+            matches!(r134a.props_tq(0.0, q), Err(RefpropError::InvalidInput(_))),
+            "props_tq should reject Q = {q} %"
+        );
+        assert!(
+            matches!(r134a.props_pq(3.0, q), Err(RefpropError::InvalidInput(_))),
+            "props_pq should reject Q = {q} %"
+        );
+        assert!(matches!(
+            r134a.get("D", "T", 0.0, "Q", q),
+            Err(RefpropError::InvalidInput(_))
+        ));
+    }
+
+    // Bornes incluses : liquide et vapeur saturés
+    let (liq, vap) = r134a.saturation_both(0.0).unwrap();
+    let q0 = r134a.props_tq(0.0, 0.0).unwrap();
+    let q100 = r134a.props_tq(0.0, 100.0).unwrap();
+    assert!((q0.density - liq.density).abs() < 1e-9 * liq.density);
+    assert!((q100.density - vap.density).abs() < 1e-9 * vap.density);
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  Titre en sortie : None / NaN hors du dôme
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn co2_quality_is_none_when_supercritical_and_some_in_the_dome() {
+    if mock_lacks("saturation data") {
+        return;
+    }
+    let co2 = fluid("CO2", UnitSystem::engineering());
+
+    // 50 °C, 100 bar : au-dessus de Tc ≈ 31 °C et Pc ≈ 73.8 bar
+    assert_eq!(co2.get_quality("T", 50.0, "P", 100.0).unwrap(), None);
+    assert!(co2.get("Q", "T", 50.0, "P", 100.0).unwrap().is_nan());
+    // Vapeur surchauffée : monophasique aussi
+    assert_eq!(co2.get_quality("T", 20.0, "P", 20.0).unwrap(), None);
+
+    let (h_liq, h_vap) = co2.get_saturation("H", "P", 40.0).unwrap();
+    let q = co2
+        .get_quality("P", 40.0, "H", 0.5 * (h_liq + h_vap))
+        .unwrap()
+        .expect("mid-dome state should have a quality");
+    assert!((q - 50.0).abs() < 0.5, "Expected Q ≈ 50 %, got {q}");
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  Recherche de température le long d'une isobare
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn r134a_temperature_at_target_density_5_bar() {
+    if mock_lacks("liquid states") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::engineering());
+
+    // Liquide sous-refroidi puis vapeur surchauffée (Tsat(5 bar) ≈ 15.7 °C)
+    for (d_target, liquid) in [(1280.0, true), (20.0, false)] {
+        let t = r134a.solve_temperature(5.0, "D", d_target).unwrap();
+        let d = r134a.get("D", "T", t, "P", 5.0).unwrap();
+        assert!(
+            (d - d_target).abs() < 1e-6 * d_target,
+            "forward flash at T = {t} °C gives D = {d} kg/m³, expected {d_target}"
+        );
+        let t_sat = r134a.get("T", "P", 5.0, "Q", 0.0).unwrap();
+        assert_eq!(t < t_sat, liquid, "T = {t} °C vs Tsat = {t_sat} °C");
+    }
+
+    // Densité dans le saut liquide → vapeur : pas de racine continue
+    assert!(matches!(
+        r134a.solve_temperature(5.0, "D", 500.0),
+        Err(RefpropError::CalculationFailed(_))
+    ));
+    assert!(matches!(
+        r134a.solve_temperature(5.0, "P", 5.0),
+        Err(RefpropError::InvalidInput(_))
+    ));
+}
@@ -1,4 +1,9 @@
-use refprop::Fluid;
+#![cfg(feature = "refprop")]
+
+use refprop::{DipoleUnit, Fluid, RefpropError, UnitSystem};
+
+mod fixtures;
+use fixtures::{fluid, mock_lacks};
 
 // ═══════════════════════════════════════════════════════════════════
 //  FluidInfo — constantes physiques
@@ -7,7 +12,7 @@ use refprop::Fluid;
 #[test]
 fn r134a_molar_mass() {
     // R134A: M ≈ 102.032 g/mol
-    let r134a = Fluid::new("R134A").unwrap();
+    let r134a = fluid("R134A", UnitSystem::refprop());
     let info = r134a.info().unwrap();
     assert!(
         (info.molar_mass - 102.032).abs() < 0.1,
@@ -19,7 +24,7 @@ fn r134a_molar_mass() {
 #[test]
 fn co2_molar_mass() {
     // CO2: M ≈ 44.01 g/mol
-    let co2 = Fluid::new("CO2").unwrap();
+    let co2 = fluid("CO2", UnitSystem::refprop());
     let info = co2.info().unwrap();
     assert!(
         (info.molar_mass - 44.01).abs() < 0.1,
@@ -30,8 +35,11 @@ fn co2_molar_mass() {
 
 #[test]
 fn water_molar_mass() {
+    if mock_lacks("WATER data") {
+        return;
+    }
     // H2O: M ≈ 18.015 g/mol
-    let water = Fluid::new("WATER").unwrap();
+    let water = fluid("WATER", UnitSystem::refprop());
     let info = water.info().unwrap();
     assert!(
         (info.molar_mass - 18.015).abs() < 0.1,
@@ -43,7 +51,7 @@ fn water_molar_mass() {
 #[test]
 fn r134a_triple_point() {
     // R134A: T_triple ≈ 169.85 K (-103.3 °C)
-    let r134a = Fluid::new("R134A").unwrap();
+    let r134a = fluid("R134A", UnitSystem::refprop());
     let info = r134a.info().unwrap();
     assert!(
         (info.triple_point_temp - 169.85).abs() < 1.0,
@@ -55,7 +63,7 @@ fn r134a_triple_point() {
 #[test]
 fn r134a_normal_boiling_point() {
     // R134A: T_nbp ≈ 247.08 K (-26.07 °C)
-    let r134a = Fluid::new("R134A").unwrap();
+    let r134a = fluid("R134A", UnitSystem::refprop());
     let info = r134a.info().unwrap();
     assert!(
         (info.normal_boiling_point - 247.08).abs() < 1.0,
@@ -64,10 +72,46 @@ fn r134a_normal_boiling_point() {
     );
 }
 
+#[test]
+fn r134a_normal_boiling_point_state_at_one_atm() {
+    if mock_lacks("saturation data") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::engineering());
+    let nbp = r134a.normal_boiling_point().unwrap();
+    assert!(
+        (nbp.pressure - 1.01325).abs() < 1e-4,
+        "Normal-boiling-point pressure should be 1 atm, got {:.6} bar",
+        nbp.pressure
+    );
+    assert!(
+        (nbp.temperature - (-26.07)).abs() < 0.1,
+        "R134A T_nbp expected ≈ -26.07 °C, got {:.4}",
+        nbp.temperature
+    );
+    assert!(nbp.quality.abs() < 1e-9, "State should be saturated liquid");
+}
+
+#[test]
+fn r134a_triple_point_state() {
+    if mock_lacks("saturation data") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::refprop());
+    let t_trp = r134a.info().unwrap().triple_point_temp;
+    let trp = r134a.triple_point().unwrap();
+    assert!((trp.temperature - t_trp).abs() < 1e-6);
+    assert!(
+        trp.pressure > 0.0 && trp.pressure < 1.0,
+        "R134A triple-point pressure expected ≈ 0.39 kPa, got {:.4}",
+        trp.pressure
+    );
+}
+
 #[test]
 fn fluid_info_gas_constant() {
     // La constante R est universelle ≈ 8.314 J/(mol·K)
-    let r134a = Fluid::new("R134A").unwrap();
+    let r134a = fluid("R134A", UnitSystem::refprop());
     let info = r134a.info().unwrap();
     assert!(
         (info.gas_constant - 8.314).abs() < 0.01,
@@ -75,3 +119,647 @@ fn fluid_info_gas_constant() {
         info.gas_constant
     );
 }
+
+#[test]
+fn r134a_component_dipole_in_coulomb_meter() {
+    let units = UnitSystem::new().dipole(DipoleUnit::CoulombMeter);
+    let r134a = fluid("R134A", units);
+    let debye = r134a.info().unwrap().dipole_moment;
+    let cm = r134a.component_info(0).unwrap().dipole_moment;
+    assert!(
+        (cm - debye * 3.33564e-30).abs() < 1e-36,
+        "μ should be {debye} D = {:e} C·m, got {cm:e}",
+        debye * 3.33564e-30
+    );
+}
+
+#[test]
+fn r407c_component_info_per_component() {
+    if mock_lacks("mixtures") {
+        return;
+    }
+    let r407c = fluid("R407C", UnitSystem::refprop());
+    let names = r407c.component_names();
+    assert_eq!(names.len(), 3, "R407C has three components: {names:?}");
+    for (i, name) in names.iter().enumerate() {
+        let info = r407c.component_info(i).unwrap();
+        assert!(info.acentric_factor > 0.0, "ω of {name} should be > 0");
+    }
+    assert!(r407c.component_info(3).is_err());
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  Modèles de transport (ECS ou corrélation dédiée)
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn transport_reference_matches_model_kind() {
+    if mock_lacks("transport properties") {
+        return;
+    }
+    for name in ["R134A", "R1234YF"] {
+        let fluid = Fluid::new(name).unwrap();
+        let (eta, tcx) = fluid.transport_models(0).unwrap();
+        assert_eq!(eta.len(), 3, "{name} viscosity model code, got {eta:?}");
+        assert_eq!(tcx.len(), 3, "{name} conductivity model code, got {tcx:?}");
+
+        let reference = fluid.transport_reference().unwrap();
+        if fluid.uses_ecs_transport().unwrap() {
+            assert_ne!(
+                reference, name,
+                "{name} ECS reference should be another fluid"
+            );
+        } else {
+            assert_eq!(reference, name, "{name} dedicated model references itself");
+        }
+    }
+}
+
+#[test]
+fn transport_reference_rejects_mixtures() {
+    if mock_lacks("mixtures") {
+        return;
+    }
+    let r407c = fluid("R407C", UnitSystem::refprop());
+    assert!(r407c.transport_reference().is_err());
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  Choix de l'équation d'état (SETMODdll)
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn selecting_an_alternative_eos_changes_density() {
+    if mock_lacks("fluid files") {
+        return;
+    }
+    let mut r134a = fluid("R134A", UnitSystem::engineering());
+    let models = r134a.available_models().unwrap();
+    // R134A.FLD : Tillner-Roth & Baehr (FEQ) recommandée, MBWR et ECS
+    // en alternatives
+    assert_eq!(
+        models.first().map(String::as_str),
+        Some("FEQ"),
+        "R134A.FLD should recommend its FEQ equation, got {models:?}"
+    );
+    let alternative = models
+        .iter()
+        .find(|m| *m == "BWR" || *m == "ECS")
+        .cloned()
+        .unwrap_or_else(|| {
+            panic!("R134A.FLD should ship a BWR or ECS alternative, got {models:?}")
+        });
+
+    let d_default = r134a.get("D", "T", 25.0, "P", 10.0).unwrap();
+    r134a.select_model(&alternative).unwrap();
+    assert_eq!(r134a.selected_model(), Some(alternative.as_str()));
+    let d_alt = r134a.get("D", "T", 25.0, "P", 10.0).unwrap();
+    assert!(
+        (d_alt - d_default).abs() > 1e-6 * d_default,
+        "{alternative} should differ from {} at 25 °C, 10 bar: {d_alt} vs {d_default}",
+        models[0]
+    );
+
+    // Un autre Fluid reste sur le modèle recommandé
+    let other = fluid("R134A", UnitSystem::engineering());
+    let d_other = other.get("D", "T", 25.0, "P", 10.0).unwrap();
+    assert!((d_other - d_default).abs() < 1e-9 * d_default);
+    // … et le modèle choisi est réappliqué au retour
+    let d_again = r134a.get("D", "T", 25.0, "P", 10.0).unwrap();
+    assert_eq!(d_again, d_alt);
+
+    assert!(matches!(
+        r134a.select_model("XYZ"),
+        Err(RefpropError::InvalidInput(_))
+    ));
+    assert!(
+        fluid("R407C", UnitSystem::refprop())
+            .available_models()
+            .is_err()
+    );
+}
+
+#[test]
+fn fluid_without_transport_models_reports_unavailable() {
+    if mock_lacks("fluid files") {
+        return;
+    }
+    // R134A privé de ses sections de transport (#TRN, #ETA, #TCX, #TK…) :
+    // la thermo reste calculable, le transport ne l'est plus
+    let _installed = fluid("R134A", UnitSystem::refprop()); // initialise REFPROP_PATH via .env
+    let base = std::path::PathBuf::from(std::env::var("REFPROP_PATH").unwrap());
+    let fld = ["fluids", "FLUIDS"]
+        .iter()
+        .map(|dir| base.join(dir).join("R134A.FLD"))
+        .find(|p| p.exists())
+        .expect("R134A.FLD should be installed");
+    let contents = String::from_utf8_lossy(&std::fs::read(fld).unwrap()).into_owned();
+
+    let is_transport = |line: &str| {
+        let key = line.get(1..4).unwrap_or_default().to_uppercase();
+        ["TRN", "ETA", "TCX"].contains(&key.as_str()) || key.starts_with("TK")
+    };
+    let mut skipping = false;
+    let stripped: Vec<&str> = contents
+        .lines()
+        .filter(|line| {
+            if line.starts_with('#') || line.starts_with('@') {
+                skipping = is_transport(line);
+            }
+            !skipping
+        })
+        .collect();
+    assert!(
+        stripped.len() < contents.lines().count(),
+        "R134A.FLD should have transport sections"
+    );
+
+    let fluid = Fluid::from_definition(stripped.join("\n"), UnitSystem::engineering()).unwrap();
+    let state = fluid.props_tp(25.0, 1.0).unwrap();
+    assert!(
+        state.density > 0.0,
+        "Thermodynamic properties should still work"
+    );
+    match fluid.transport(25.0, state.density) {
+        Err(RefpropError::TransportUnavailable(_)) => {}
+        other => panic!("Expected TransportUnavailable, got {other:?}"),
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  Résolution des alias (numéros R, CAS)
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn r290_resolves_to_propane() {
+    if mock_lacks("fluid aliases") {
+        return;
+    }
+    // R290 = propane, M ≈ 44.096 g/mol
+    let r290 = fluid("R290", UnitSystem::refprop());
+    let info = r290.info().unwrap();
+    assert!(
+        (info.molar_mass - 44.096).abs() < 0.1,
+        "R290 should load PROPANE.FLD (M ≈ 44.10 g/mol), got {:.4}",
+        info.molar_mass
+    );
+}
+
+#[test]
+fn r600a_resolves_to_isobutane() {
+    if mock_lacks("fluid aliases") {
+        return;
+    }
+    // R600a = isobutane, M ≈ 58.122 g/mol
+    let r600a = Fluid::new("R600a").unwrap();
+    let info = r600a.info().unwrap();
+    assert!(
+        (info.molar_mass - 58.122).abs() < 0.1,
+        "R600a should load ISOBUTAN.FLD (M ≈ 58.12 g/mol), got {:.4}",
+        info.molar_mass
+    );
+}
+
+#[test]
+fn cas_number_resolves_to_propane() {
+    if mock_lacks("fluid aliases") {
+        return;
+    }
+    // Propane CAS: 74-98-6
+    let propane = Fluid::new("74-98-6").unwrap();
+    let info = propane.info().unwrap();
+    assert!(
+        (info.molar_mass - 44.096).abs() < 0.1,
+        "CAS 74-98-6 should load PROPANE.FLD, got M = {:.4}",
+        info.molar_mass
+    );
+}
+
+#[test]
+fn custom_alias_is_honoured() {
+    if mock_lacks("fluid aliases") {
+        return;
+    }
+    Fluid::with_alias("MY_REFRIGERANT", "R134A");
+    let fluid = Fluid::new("my_refrigerant").unwrap();
+    let info = fluid.info().unwrap();
+    assert!(
+        (info.molar_mass - 102.032).abs() < 0.1,
+        "Custom alias should load R134A.FLD, got M = {:.4}",
+        info.molar_mass
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  Version et emplacement de la bibliothèque
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn library_path_points_at_existing_file() {
+    if mock_lacks("library file") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::refprop());
+    let path = r134a.library_path();
+    assert!(
+        path.is_file(),
+        "{} should be an existing file",
+        path.display()
+    );
+
+    let fp = r134a.fluids_fingerprint().unwrap();
+    assert_eq!(fp.len(), 16);
+    assert_eq!(
+        fp,
+        r134a.fluids_fingerprint().unwrap(),
+        "Fingerprint should be stable"
+    );
+
+    // RPVersion n'existe qu'à partir de REFPROP 10
+    if let Ok(version) = r134a.refprop_version() {
+        assert!(!version.is_empty());
+    }
+}
+
+#[test]
+fn error_code_maps_to_standard_message() {
+    let r134a = fluid("R134A", UnitSystem::refprop());
+    // ERRMSGdll n'existe qu'à partir de REFPROP 10
+    if r134a.refprop_version().is_err() {
+        return;
+    }
+    // 50 K est bien en dessous du point triple (≈ 169.85 K)
+    let code = match r134a.props_tp(50.0, 100.0) {
+        Err(RefpropError::Refprop { code, .. }) => code,
+        other => panic!("Expected a REFPROP error below Tmin, got {other:?}"),
+    };
+    let message = r134a.error_message(code).unwrap();
+    assert!(
+        message.to_lowercase().contains("temperature"),
+        "Message for code {code} should mention the temperature limit, got {message:?}"
+    );
+}
+
+#[test]
+fn property_names_resolve_through_getenum() {
+    let r134a = fluid("R134A", UnitSystem::refprop());
+    // GETENUMdll n'existe qu'à partir de REFPROP 10
+    if r134a.refprop_version().is_err() {
+        assert!(matches!(
+            r134a.property_enum("D"),
+            Err(RefpropError::Sys(_))
+        ));
+        return;
+    }
+    let d = r134a.property_enum("D").unwrap();
+    let h = r134a.property_enum("Hmass").unwrap();
+    assert_ne!(d, h, "D and Hmass should map to different codes");
+    assert!(
+        matches!(
+            r134a.property_enum("NOT_A_PROPERTY"),
+            Err(RefpropError::InvalidInput(_))
+        ),
+        "an unknown property name should be rejected"
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  Définition de fluide en mémoire
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn from_definition_matches_installed_fluid() {
+    if mock_lacks("fluid files") {
+        return;
+    }
+    // Charger R134A normalement (initialise aussi REFPROP_PATH via .env)
+    let installed = fluid("R134A", UnitSystem::engineering());
+    let base = std::path::PathBuf::from(std::env::var("REFPROP_PATH").unwrap());
+    let fld = ["fluids", "FLUIDS"]
+        .iter()
+        .map(|dir| base.join(dir).join("R134A.FLD"))
+        .find(|p| p.exists())
+        .expect("R134A.FLD should be installed");
+
+    // Copie dans un répertoire temporaire, puis lecture du contenu
+    let copy_dir = std::env::temp_dir().join(format!("refprop-test-{}", std::process::id()));
+    std::fs::create_dir_all(&copy_dir).unwrap();
+    let copy = copy_dir.join("R134A.FLD");
+    std::fs::copy(&fld, &copy).unwrap();
+    // Octets bruts : certains fichiers .FLD ont des commentaires Latin-1
+    let contents = std::fs::read(&copy).unwrap();
+    let _ = std::fs::remove_dir_all(&copy_dir);
+
+    let embedded = Fluid::from_definition(&contents, UnitSystem::engineering()).unwrap();
+    let p_installed = installed.get("P", "T", 0.0, "Q", 0.0).unwrap();
+    let p_embedded = embedded.get("P", "T", 0.0, "Q", 0.0).unwrap();
+    assert!(
+        (p_installed - p_embedded).abs() < 1e-9,
+        "Embedded definition should match installed R134A: {p_embedded} vs {p_installed}"
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  Bibliothèque et fichiers de fluides dans des répertoires séparés
+// ═══════════════════════════════════════════════════════════════════
+
+fn copy_dir(from: &std::path::Path, to: &std::path::Path) {
+    std::fs::create_dir_all(to).unwrap();
+    for entry in std::fs::read_dir(from).unwrap().flatten() {
+        if entry.path().is_file() {
+            std::fs::copy(entry.path(), to.join(entry.file_name())).unwrap();
+        }
+    }
+}
+
+#[test]
+fn with_paths_loads_dll_and_fluids_from_different_directories() {
+    if mock_lacks("library file") {
+        return;
+    }
+    let installed = fluid("R134A", UnitSystem::refprop()); // initialise REFPROP_PATH via .env
+    let dll = installed.library_path().to_path_buf();
+    assert!(dll.is_file(), "{} should be a file", dll.display());
+    let base = std::path::PathBuf::from(std::env::var("REFPROP_PATH").unwrap());
+
+    let root = std::env::temp_dir().join(format!("refprop-paths-{}", std::process::id()));
+    let dll_dir = root.join("lib");
+    let data_dir = root.join("data");
+    std::fs::create_dir_all(&dll_dir).unwrap();
+    std::fs::copy(&dll, dll_dir.join(dll.file_name().unwrap())).unwrap();
+    for sub in ["fluids", "FLUIDS", "mixtures", "MIXTURES"] {
+        if base.join(sub).is_dir() {
+            copy_dir(&base.join(sub), &data_dir.join(sub));
+        }
+    }
+
+    let split = Fluid::with_paths(
+        "R134A",
+        UnitSystem::refprop(),
+        dll_dir.to_str().unwrap(),
+        data_dir.to_str().unwrap(),
+    );
+    let missing = Fluid::with_paths(
+        "R134A",
+        UnitSystem::refprop(),
+        dll_dir.to_str().unwrap(),
+        root.join("nowhere").to_str().unwrap(),
+    );
+    // Le Fluid est libéré avant la suppression des répertoires
+    let p_split = split.and_then(|f| f.get("P", "T", 273.15, "Q", 0.0));
+    let _ = std::fs::remove_dir_all(&root);
+
+    let p_split = p_split.unwrap();
+    let p_installed = installed.get("P", "T", 273.15, "Q", 0.0).unwrap();
+    assert!(
+        (p_split - p_installed).abs() < 1e-9,
+        "Split directories should give the same Psat: {p_split} vs {p_installed}"
+    );
+    assert!(
+        missing.is_err(),
+        "A missing fluids directory should be rejected"
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  Capture de la sortie console
+// ═══════════════════════════════════════════════════════════════════
+
+#[cfg(unix)]
+#[test]
+fn capture_output_redirects_stdout() {
+    if mock_lacks("library file") {
+        return;
+    }
+    use std::io::Write;
+
+    let capture = Fluid::capture_output().unwrap();
+    assert!(
+        Fluid::capture_output().is_err(),
+        "A second concurrent capture should be rejected"
+    );
+    // Écriture directe sur le descripteur 1, comme le ferait REFPROP
+    let mut stdout = std::io::stdout();
+    stdout.write_all(b"REFPROP capture marker\n").unwrap();
+    stdout.flush().unwrap();
+    let fluid = Fluid::new("R134A");
+    let log = capture.finish();
+
+    assert!(fluid.is_ok(), "Fluid should load while capturing");
+    assert!(
+        log.contains("REFPROP capture marker"),
+        "Stdout writes should end up in the capture, got {log:?}"
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  Construction validée (flash de référence)
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn new_validated_accepts_usable_fluids() {
+    if mock_lacks("saturation data") {
+        return;
+    }
+    for name in ["R134A", "CO2", "WATER", "R407C"] {
+        let fluid = Fluid::new_validated(name);
+        assert!(fluid.is_ok(), "{name} should validate: {:?}", fluid.err());
+    }
+    assert!(Fluid::new_validated("NOT_A_FLUID").is_err());
+}
+
+#[test]
+fn validated_rejects_definition_without_usable_eos() {
+    if mock_lacks("fluid files") {
+        return;
+    }
+    // R134A dont les paramètres de réduction de l'EOS sont annulés : SETUP
+    // passe, mais le premier flash ne peut pas aboutir
+    let _installed = fluid("R134A", UnitSystem::refprop()); // initialise REFPROP_PATH via .env
+    let base = std::path::PathBuf::from(std::env::var("REFPROP_PATH").unwrap());
+    let fld = ["fluids", "FLUIDS"]
+        .iter()
+        .map(|dir| base.join(dir).join("R134A.FLD"))
+        .find(|p| p.exists())
+        .expect("R134A.FLD should be installed");
+    let contents = String::from_utf8_lossy(&std::fs::read(fld).unwrap()).into_owned();
+    let mut replaced = 0;
+    let broken: Vec<String> = contents
+        .lines()
+        .map(|line| {
+            if line.to_lowercase().contains("!reducing parameters") {
+                replaced += 1;
+                "0.0  0.0  0.0   !reducing parameters".to_string()
+            } else {
+                line.to_string()
+            }
+        })
+        .collect();
+    assert!(
+        replaced > 0,
+        "R134A.FLD should have a reducing-parameters line"
+    );
+
+    let fluid = Fluid::from_definition(broken.join("\n"), UnitSystem::refprop())
+        .expect("SETUPdll accepts the broken definition");
+    let result = fluid.validated();
+    assert!(
+        matches!(result, Err(RefpropError::CalculationFailed(_))),
+        "A zero-reducing-parameter EOS should fail the reference flash, got {:?}",
+        result.map(|_| ())
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  Incertitudes déclarées dans les fichiers .FLD
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn water_liquid_density_uncertainty_is_small() {
+    if mock_lacks("WATER data") {
+        return;
+    }
+    let water = fluid("WATER", UnitSystem::engineering());
+    let u = water.property_uncertainty("D", 25.0, 1.01325).unwrap();
+    assert!(
+        u > 0.0 && u < 0.01,
+        "Water liquid density uncertainty should be a small positive fraction, got {u}"
+    );
+    let err = water
+        .property_uncertainty("XYZ", 25.0, 1.01325)
+        .unwrap_err();
+    assert!(
+        matches!(&err, RefpropError::InvalidInput(msg) if msg.contains("CP")),
+        "An unsupported key should list the supported ones, got {err:?}"
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  Accès brut à la bibliothèque
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn raw_library_therm_matches_props_td_direct() {
+    use refprop::sys::REFPROP_NC_MAX;
+
+    let r134a = fluid("R134A", UnitSystem::refprop());
+    let (t, d) = (300.0, 12.0); // K, mol/L (liquid)
+    let mut z = [0.0; REFPROP_NC_MAX];
+    z[0] = 1.0;
+
+    let (p, h) = r134a
+        .with_raw_library(|lib| {
+            let (mut p, mut e, mut h, mut s) = (0.0, 0.0, 0.0, 0.0);
+            let (mut cv, mut cp, mut w, mut hjt) = (0.0, 0.0, 0.0, 0.0);
+            unsafe {
+                lib.THERMdll(
+                    &t,
+                    &d,
+                    z.as_ptr(),
+                    &mut p,
+                    &mut e,
+                    &mut h,
+                    &mut s,
+                    &mut cv,
+                    &mut cp,
+                    &mut w,
+                    &mut hjt,
+                )
+            };
+            (p, h)
+        })
+        .unwrap();
+
+    let safe = r134a.props_td_direct(t, d).unwrap();
+    assert_eq!(
+        p, safe.pressure,
+        "raw THERMdll pressure should match the wrapper"
+    );
+    assert_eq!(
+        h, safe.enthalpy,
+        "raw THERMdll enthalpy should match the wrapper"
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  Table des symboles résolus (chargement tolérant)
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn lenient_load_reports_symbols_and_serves_pure_fluids() {
+    if mock_lacks("library file") {
+        return;
+    }
+    use refprop::sys::{ALL_SYMBOLS, CORE_SYMBOLS, RefpropLibrary};
+
+    let dir = Fluid::discover_refprop_path().unwrap();
+    let lib = RefpropLibrary::load_lenient_from_dir(std::path::Path::new(&dir)).unwrap();
+    let resolved = lib.resolved_symbols();
+    for core in CORE_SYMBOLS {
+        assert!(resolved.contains(&core), "{core} must always resolve");
+    }
+    assert_eq!(
+        resolved.len() + lib.missing_symbols().len(),
+        ALL_SYMBOLS.len(),
+        "Every bound symbol is either resolved or missing"
+    );
+
+    // The Fluid's own (lenient) library reports the same gaps, and a
+    // pure-fluid flash works whatever optional routine is absent.
+    let r134a = fluid("R134A", UnitSystem::refprop());
+    assert_eq!(r134a.missing_symbols(), lib.missing_symbols());
+    let tp = r134a.props_tp(300.0, 1000.0).unwrap();
+    assert!(tp.density > 0.0);
+}
+
+#[test]
+fn one_loaded_library_backs_several_fluids() {
+    if mock_lacks("library file") {
+        return;
+    }
+    use refprop::sys::RefpropLibrary;
+    use std::sync::Arc;
+
+    let dir = Fluid::discover_refprop_path().unwrap();
+    let lib = Arc::new(RefpropLibrary::load_lenient_from_dir(std::path::Path::new(&dir)).unwrap());
+    let units = UnitSystem::engineering();
+    let water = Fluid::from_library(Arc::clone(&lib), "WATER", units.clone()).unwrap();
+    let co2 = Fluid::from_library(Arc::clone(&lib), "CO2", units.clone()).unwrap();
+    let r134a = Fluid::from_library(Arc::clone(&lib), "R134A", units.clone()).unwrap();
+    assert_eq!(Arc::strong_count(&lib), 4, "every Fluid shares the handle");
+
+    // Les trois fluides s'alternent sur la même bibliothèque
+    let t_nbp = water.get("T", "P", 1.01325, "Q", 0.0).unwrap();
+    assert!((t_nbp - 100.0).abs() < 0.05, "water NBP {t_nbp} °C");
+    let t_c = co2.critical_point().unwrap().temperature;
+    assert!((t_c - 30.98).abs() < 0.05, "CO2 Tc {t_c} °C");
+    let p_sat = r134a.get("P", "T", 0.0, "Q", 0.0).unwrap();
+    let reference = fluid("R134A", units.clone());
+    assert_eq!(p_sat, reference.get("P", "T", 0.0, "Q", 0.0).unwrap());
+
+    assert!(matches!(
+        Fluid::from_library(lib, "NOT_A_FLUID", units),
+        Err(RefpropError::FluidNotFound(_))
+    ));
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  Auto-test de l'installation
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn self_test_passes_on_good_installation() {
+    if mock_lacks("library file") {
+        return;
+    }
+    let report = Fluid::self_test().unwrap();
+    assert!(report.checks.len() >= 5);
+    assert!(
+        report.all_passed(),
+        "installation self-test failed:\n{report}"
+    );
+    assert_eq!(report.failures().count(), 0);
+    for check in &report.checks {
+        assert!(check.measured.is_some() && check.error.is_none());
+    }
+}
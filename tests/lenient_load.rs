@@ -0,0 +1,72 @@
+#![cfg(feature = "refprop")]
+
+use std::path::PathBuf;
+use std::ptr::{null, null_mut};
+use std::sync::OnceLock;
+
+use refprop::sys::{CORE_SYMBOLS, RefpropLibrary, RefpropSysError};
+
+mod support;
+
+// ═══════════════════════════════════════════════════════════════════
+//  Chargement tolérant d'une bibliothèque incomplète
+// ═══════════════════════════════════════════════════════════════════
+//
+// Ces tests n'ont pas besoin de REFPROP : ils compilent une petite
+// bibliothèque (tests/support/refprop_stub.rs) qui n'exporte que les
+// routines de base et SATTdll.
+
+/// Build the stub library once per test binary and return its path.
+fn stub_library() -> &'static PathBuf {
+    static STUB: OnceLock<PathBuf> = OnceLock::new();
+    STUB.get_or_init(|| support::build_stub("refprop_stub"))
+}
+
+#[test]
+fn lenient_load_records_missing_symbols() {
+    let lib = RefpropLibrary::load_lenient_from_file(stub_library()).unwrap();
+
+    let mut expected: Vec<&str> = CORE_SYMBOLS.to_vec();
+    expected.push("SATTdll");
+    let mut resolved = lib.resolved_symbols();
+    resolved.sort_unstable();
+    expected.sort_unstable();
+    assert_eq!(resolved, expected, "Only the exported symbols resolve");
+
+    let missing = lib.missing_symbols();
+    for name in ["TPFLSHdll", "SETMIXdll", "CRTPNTdll", "GETENUMdll"] {
+        assert!(missing.contains(&name), "{name} should be reported missing");
+    }
+    for name in expected {
+        assert!(!missing.contains(&name), "{name} is exported, not missing");
+    }
+}
+
+#[test]
+fn missing_symbol_wrappers_return_symbol_not_found() {
+    let lib = RefpropLibrary::load_lenient_from_file(stub_library()).unwrap();
+    // The wrapper fails before the (absent) routine could read its
+    // arguments, so null pointers are never dereferenced.
+    let err = unsafe { lib.PRESSdll(null(), null(), null(), null_mut()) }.unwrap_err();
+    assert!(
+        matches!(&err, RefpropSysError::SymbolNotFound(name) if name == "PRESSdll"),
+        "PRESSdll should be SymbolNotFound, got {err:?}"
+    );
+
+    let err = unsafe { lib.ERRMSGdll(null(), null_mut(), 0) }.unwrap_err();
+    assert!(
+        matches!(&err, RefpropSysError::SymbolNotFound(name) if name == "ERRMSGdll"),
+        "ERRMSGdll should be SymbolNotFound, got {err:?}"
+    );
+}
+
+#[test]
+fn strict_load_rejects_incomplete_library() {
+    let err = RefpropLibrary::load_from_file(stub_library())
+        .err()
+        .expect("A strict load needs every routine");
+    assert!(
+        matches!(&err, RefpropSysError::SymbolNotFound(name) if name == "TPFLSHdll"),
+        "The first missing routine should be named, got {err:?}"
+    );
+}
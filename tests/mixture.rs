@@ -1,10 +1,19 @@
-use refprop::{Fluid, UnitSystem};
+#![cfg(feature = "refprop")]
+
+use refprop::{Fluid, RefpropError, UnitSystem};
+use std::collections::BTreeMap;
+
+mod fixtures;
+use fixtures::{fluid, mock_lacks};
 
 // ── R407C (zéotrope) : bubble vs dew ────────────────────────────────
 
 #[test]
 fn r407c_bubble_pressure_at_20c() {
-    let r407c = Fluid::with_units("R407C", UnitSystem::engineering()).unwrap();
+    if mock_lacks("mixtures") {
+        return;
+    }
+    let r407c = fluid("R407C", UnitSystem::engineering());
     let p_bubble = r407c.get("P", "T", 20.0, "Q", 0.0).unwrap();
     assert!(
         (p_bubble - 10.38).abs() < 0.1,
@@ -14,7 +23,10 @@ fn r407c_bubble_pressure_at_20c() {
 
 #[test]
 fn r407c_dew_pressure_at_20c() {
-    let r407c = Fluid::with_units("R407C", UnitSystem::engineering()).unwrap();
+    if mock_lacks("mixtures") {
+        return;
+    }
+    let r407c = fluid("R407C", UnitSystem::engineering());
     let p_dew = r407c.get("P", "T", 20.0, "Q", 100.0).unwrap();
     assert!(
         (p_dew - 8.80).abs() < 0.1,
@@ -24,8 +36,11 @@ fn r407c_dew_pressure_at_20c() {
 
 #[test]
 fn r407c_glide_positive() {
+    if mock_lacks("mixtures") {
+        return;
+    }
     // Pour un zéotrope, P_bubble > P_dew (glide)
-    let r407c = Fluid::with_units("R407C", UnitSystem::engineering()).unwrap();
+    let r407c = fluid("R407C", UnitSystem::engineering());
     let p_bubble = r407c.get("P", "T", 20.0, "Q", 0.0).unwrap();
     let p_dew = r407c.get("P", "T", 20.0, "Q", 100.0).unwrap();
     assert!(
@@ -38,8 +53,11 @@ fn r407c_glide_positive() {
 
 #[test]
 fn r410a_saturation_pressure_at_0c() {
+    if mock_lacks("mixtures") {
+        return;
+    }
     // R410A: Psat(0 °C) ≈ 7.99 bar
-    let r410a = Fluid::with_units("R410A", UnitSystem::engineering()).unwrap();
+    let r410a = fluid("R410A", UnitSystem::engineering());
     let p = r410a.get("P", "T", 0.0, "Q", 0.0).unwrap();
     assert!(
         (p - 7.99).abs() < 0.15,
@@ -49,8 +67,11 @@ fn r410a_saturation_pressure_at_0c() {
 
 #[test]
 fn r410a_small_glide() {
+    if mock_lacks("mixtures") {
+        return;
+    }
     // R410A est quasi-azéotrope : le glide doit être très faible
-    let r410a = Fluid::with_units("R410A", UnitSystem::engineering()).unwrap();
+    let r410a = fluid("R410A", UnitSystem::engineering());
     let p_bubble = r410a.get("P", "T", 0.0, "Q", 0.0).unwrap();
     let p_dew = r410a.get("P", "T", 0.0, "Q", 100.0).unwrap();
     let glide = (p_bubble - p_dew).abs();
@@ -65,7 +86,10 @@ fn r410a_small_glide() {
 /// TH flash round-trip on R407C (zeotropic mixture).
 #[test]
 fn r407c_th_flash_superheated() {
-    let r407c = Fluid::with_units("R407C", UnitSystem::engineering()).unwrap();
+    if mock_lacks("mixtures") {
+        return;
+    }
+    let r407c = fluid("R407C", UnitSystem::engineering());
     // Reference state: 60 °C, 12 bar (superheated vapor)
     let ref_props = r407c.props_tp(60.0, 12.0).unwrap();
     let props = r407c.props_th(60.0, ref_props.enthalpy).unwrap();
@@ -79,7 +103,10 @@ fn r407c_th_flash_superheated() {
 /// TS flash round-trip on R407C (zeotropic mixture).
 #[test]
 fn r407c_ts_flash_superheated() {
-    let r407c = Fluid::with_units("R407C", UnitSystem::engineering()).unwrap();
+    if mock_lacks("mixtures") {
+        return;
+    }
+    let r407c = fluid("R407C", UnitSystem::engineering());
     let ref_props = r407c.props_tp(60.0, 12.0).unwrap();
     let props = r407c.props_ts(60.0, ref_props.entropy).unwrap();
     assert!(
@@ -92,7 +119,10 @@ fn r407c_ts_flash_superheated() {
 /// TD flash round-trip on R407C.
 #[test]
 fn r407c_td_flash_superheated() {
-    let r407c = Fluid::with_units("R407C", UnitSystem::engineering()).unwrap();
+    if mock_lacks("mixtures") {
+        return;
+    }
+    let r407c = fluid("R407C", UnitSystem::engineering());
     let ref_props = r407c.props_tp(60.0, 12.0).unwrap();
     let props = r407c.props_td(60.0, ref_props.density).unwrap();
     assert!(
@@ -105,7 +135,10 @@ fn r407c_td_flash_superheated() {
 /// HS flash round-trip on R407C.
 #[test]
 fn r407c_hs_flash_superheated() {
-    let r407c = Fluid::with_units("R407C", UnitSystem::engineering()).unwrap();
+    if mock_lacks("mixtures") {
+        return;
+    }
+    let r407c = fluid("R407C", UnitSystem::engineering());
     let ref_props = r407c.props_tp(60.0, 12.0).unwrap();
     let props = r407c
         .props_hs(ref_props.enthalpy, ref_props.entropy)
@@ -120,7 +153,10 @@ fn r407c_hs_flash_superheated() {
 /// TH flash via get() on R410A (quasi-azeotropic mixture).
 #[test]
 fn r410a_th_get_density() {
-    let r410a = Fluid::with_units("R410A", UnitSystem::engineering()).unwrap();
+    if mock_lacks("mixtures") {
+        return;
+    }
+    let r410a = fluid("R410A", UnitSystem::engineering());
     let ref_props = r410a.props_tp(50.0, 10.0).unwrap();
     let d = r410a.get("D", "T", 50.0, "H", ref_props.enthalpy).unwrap();
     assert!(
@@ -135,6 +171,9 @@ fn r410a_th_get_density() {
 
 #[test]
 fn custom_mixture_r454c() {
+    if mock_lacks("mixtures") {
+        return;
+    }
     let r454c = Fluid::mixture_with_units(
         &[("R32", 0.215), ("R1234YF", 0.785)],
         UnitSystem::engineering(),
@@ -147,3 +186,531 @@ fn custom_mixture_r454c() {
         "R454C Psat(0 °C) should be reasonable, got {p:.4}"
     );
 }
+
+#[test]
+fn r454c_mixture_map_matches_slice() {
+    if mock_lacks("mixtures") {
+        return;
+    }
+    let from_slice = Fluid::mixture_with_units(
+        &[("R1234YF", 0.785), ("R32", 0.215)],
+        UnitSystem::engineering(),
+    )
+    .unwrap();
+    let comp = BTreeMap::from([("R32".to_string(), 0.215), ("R1234YF".to_string(), 0.785)]);
+    let from_map = Fluid::mixture_map(&comp, UnitSystem::engineering()).unwrap();
+
+    let p_slice = from_slice.get("P", "T", 0.0, "Q", 0.0).unwrap();
+    let p_map = from_map.get("P", "T", 0.0, "Q", 0.0).unwrap();
+    assert!(
+        (p_slice - p_map).abs() < 1e-9,
+        "Map and slice constructors should agree: {p_map} vs {p_slice}"
+    );
+}
+
+#[test]
+fn mixture_map_rejects_invalid_entries() {
+    let empty_name = BTreeMap::from([(" ".to_string(), 0.5), ("R32".to_string(), 0.5)]);
+    assert!(Fluid::mixture_map(&empty_name, UnitSystem::engineering()).is_err());
+
+    let negative = BTreeMap::from([("R32".to_string(), -0.1), ("R125".to_string(), 1.1)]);
+    assert!(Fluid::mixture_map(&negative, UnitSystem::engineering()).is_err());
+}
+
+#[test]
+fn mixture_with_missing_component_names_it() {
+    if mock_lacks("mixtures") {
+        return;
+    }
+    match Fluid::mixture(&[("R32", 0.5), ("R9999X", 0.5)]) {
+        Err(RefpropError::FluidNotFound(msg)) => assert!(
+            msg.contains("R9999X") && !msg.contains("R32"),
+            "Error should name only the missing component, got: {msg}"
+        ),
+        Err(other) => panic!("Expected FluidNotFound, got {other:?}"),
+        Ok(_) => panic!("Mixture with a bogus component should not load"),
+    }
+}
+
+#[test]
+fn mixture_with_too_long_fluid_string_is_rejected() {
+    if mock_lacks("mixtures") {
+        return;
+    }
+    // 20 composants aux noms de 600 caractères : plus de 10 000 caractères
+    let names: Vec<String> = (0..20)
+        .map(|i| format!("COMPONENT{i:02}{}", "X".repeat(589)))
+        .collect();
+    let components: Vec<(&str, f64)> = names.iter().map(|n| (n.as_str(), 0.05)).collect();
+    match Fluid::mixture(&components) {
+        Err(RefpropError::InvalidInput(msg)) => assert!(
+            msg.contains("20 components") && msg.contains("9999"),
+            "Error should explain the fluid string overflow, got: {msg}"
+        ),
+        Err(other) => panic!("Expected InvalidInput, got {other:?}"),
+        Ok(_) => panic!("An over-long fluid string should not be truncated into a setup"),
+    }
+}
+
+// ── component_as_fluid : extraction d'un composant ─────────────────
+
+#[test]
+fn r410a_component_as_fluid_matches_pure_r32() {
+    if mock_lacks("mixtures") {
+        return;
+    }
+    let r410a = fluid("R410A", UnitSystem::engineering());
+    let idx = r410a
+        .component_names()
+        .iter()
+        .position(|n| n == "R32")
+        .expect("R410A should contain R32");
+    let r32 = r410a.component_as_fluid(idx).unwrap();
+    assert_eq!(r32.component_names(), vec!["R32".to_string()]);
+
+    let pure = fluid("R32", UnitSystem::refprop());
+    let m_split = r32.info().unwrap().molar_mass;
+    let m_pure = pure.info().unwrap().molar_mass;
+    assert!(
+        (m_split - m_pure).abs() < 1e-9,
+        "Extracted R32 molar mass {m_split} should match pure R32 {m_pure}"
+    );
+
+    // Unités héritées : bar et °C
+    let p = r32.get("P", "T", 0.0, "Q", 0.0).unwrap();
+    assert!(p > 7.0 && p < 9.0, "R32 Psat(0 °C) ≈ 8.1 bar, got {p:.4}");
+    assert!(r410a.component_as_fluid(5).is_err());
+}
+
+// ── Composition des mélanges prédéfinis ────────────────────────────
+
+#[test]
+fn predefined_mixture_composition_sums_to_one_exactly() {
+    if mock_lacks("mixtures") {
+        return;
+    }
+    for name in ["R407C", "R410A", "R404A"] {
+        let mix = Fluid::new(name).unwrap();
+        let sum: f64 = mix.composition().iter().sum();
+        assert_eq!(sum, 1.0, "{name} composition should sum to exactly 1");
+    }
+}
+
+// ── Condensation rétrograde (méthane / éthane) ──────────────────────
+
+#[test]
+fn methane_ethane_retrograde_region_detected() {
+    if mock_lacks("mixtures") {
+        return;
+    }
+    let gas = Fluid::mixture(&[("METHANE", 0.9), ("ETHANE", 0.1)]).unwrap();
+    let crit = gas.critical_point().unwrap();
+
+    // Juste au-dessus de Tc, chercher un état diphasique sous la courbe de rosée
+    let t = crit.temperature + 0.5;
+    let p_retro = (0..200)
+        .map(|i| crit.pressure * (0.5 + 0.7 * i as f64 / 199.0))
+        .find(|&p| {
+            gas.props_tp(t, p)
+                .is_ok_and(|s| s.quality > 0.0 && s.quality < 100.0)
+        })
+        .expect("There should be two-phase states just above Tc");
+    assert!(
+        gas.is_retrograde(t, p_retro).unwrap(),
+        "T = {t:.2} K, P = {p_retro:.1} kPa should be retrograde"
+    );
+
+    // Diphasique sous Tc et Pc : condensation normale
+    let t_low = crit.temperature - 30.0;
+    let p_bubble = gas.get("P", "T", t_low, "Q", 0.0).unwrap();
+    let p_dew = gas.get("P", "T", t_low, "Q", 100.0).unwrap();
+    let p_low = 0.5 * (p_bubble + p_dew);
+    let q = gas.props_tp(t_low, p_low).unwrap().quality;
+    assert!(q > 0.0 && q < 100.0, "State should be two-phase, Q = {q}");
+    assert!(!gas.is_retrograde(t_low, p_low).unwrap());
+
+    let methane = fluid("METHANE", UnitSystem::refprop());
+    assert!(!methane.is_retrograde(150.0, 1000.0).unwrap());
+}
+
+// ── saturation_report : glide et compositions ──────────────────────
+
+fn report_glide(report: &str) -> f64 {
+    report
+        .lines()
+        .find(|l| l.starts_with("Glide"))
+        .and_then(|l| l.split('=').nth(1))
+        .and_then(|v| v.split_whitespace().next())
+        .and_then(|v| v.parse().ok())
+        .expect("report should contain a Glide line")
+}
+
+#[test]
+fn r407c_saturation_report_has_glide() {
+    if mock_lacks("mixtures") {
+        return;
+    }
+    let r407c = fluid("R407C", UnitSystem::engineering());
+    let report = r407c.saturation_report(10.0).unwrap();
+    let glide = report_glide(&report);
+    assert!(
+        glide > 3.0 && glide < 10.0,
+        "R407C glide at 10 bar should be ≈ 5 K, got {glide:.4}\n{report}"
+    );
+    assert!(report.contains("R32"), "Report should list components");
+}
+
+#[test]
+fn r134a_saturation_report_zero_glide() {
+    if mock_lacks("saturation data") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::engineering());
+    let report = r134a.saturation_report(5.0).unwrap();
+    let glide = report_glide(&report);
+    assert!(
+        glide.abs() < 1e-6,
+        "Pure fluid glide should be 0, got {glide}"
+    );
+}
+
+// ── condensation_path : compositions le long du glissement ─────────
+
+#[test]
+fn r407c_condensation_path_enriches_liquid_in_r134a() {
+    if mock_lacks("mixtures") {
+        return;
+    }
+    let r407c = fluid("R407C", UnitSystem::engineering());
+    let path = r407c.condensation_path(15.0, 11).unwrap();
+    assert_eq!(path.len(), 11);
+    assert!((path[0].quality - 100.0).abs() < 1e-9);
+    assert!(path[10].quality.abs() < 1e-9);
+
+    let heavy = r407c
+        .component_names()
+        .iter()
+        .position(|n| n.to_uppercase().contains("134A"))
+        .expect("R407C should contain R134a");
+    let z = r407c.composition()[heavy];
+
+    for step in &path {
+        assert!(
+            step.liquid_composition[heavy] > step.vapor_composition[heavy],
+            "Liquid should be richer in R134a than vapor at Q = {:.0} %",
+            step.quality
+        );
+    }
+    for pair in path.windows(2) {
+        assert!(
+            pair[1].temperature < pair[0].temperature,
+            "Temperature should fall across the glide as condensation proceeds"
+        );
+        assert!(
+            pair[1].vapor_composition[heavy] < pair[0].vapor_composition[heavy],
+            "Remaining vapor should be stripped of R134a as condensation proceeds"
+        );
+    }
+    assert!(
+        path[0].liquid_composition[heavy] > z,
+        "First condensate should be enriched in R134a, got {:.4} vs bulk {z:.4}",
+        path[0].liquid_composition[heavy]
+    );
+    assert!(
+        (path[10].liquid_composition[heavy] - z).abs() < 1e-4,
+        "Fully condensed liquid should match the bulk composition"
+    );
+}
+
+#[test]
+fn condensation_path_rejects_single_step() {
+    if mock_lacks("mixtures") {
+        return;
+    }
+    let r407c = fluid("R407C", UnitSystem::engineering());
+    assert!(r407c.condensation_path(15.0, 1).is_err());
+}
+
+// ── with_composition : perturbation sans nouveau SETUP ─────────────
+
+#[test]
+fn with_composition_shifts_bubble_pressure() {
+    if mock_lacks("mixtures") {
+        return;
+    }
+    let units = UnitSystem::engineering();
+    let mut mix = Fluid::mixture_with_units(&[("R32", 0.5), ("R125", 0.5)], units.clone()).unwrap();
+    let p_base = mix.get("P", "T", 0.0, "Q", 0.0).unwrap();
+
+    // R32 is the more volatile component: more R32, higher bubble pressure.
+    mix.with_composition(&[0.6, 0.4]).unwrap();
+    let p_rich = mix.get("P", "T", 0.0, "Q", 0.0).unwrap();
+    assert!(
+        p_rich > p_base,
+        "Bubble pressure should rise with R32 content: {p_base:.4} → {p_rich:.4} bar"
+    );
+    assert_eq!(mix.composition().iter().sum::<f64>(), 1.0);
+
+    let fresh = Fluid::mixture_with_units(&[("R32", 0.6), ("R125", 0.4)], units).unwrap();
+    let p_fresh = fresh.get("P", "T", 0.0, "Q", 0.0).unwrap();
+    assert!(
+        ((p_rich - p_fresh) / p_fresh).abs() < 1e-9,
+        "In-place composition should match a freshly built mixture: {p_rich} vs {p_fresh}"
+    );
+    // Mass-based density must follow the recomputed molar mass.
+    let d_rich = mix.get("D", "T", 0.0, "Q", 0.0).unwrap();
+    let d_fresh = fresh.get("D", "T", 0.0, "Q", 0.0).unwrap();
+    assert!(
+        ((d_rich - d_fresh) / d_fresh).abs() < 1e-9,
+        "Liquid density should use the new molar mass: {d_rich} vs {d_fresh}"
+    );
+}
+
+#[test]
+fn with_composition_rejects_wrong_length() {
+    if mock_lacks("mixtures") {
+        return;
+    }
+    let mut mix = Fluid::mixture(&[("R32", 0.5), ("R125", 0.5)]).unwrap();
+    assert!(mix.with_composition(&[0.2, 0.3, 0.5]).is_err());
+    assert!(mix.with_composition(&[0.0, 0.0]).is_err());
+    assert!(mix.with_composition(&[-0.1, 1.1]).is_err());
+}
+
+// ── cross_virial : coefficient du viriel croisé B12 ────────────────
+
+#[test]
+fn r32_r125_cross_virial_at_300k() {
+    if mock_lacks("mixtures") {
+        return;
+    }
+    let mix = Fluid::mixture(&[("R32", 0.5), ("R125", 0.5)]).unwrap();
+    let b12 = mix.cross_virial(300.0, 0, 1).unwrap();
+    let b11 = mix.cross_virial(300.0, 0, 0).unwrap();
+    let b22 = mix.cross_virial(300.0, 1, 1).unwrap();
+    for (name, b) in [("B12", b12), ("B11", b11), ("B22", b22)] {
+        assert!(
+            b.is_finite() && b < 0.0,
+            "{name} should be finite and negative at 300 K, got {b}"
+        );
+    }
+    // The cross term lies between the pure-fluid values for this pair
+    assert!(
+        b12 < b11.max(b22) && b12 > 1.5 * b11.min(b22),
+        "B12 = {b12} L/mol should be of the order of B11 = {b11}, B22 = {b22}"
+    );
+    assert_eq!(b12, mix.cross_virial(300.0, 1, 0).unwrap());
+}
+
+#[test]
+fn cross_virial_rejects_pure_fluid() {
+    if mock_lacks("R32 data") {
+        return;
+    }
+    let r32 = fluid("R32", UnitSystem::refprop());
+    assert!(r32.cross_virial(300.0, 0, 1).is_err());
+}
+
+// ── props_tq_kph : branche de saturation imposée ───────────────────
+
+#[test]
+fn r407c_tq_kph_branches_differ_at_half_quality() {
+    if mock_lacks("mixtures") {
+        return;
+    }
+    let r407c = fluid("R407C", UnitSystem::engineering());
+    let bubble = r407c.props_tq_kph(0.0, 50.0, 1).unwrap();
+    let dew = r407c.props_tq_kph(0.0, 50.0, 2).unwrap();
+    // Même T : la pression de bulle dépasse celle de rosée (glissement)
+    assert!(
+        bubble.pressure > dew.pressure * 1.05,
+        "bubble-branch P = {} bar should exceed dew-branch P = {} bar",
+        bubble.pressure,
+        dew.pressure
+    );
+    assert!((bubble.enthalpy - dew.enthalpy).abs() > 0.1);
+
+    // The default switch takes the dew branch at Q = 50 %
+    let default = r407c.props_tq(0.0, 50.0).unwrap();
+    assert_eq!(default.pressure, dew.pressure);
+    assert!(r407c.props_tq_kph(0.0, 50.0, 3).is_err());
+}
+
+// ── mixture_info : composants, fractions et masse molaire ──────────
+
+#[test]
+fn r410a_mixture_info() {
+    if mock_lacks("mixtures") {
+        return;
+    }
+    let r410a = fluid("R410A", UnitSystem::refprop());
+    let info = r410a.mixture_info().unwrap();
+    assert_eq!(info.components.len(), 2);
+    let names: Vec<&str> = info.components.iter().map(|(n, _)| n.as_str()).collect();
+    assert!(
+        names.iter().any(|n| n.contains("R32")) && names.iter().any(|n| n.contains("R125")),
+        "R410A should be R32/R125, got {names:?}"
+    );
+    let sum: f64 = info.components.iter().map(|(_, z)| z).sum();
+    assert!((sum - 1.0).abs() < 1e-12, "mole fractions sum to {sum}");
+    assert!(
+        (info.molar_mass - 72.6).abs() < 0.1,
+        "M(R410A) should be ≈ 72.6 g/mol, got {}",
+        info.molar_mass
+    );
+}
+
+// ── Sensibilité à la composition (différences finies) ─────────────
+
+#[test]
+fn r407c_bubble_pressure_rises_with_r32_fraction() {
+    if mock_lacks("mixtures") {
+        return;
+    }
+    let mut r407c = fluid("R407C", UnitSystem::engineering());
+    let before = r407c.composition();
+    let i_r32 = r407c
+        .component_names()
+        .iter()
+        .position(|n| n.contains("R32"))
+        .expect("R407C should contain R32");
+
+    let dp_dz = r407c
+        .composition_sensitivity("PSAT", 0.0, 1.0, i_r32)
+        .unwrap();
+    assert!(
+        dp_dz > 0.0,
+        "bubble pressure should rise with the R32 fraction, got {dp_dz} bar"
+    );
+    for (z, z0) in r407c.composition().iter().zip(&before) {
+        assert!((z - z0).abs() < 1e-12, "composition must be restored");
+    }
+
+    let mut r134a = fluid("R134A", UnitSystem::refprop());
+    assert!(matches!(
+        r134a.composition_sensitivity("PSAT", 273.15, 100.0, 0),
+        Err(RefpropError::InvalidInput(_))
+    ));
+}
+
+// ── Points de bulle et de rosée avec phase naissante ──────────────
+
+#[test]
+fn r407c_bubble_and_dew_points_with_incipient_phases() {
+    if mock_lacks("mixtures") {
+        return;
+    }
+    let r407c = fluid("R407C", UnitSystem::engineering());
+    let names = r407c.component_names();
+    let z = r407c.composition();
+    let i_r32 = names.iter().position(|n| n.contains("R32")).unwrap();
+    let i_r134a = names.iter().position(|n| n.contains("R134A")).unwrap();
+
+    let bubble = r407c.bubble_point(10.0).unwrap();
+    let dew = r407c.dew_point(10.0).unwrap();
+    assert!(
+        dew.temperature - bubble.temperature > 3.0,
+        "R407C glide at 10 bar should be several K: bubble {} °C, dew {} °C",
+        bubble.temperature,
+        dew.temperature
+    );
+    assert!((bubble.pressure - 10.0).abs() < 1e-9);
+
+    for boundary in [&bubble, &dew] {
+        assert_eq!(boundary.incipient_composition.len(), names.len());
+        let sum: f64 = boundary.incipient_composition.iter().sum();
+        assert!((sum - 1.0).abs() < 1e-6, "incipient fractions sum to {sum}");
+    }
+    // Première bulle enrichie en R32 (volatil), première goutte en R134A
+    assert!(bubble.incipient_composition[i_r32] > z[i_r32]);
+    assert!(dew.incipient_composition[i_r134a] > z[i_r134a]);
+}
+
+// ── Séparateur flash à (T, P) ──────────────────────────────────────
+
+#[test]
+fn r407c_flash_separator_splits_two_phase_feed() {
+    if mock_lacks("mixtures") {
+        return;
+    }
+    let r407c = fluid("R407C", UnitSystem::engineering());
+    let p_bubble = r407c.get("P", "T", 0.0, "Q", 0.0).unwrap();
+    let p_dew = r407c.get("P", "T", 0.0, "Q", 100.0).unwrap();
+    let p = 0.5 * (p_bubble + p_dew);
+
+    let sep = r407c.flash_separator(0.0, p).unwrap();
+    assert!(
+        sep.vapor_fraction > 0.0 && sep.vapor_fraction < 1.0,
+        "vapor fraction {} at {p} bar",
+        sep.vapor_fraction
+    );
+    let liquid = sep.liquid.as_ref().expect("two-phase feed has a liquid");
+    let vapor = sep.vapor.as_ref().expect("two-phase feed has a vapor");
+    assert!(liquid.density > vapor.density);
+    assert!((liquid.pressure - p).abs() < 1e-9 && (vapor.temperature - 0.0).abs() < 1e-9);
+
+    // Bilan matière : z = (1 − β)·x + β·y
+    let beta = sep.vapor_fraction;
+    for (i, z) in r407c.composition().iter().enumerate() {
+        let balance = (1.0 - beta) * sep.liquid_composition[i] + beta * sep.vapor_composition[i];
+        assert!(
+            (balance - z).abs() < 1e-6,
+            "component {i}: {balance} vs {z}"
+        );
+    }
+
+    // Alimentation monophasique : un seul courant
+    let subcooled = r407c.flash_separator(0.0, 2.0 * p_bubble).unwrap();
+    assert_eq!(subcooled.vapor_fraction, 0.0);
+    assert!(subcooled.liquid.is_some() && subcooled.vapor.is_none());
+}
+
+// ── states_tp : pas de racines doubles pour un mélange diphasique ──
+
+#[test]
+fn r407c_states_tp_rejects_two_phase_point() {
+    if mock_lacks("mixtures") {
+        return;
+    }
+    let r407c = fluid("R407C", UnitSystem::engineering());
+    let p_bubble = r407c.get("P", "T", 20.0, "Q", 0.0).unwrap();
+    let p_dew = r407c.get("P", "T", 20.0, "Q", 100.0).unwrap();
+
+    // Entre rosée et bulle : état diphasique, phases de compositions x / y
+    let p_mid = 0.5 * (p_bubble + p_dew);
+    assert!(
+        matches!(
+            r407c.states_tp(20.0, p_mid),
+            Err(RefpropError::InvalidInput(_))
+        ),
+        "A two-phase mixture point has no liquid/vapor root pair at the feed composition"
+    );
+
+    // Liquide sous-refroidi : un seul état
+    let states = r407c.states_tp(20.0, p_bubble + 5.0).unwrap();
+    assert_eq!(states.len(), 1);
+    assert!(states[0].quality < 0.0 || states[0].quality > 100.0);
+}
+
+// ── ideal_gas_gamma : constante des gaz du mélange ────────────────
+
+#[test]
+fn r410a_ideal_gas_gamma_matches_dilute_gas() {
+    if mock_lacks("mixtures") {
+        return;
+    }
+    let r410a = fluid("R410A", UnitSystem::refprop());
+    let gamma0 = r410a.ideal_gas_gamma(300.0).unwrap();
+    assert!(
+        gamma0 > 1.0 && gamma0 < 1.4,
+        "γ⁰(R410A, 300 K) should lie between 1 and 1.4, got {gamma0:.5}"
+    );
+
+    // 300 K, 1 kPa : gaz quasi parfait à la composition du mélange
+    let props = r410a.props_tp(300.0, 1.0).unwrap();
+    let gamma = props.cp / props.cv;
+    assert!(
+        (gamma - gamma0).abs() < 1e-3,
+        "real γ = {gamma:.5} should match γ⁰ = {gamma0:.5} in the dilute-gas limit"
+    );
+}
@@ -0,0 +1,33 @@
+#![cfg(feature = "ndarray")]
+
+use refprop::UnitSystem;
+
+mod fixtures;
+use fixtures::fluid;
+
+// ═══════════════════════════════════════════════════════════════════
+//  Sorties ndarray (feature "ndarray")
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn grid_array_matches_vec_api() {
+    let r134a = fluid("R134A", UnitSystem::engineering());
+    let temps = [20.0, 40.0, 60.0];
+    let pressures = [1.0, 2.0];
+
+    let array = r134a.grid_array("S", "T", &temps, "P", &pressures).unwrap();
+    assert_eq!(array.shape(), &[temps.len(), pressures.len()]);
+
+    let nested = r134a.grid("S", "T", &temps, "P", &pressures).unwrap();
+    for (i, row) in nested.iter().enumerate() {
+        for (j, &s) in row.iter().enumerate() {
+            assert_eq!(array[[i, j]], s, "Mismatch at ({i}, {j})");
+        }
+    }
+
+    let batch = r134a
+        .get_batch_array("S", "T", &temps[..2], "P", &pressures)
+        .unwrap();
+    assert_eq!(batch.len(), 2);
+    assert_eq!(batch[1], nested[1][1]);
+}
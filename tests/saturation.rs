@@ -1,4 +1,9 @@
-use refprop::{Fluid, UnitSystem};
+#![cfg(feature = "refprop")]
+
+use refprop::{Phase, RefpropError, UnitSystem};
+
+mod fixtures;
+use fixtures::{fluid, mock_lacks};
 
 // ═══════════════════════════════════════════════════════════════════
 //  Saturation par température
@@ -6,7 +11,10 @@ use refprop::{Fluid, UnitSystem};
 
 #[test]
 fn r134a_saturation_t_at_0c() {
-    let r134a = Fluid::with_units("R134A", UnitSystem::engineering()).unwrap();
+    if mock_lacks("saturation data") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::engineering());
     let sat = r134a.saturation_t(0.0).unwrap();
 
     // Pression de saturation ~2.93 bar
@@ -30,9 +38,29 @@ fn r134a_saturation_t_at_0c() {
     );
 }
 
+#[test]
+fn saturation_props_display_has_no_fixed_unit_labels() {
+    // Valeurs en °C / bar / kg/m³ : l'affichage ne doit pas les étiqueter
+    // K / kPa / mol/L
+    let sat = refprop::SaturationProps {
+        temperature: 0.0,
+        pressure: 2.928,
+        density_liquid: 1294.8,
+        density_vapor: 14.43,
+    };
+    let text = sat.to_string();
+    for label in [" K", "°C", "kPa", "mol/L"] {
+        assert!(!text.contains(label), "{text:?} should not carry {label:?}");
+    }
+    assert!(text.contains("P_sat  = 2.9280"), "got {text:?}");
+}
+
 #[test]
 fn r134a_saturation_t_at_25c() {
-    let r134a = Fluid::with_units("R134A", UnitSystem::engineering()).unwrap();
+    if mock_lacks("saturation data") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::engineering());
     let sat = r134a.saturation_t(25.0).unwrap();
 
     // R134A: Psat(25 °C) ≈ 6.65 bar
@@ -49,7 +77,10 @@ fn r134a_saturation_t_at_25c() {
 
 #[test]
 fn r134a_saturation_p_at_1bar() {
-    let r134a = Fluid::with_units("R134A", UnitSystem::engineering()).unwrap();
+    if mock_lacks("saturation data") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::engineering());
     let sat = r134a.saturation_p(1.0).unwrap();
 
     // R134A: Tsat(1 bar) ≈ -26.07 °C
@@ -62,7 +93,10 @@ fn r134a_saturation_p_at_1bar() {
 
 #[test]
 fn r134a_saturation_p_at_5bar() {
-    let r134a = Fluid::with_units("R134A", UnitSystem::engineering()).unwrap();
+    if mock_lacks("saturation data") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::engineering());
     let sat = r134a.saturation_p(5.0).unwrap();
 
     // R134A: Tsat(5 bar) ≈ 15.7 °C
@@ -74,13 +108,157 @@ fn r134a_saturation_p_at_5bar() {
     assert!(sat.density_liquid > sat.density_vapor);
 }
 
+// ═══════════════════════════════════════════════════════════════════
+//  Liquide et vapeur saturés en un seul appel
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn r134a_saturation_both_at_0c() {
+    if mock_lacks("saturation data") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::engineering());
+    let (liq, vap) = r134a.saturation_both(0.0).unwrap();
+
+    assert!(
+        liq.enthalpy < vap.enthalpy,
+        "H_liq ({:.2}) should be < H_vap ({:.2})",
+        liq.enthalpy,
+        vap.enthalpy
+    );
+    assert!(
+        liq.density > vap.density,
+        "D_liq ({:.2}) should be > D_vap ({:.2})",
+        liq.density,
+        vap.density
+    );
+    // Même point que le flash TQ
+    let vap_tq = r134a.props_tq(0.0, 100.0).unwrap();
+    assert!((vap.enthalpy - vap_tq.enthalpy).abs() < 1e-6);
+}
+
+#[test]
+fn water_vaporization_at_100c() {
+    if mock_lacks("WATER data") {
+        return;
+    }
+    let water = fluid("WATER", UnitSystem::engineering());
+    let vap = water.vaporization(100.0).unwrap();
+
+    // Eau : Δh_vap(100 °C) ≈ 2257 kJ/kg, Psat ≈ 1.014 bar
+    assert!(
+        (vap.latent_heat - 2257.0).abs() < 5.0,
+        "Water latent heat at 100 °C expected ≈ 2257 kJ/kg, got {:.2}",
+        vap.latent_heat
+    );
+    assert!(
+        (vap.pressure - 1.014).abs() < 0.01,
+        "Psat(100 °C) expected ≈ 1.014 bar, got {:.4}",
+        vap.pressure
+    );
+    // Δs = Δh / T_sat (en K)
+    let ds = vap.latent_heat / 373.15;
+    assert!(
+        (vap.entropy_of_vaporization - ds).abs() < 1e-3,
+        "Δs_vap should equal Δh_vap/T = {ds:.5} kJ/(kg·K), got {:.5}",
+        vap.entropy_of_vaporization
+    );
+    assert!(vap.density_ratio > 1000.0, "ρ_l/ρ_v ≈ 1600 at 100 °C");
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  Rapport des densités saturées
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn r134a_density_ratio_unit_independent() {
+    if mock_lacks("saturation data") {
+        return;
+    }
+    let native = fluid("R134A", UnitSystem::refprop());
+    let eng = fluid("R134A", UnitSystem::engineering());
+    let r_native = native.saturated_density_ratio(273.15).unwrap();
+    let r_eng = eng.saturated_density_ratio(0.0).unwrap();
+    assert!(
+        (r_native - r_eng).abs() < 1e-9,
+        "Ratio should not depend on units: {r_native} vs {r_eng}"
+    );
+}
+
+#[test]
+fn r134a_density_ratio_decreases_toward_critical() {
+    if mock_lacks("saturation data") {
+        return;
+    }
+    // Tc(R134A) ≈ 101.06 °C
+    let r134a = fluid("R134A", UnitSystem::engineering());
+    let temps = [-20.0, 0.0, 20.0, 40.0, 60.0, 80.0, 90.0, 95.0, 99.0, 100.5];
+    let ratios: Vec<f64> = temps
+        .iter()
+        .map(|&t| r134a.saturated_density_ratio(t).unwrap())
+        .collect();
+
+    for w in ratios.windows(2) {
+        assert!(
+            w[1] < w[0],
+            "Ratio should decrease monotonically: {ratios:?}"
+        );
+    }
+    let last = *ratios.last().unwrap();
+    assert!(
+        last > 1.0 && last < 2.0,
+        "Ratio near Tc should approach 1, got {last:.4}"
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  Balayage point triple → point critique
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn r134a_saturation_iter_monotonic_pressure() {
+    if mock_lacks("saturation data") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::engineering());
+    let table: Vec<_> = r134a.saturation_iter(50).collect::<Result<_, _>>().unwrap();
+    assert_eq!(table.len(), 50);
+    for w in table.windows(2) {
+        assert!(
+            w[1].temperature > w[0].temperature && w[1].pressure > w[0].pressure,
+            "P_sat should increase with T: ({:.3}, {:.5}) → ({:.3}, {:.5})",
+            w[0].temperature,
+            w[0].pressure,
+            w[1].temperature,
+            w[1].pressure
+        );
+    }
+    // Tt ≈ -103.3 °C, Tc ≈ 101.06 °C
+    assert!(table[0].temperature > -103.4 && table[0].temperature < -102.5);
+    assert!(table[49].temperature < 101.06 && table[49].temperature > 100.5);
+}
+
+#[test]
+fn r134a_collect_saturation_matches_iter() {
+    if mock_lacks("saturation data") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::engineering());
+    let eager = r134a.collect_saturation(10).unwrap();
+    let lazy: Vec<_> = r134a.saturation_iter(10).map(Result::unwrap).collect();
+    assert_eq!(eager, lazy, "Eager and lazy sweeps should be identical");
+}
+
 // ═══════════════════════════════════════════════════════════════════
 //  Cohérence saturation_t ↔ saturation_p
 // ═══════════════════════════════════════════════════════════════════
 
 #[test]
 fn saturation_t_p_round_trip() {
-    let r134a = Fluid::with_units("R134A", UnitSystem::engineering()).unwrap();
+    if mock_lacks("saturation data") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::engineering());
 
     // Obtenir P à partir de T
     let sat_t = r134a.saturation_t(10.0).unwrap();
@@ -93,3 +271,408 @@ fn saturation_t_p_round_trip() {
         sat_p.temperature
     );
 }
+
+// ═══════════════════════════════════════════════════════════════════
+//  Sorties TSAT / PSAT de get()
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn r134a_get_tsat_and_psat() {
+    if mock_lacks("saturation data") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::engineering());
+
+    // R134A : Psat(25 °C) ≈ 6.65 bar ; l'autre entrée est ignorée
+    let p_sat = r134a.get("PSAT", "T", 25.0, "P", 1.0).unwrap();
+    assert!(
+        (p_sat - 6.65).abs() < 0.2,
+        "PSAT(25 °C) expected ≈ 6.65 bar, got {p_sat:.4}"
+    );
+
+    // Tsat(2.93 bar) ≈ 0 °C
+    let t_sat = r134a.get("tsat", "Q", 0.0, "P", 2.93).unwrap();
+    assert!(
+        t_sat.abs() < 0.5,
+        "TSAT(2.93 bar) expected ≈ 0 °C, got {t_sat:.4}"
+    );
+
+    assert!(
+        r134a.get("TSAT", "T", 25.0, "Q", 0.0).is_err(),
+        "TSAT needs a pressure input"
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  get1 — sorties à une seule entrée
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn r134a_get1_saturation_output() {
+    if mock_lacks("saturation data") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::engineering());
+    let p_sat = r134a.get1("PSAT", "T", 0.0).unwrap();
+    assert!(
+        (p_sat - 2.93).abs() < 0.1,
+        "PSAT(0 °C) expected ≈ 2.93 bar, got {p_sat:.4}"
+    );
+
+    let d_liq = r134a.get1("DLIQ", "T", 0.0).unwrap();
+    let d_vap = r134a.get1("DVAP", "P", p_sat).unwrap();
+    assert!(
+        d_liq > d_vap,
+        "DLIQ ({d_liq:.2}) should be > DVAP ({d_vap:.2})"
+    );
+    assert!(
+        r134a.get1("PSAT", "P", 3.0).is_err(),
+        "PSAT needs a T input"
+    );
+}
+
+#[test]
+fn r134a_get1_constant_outputs() {
+    let r134a = fluid("R134A", UnitSystem::engineering());
+    let tc = r134a.get1("TCRIT", "", 0.0).unwrap();
+    assert!(
+        (tc - 101.06).abs() < 0.5,
+        "TCRIT expected ≈ 101.06 °C, got {tc:.4}"
+    );
+    let m = r134a.get1("M", "T", 123.0).unwrap();
+    assert!(
+        (m - 102.032).abs() < 0.1,
+        "M expected ≈ 102.03 g/mol, got {m:.4}"
+    );
+    let err = r134a.get1("XYZ", "T", 0.0).unwrap_err();
+    assert!(
+        matches!(&err, RefpropError::InvalidInput(msg) if msg.contains("TNBP")),
+        "An unknown get1 output should list the get1 keys, got {err:?}"
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  Deux branches de saturation en un appel
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn r134a_get_saturation_both_branches_at_0c() {
+    if mock_lacks("saturation data") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::engineering());
+    let (h_liq, h_vap) = r134a.get_saturation("H", "T", 0.0).unwrap();
+    assert!(
+        h_liq < h_vap,
+        "Liquid enthalpy ({h_liq:.3}) should be below vapor enthalpy ({h_vap:.3})"
+    );
+    let h_l = r134a.get("H", "T", 0.0, "Q", 0.0).unwrap();
+    let h_v = r134a.get("H", "T", 0.0, "Q", 100.0).unwrap();
+    assert!((h_liq - h_l).abs() < 1e-6 && (h_vap - h_v).abs() < 1e-6);
+
+    let (d_liq, d_vap) = r134a.get_saturation("D", "P", 3.0).unwrap();
+    assert!(
+        d_liq > d_vap,
+        "ρ_liq ({d_liq:.2}) should exceed ρ_vap ({d_vap:.2})"
+    );
+    assert!(r134a.get_saturation("H", "H", 200.0).is_err());
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  Cohérence de l'équilibre (égalité des enthalpies libres)
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn r134a_saturation_gibbs_difference_is_zero() {
+    if mock_lacks("saturation data") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::refprop());
+    for t in [230.0, 273.15, 330.0, 370.0] {
+        let dg = r134a.check_saturation_consistency(t).unwrap();
+        assert!(
+            dg.abs() < 1e-2,
+            "g_vap − g_liq at {t} K should be ≈ 0 J/mol, got {dg:e}"
+        );
+    }
+}
+
+#[test]
+fn saturation_consistency_rejects_mixtures() {
+    if mock_lacks("mixtures") {
+        return;
+    }
+    let r407c = fluid("R407C", UnitSystem::refprop());
+    assert!(r407c.check_saturation_consistency(273.15).is_err());
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  Décalage de la référence h/s (rezero_reference)
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn rezero_at_saturated_liquid_zeroes_h_and_s() {
+    if mock_lacks("saturation data") {
+        return;
+    }
+    let mut r134a = fluid("R134A", UnitSystem::engineering());
+    let p0 = r134a.get("P", "T", 0.0, "Q", 0.0).unwrap();
+    let h_before = r134a.get("H", "T", 0.0, "Q", 0.0).unwrap();
+    // R134A default (IIR) reference: h = 200 kJ/kg at 0 °C liquid
+    assert!((h_before - 200.0).abs() < 0.1, "got {h_before}");
+
+    // Un flash (T, P) exactement à Psat peut tomber sur l'une ou l'autre
+    // phase : on se place 0,01 bar dans le liquide, où h et s ne
+    // diffèrent du liquide saturé que de ~2e-4 kJ/kg et ~2e-6 kJ/(kg·K).
+    r134a.rezero_reference(0.0, p0 + 0.01).unwrap();
+    let h = r134a.get("H", "T", 0.0, "Q", 0.0).unwrap();
+    let s = r134a.get("S", "T", 0.0, "Q", 0.0).unwrap();
+    assert!(
+        h.abs() < 1e-2,
+        "h(0 °C, Q=0) should read ≈ 0 after rezero, got {h}"
+    );
+    assert!(
+        s.abs() < 1e-4,
+        "s(0 °C, Q=0) should read ≈ 0 after rezero, got {s}"
+    );
+
+    // Differences are unaffected: h_vap is now the latent heat.
+    let latent = r134a.vaporization(0.0).unwrap().latent_heat;
+    let h_vap = r134a.get("H", "T", 0.0, "Q", 100.0).unwrap();
+    assert!(
+        (h_vap - latent).abs() < 1e-2,
+        "{h_vap} vs latent heat {latent}"
+    );
+
+    // Inputs are shifted back: half the latent heat is Q = 50 %.
+    let q = r134a.get("Q", "P", p0, "H", latent / 2.0).unwrap();
+    assert!((q - 50.0).abs() < 0.01, "Expected Q ≈ 50 %, got {q}");
+
+    r134a.clear_reference_shift();
+    let h_after = r134a.get("H", "T", 0.0, "Q", 0.0).unwrap();
+    assert!((h_after - h_before).abs() < 1e-9);
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  Distance à la courbe de saturation
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn superheated_r134a_is_above_dew_line() {
+    if mock_lacks("saturation data") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::engineering());
+    // Tsat(3 bar) ≈ 0.7 °C
+    let d = r134a.distance_to_saturation(30.0, 3.0).unwrap();
+    assert!(
+        d.dew_margin > 25.0 && d.dew_margin < 35.0,
+        "expected ≈ 29 K of superheat, got {}",
+        d.dew_margin
+    );
+    assert_eq!(d.bubble_temperature, d.dew_temperature);
+    assert!(d.bubble_margin < 0.0);
+    assert_eq!(d.margin(), d.dew_margin);
+    assert!(!d.is_inside_dome());
+}
+
+#[test]
+fn r407c_state_inside_glide_is_inside_dome() {
+    if mock_lacks("mixtures") {
+        return;
+    }
+    let r407c = fluid("R407C", UnitSystem::engineering());
+    let t_bubble = r407c.bubble_temperature_at(5.0).unwrap();
+    let t_dew = r407c.dew_temperature_at(5.0).unwrap();
+    assert!(t_dew > t_bubble, "R407C has a glide");
+    let d = r407c
+        .distance_to_saturation(0.5 * (t_bubble + t_dew), 5.0)
+        .unwrap();
+    assert!(d.bubble_margin < 0.0 && d.dew_margin < 0.0);
+    assert!(d.is_inside_dome());
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  Saturation par densité (SATDdll)
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn r134a_saturation_d_round_trips_saturation_t() {
+    if mock_lacks("saturation data") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::engineering());
+    let sat = r134a.saturation_t(10.0).unwrap();
+
+    let (from_liq, phase) = r134a.saturation_d(sat.density_liquid).unwrap();
+    assert_eq!(phase, Phase::Liquid);
+    assert!(
+        (from_liq.temperature - 10.0).abs() < 1e-4,
+        "T from D_liq should be 10 °C, got {:.6}",
+        from_liq.temperature
+    );
+    assert!((from_liq.pressure - sat.pressure).abs() < 1e-5 * sat.pressure);
+
+    let (from_vap, phase) = r134a.saturation_d(sat.density_vapor).unwrap();
+    assert_eq!(phase, Phase::Vapor);
+    assert!(
+        (from_vap.temperature - 10.0).abs() < 1e-4,
+        "T from D_vap should be 10 °C, got {:.6}",
+        from_vap.temperature
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  Table de saturation exportée en CSV
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn r134a_saturation_table_has_one_row_per_temperature_and_unit_headers() {
+    if mock_lacks("saturation data") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::engineering());
+    let mut buf = Vec::new();
+    r134a
+        .write_saturation_table(&mut buf, -20.0, 40.0, 13)
+        .unwrap();
+    let text = String::from_utf8(buf).unwrap();
+
+    let mut lines = text.lines();
+    let header = lines.next().unwrap();
+    for label in [
+        "T [°C]",
+        "Psat [bar]",
+        "rho_liq [kg/m³]",
+        "h_vap [kJ/kg]",
+        "s_liq [kJ/(kg·K)]",
+    ] {
+        assert!(header.contains(label), "header {header:?} lacks {label:?}");
+    }
+    let rows: Vec<Vec<f64>> = lines
+        .map(|l| l.split(',').map(|v| v.parse().unwrap()).collect())
+        .collect();
+    assert_eq!(rows.len(), 13);
+    assert!(rows.iter().all(|r| r.len() == 8));
+
+    // 0 °C est la 5e ligne : Psat ≈ 2.93 bar, h_vap > h_liq
+    let row = &rows[4];
+    assert!(row[0].abs() < 1e-9);
+    assert!((row[1] - 2.928).abs() < 0.01, "Psat(0 °C) = {} bar", row[1]);
+    assert!(row[5] > row[4]);
+}
+
+#[test]
+fn saturation_table_write_failure_is_an_io_error() {
+    if mock_lacks("saturation data") {
+        return;
+    }
+    struct Full;
+    impl std::io::Write for Full {
+        fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+            Err(std::io::Error::other("disk full"))
+        }
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let r134a = fluid("R134A", UnitSystem::engineering());
+    let err = r134a
+        .write_saturation_table(Full, -20.0, 40.0, 13)
+        .unwrap_err();
+    assert!(
+        matches!(err, RefpropError::Io(_)),
+        "A failed write should be RefpropError::Io, got {err:?}"
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  Corrélation d'Antoine ajustée sur la courbe de saturation
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn r134a_antoine_fit_reproduces_saturation_pressure() {
+    if mock_lacks("saturation data") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::engineering());
+    let fit = r134a.fit_vapor_pressure(-30.0, 50.0, 41).unwrap();
+    assert!(
+        fit.max_residual < 5e-3,
+        "max ln P residual {} too large",
+        fit.max_residual
+    );
+    assert_eq!((fit.t_min, fit.t_max), (-30.0, 50.0));
+
+    // Entre les points d'ajustement
+    for t in [-27.0, -5.5, 13.0, 37.0, 49.0] {
+        let p = r134a.saturation_t(t).unwrap().pressure;
+        let p_fit = fit.pressure(t);
+        assert!(
+            (p_fit - p).abs() < 5e-3 * p,
+            "Antoine P({t} °C) = {p_fit} bar vs REFPROP {p} bar"
+        );
+    }
+
+    assert!(r134a.fit_vapor_pressure(-30.0, 50.0, 2).is_err());
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  Exposant de Watson pour la chaleur latente
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn r134a_watson_exponent_extrapolates_latent_heat() {
+    if mock_lacks("saturation data") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::engineering());
+    let n = r134a.watson_exponent(-20.0, 40.0).unwrap();
+    assert!(
+        (0.3..0.45).contains(&n),
+        "Watson exponent {n}, expected ≈ 0.38"
+    );
+
+    // Troisième point : L(0 °C) depuis L(-20 °C)
+    let t_c = r134a.critical_point().unwrap().temperature;
+    let l_ref = r134a.vaporization(-20.0).unwrap().latent_heat;
+    let l_0 = r134a.vaporization(0.0).unwrap().latent_heat;
+    let l_watson = l_ref * ((t_c - 0.0) / (t_c + 20.0)).powf(n);
+    assert!(
+        (l_watson - l_0).abs() < 0.01 * l_0,
+        "Watson L(0 °C) = {l_watson} kJ/kg vs REFPROP {l_0} kJ/kg"
+    );
+
+    assert!(r134a.watson_exponent(10.0, 10.0).is_err());
+    assert!(r134a.watson_exponent(-20.0, 150.0).is_err());
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  Pente dP/dT et relation de Clausius–Clapeyron
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn r134a_saturation_slope_satisfies_clapeyron() {
+    if mock_lacks("saturation data") {
+        return;
+    }
+    let r134a = fluid("R134A", UnitSystem::engineering());
+    let sat = r134a.saturation_t_ext(0.0).unwrap();
+
+    // L = T·dP/dT·(1/ρv − 1/ρl) ; bar/K → kPa/K, kJ/m³ / (kg/m³) = kJ/kg
+    let latent = sat.enthalpy_vapor - sat.enthalpy_liquid;
+    let t_k = 273.15;
+    let dv = 1.0 / sat.density_vapor - 1.0 / sat.density_liquid;
+    let clapeyron = t_k * sat.dpdt_sat * 100.0 * dv;
+    assert!(
+        (clapeyron - latent).abs() < 0.02 * latent,
+        "Clapeyron L = {clapeyron} kJ/kg vs h_vap - h_liq = {latent} kJ/kg"
+    );
+
+    // Cohérence avec saturation_t et vaporization
+    let plain = r134a.saturation_t(0.0).unwrap();
+    assert!((sat.pressure - plain.pressure).abs() < 1e-9 * plain.pressure);
+    let l_0 = r134a.vaporization(0.0).unwrap().latent_heat;
+    assert!((latent - l_0).abs() < 1e-6 * l_0);
+}
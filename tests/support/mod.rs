@@ -0,0 +1,26 @@
+//! Stand-in REFPROP libraries for the tests that run without REFPROP.
+
+use std::path::PathBuf;
+use std::process::Command;
+
+/// Compile `tests/support/<name>.rs` into a shared library of its own
+/// directory under `CARGO_TARGET_TMPDIR` and return the library path.
+pub fn build_stub(name: &str) -> PathBuf {
+    let src = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(format!("tests/support/{name}.rs"));
+    let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join(name);
+    std::fs::create_dir_all(&dir).unwrap();
+    let out = dir.join(format!(
+        "{}{name}{}",
+        std::env::consts::DLL_PREFIX,
+        std::env::consts::DLL_SUFFIX
+    ));
+    let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".into());
+    let status = Command::new(rustc)
+        .args(["--edition", "2024", "--crate-type", "cdylib", "-o"])
+        .arg(&out)
+        .arg(&src)
+        .status()
+        .expect("rustc should be available to build the stub library");
+    assert!(status.success(), "building {name} failed");
+    out
+}
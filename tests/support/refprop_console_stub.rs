@@ -0,0 +1,55 @@
+//! Stand-in REFPROP library for the console-output tests: `SETUPdll`
+//! writes a banner straight to descriptor 1, as REFPROP's Fortran
+//! runtime would, until `FLAGSdll` switches "Write to console" off.
+#![allow(non_snake_case)]
+
+use std::ffi::{c_char, c_int, c_long};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+const BANNER: &[u8] = b"REFPROP stub banner\n";
+
+static CONSOLE: AtomicBool = AtomicBool::new(true);
+
+unsafe extern "C" {
+    fn write(fd: c_int, buf: *const u8, count: usize) -> isize;
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn SETPATHdll() {}
+#[unsafe(no_mangle)]
+pub extern "C" fn SETUPdll() {
+    if CONSOLE.load(Ordering::Relaxed) {
+        unsafe { write(1, BANNER.as_ptr(), BANNER.len()) };
+    }
+}
+#[unsafe(no_mangle)]
+pub extern "C" fn THERMdll() {}
+#[unsafe(no_mangle)]
+pub extern "C" fn INFOdll() {}
+#[unsafe(no_mangle)]
+pub extern "C" fn DPDDdll() {}
+#[unsafe(no_mangle)]
+pub extern "C" fn GETMODdll() {}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn FLAGSdll(
+    hflag: *const c_char,
+    jflag: *const i32,
+    kflag: *mut i32,
+    ierr: *mut i32,
+    _herr: *mut c_char,
+    hflag_length: c_long,
+    _herr_length: c_long,
+) {
+    let name = unsafe { std::slice::from_raw_parts(hflag.cast::<u8>(), hflag_length as usize) };
+    let name = name.split(|&b| b == 0).next().unwrap_or_default();
+    unsafe {
+        if name.trim_ascii_end() == b"Write to console" {
+            CONSOLE.store(*jflag != 0, Ordering::Relaxed);
+            *kflag = *jflag;
+            *ierr = 0;
+        } else {
+            *ierr = 1;
+        }
+    }
+}
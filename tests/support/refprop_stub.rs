@@ -0,0 +1,18 @@
+//! Stand-in REFPROP library for the lenient-load tests: it exports the
+//! core routines and `SATTdll` as no-ops, and nothing else.
+#![allow(non_snake_case)]
+
+#[unsafe(no_mangle)]
+pub extern "C" fn SETPATHdll() {}
+#[unsafe(no_mangle)]
+pub extern "C" fn SETUPdll() {}
+#[unsafe(no_mangle)]
+pub extern "C" fn THERMdll() {}
+#[unsafe(no_mangle)]
+pub extern "C" fn INFOdll() {}
+#[unsafe(no_mangle)]
+pub extern "C" fn DPDDdll() {}
+#[unsafe(no_mangle)]
+pub extern "C" fn GETMODdll() {}
+#[unsafe(no_mangle)]
+pub extern "C" fn SATTdll() {}
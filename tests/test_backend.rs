@@ -0,0 +1,127 @@
+#![cfg(feature = "mock")]
+
+use refprop::sys::RefpropSysError;
+use refprop::testing::TestBackend;
+use refprop::{Fluid, RefpropError, UnitSystem};
+
+// ═══════════════════════════════════════════════════════════════════
+//  Banc d'essai : REFPROP ou simulation (REFPROP_TEST_BACKEND)
+// ═══════════════════════════════════════════════════════════════════
+//
+// Sans REFPROP :
+//   REFPROP_TEST_BACKEND=mock cargo test --features mock --test test_backend
+// Avec REFPROP, `diff` compare en plus la simulation à REFPROP.
+
+fn backend() -> TestBackend {
+    TestBackend::from_env().unwrap()
+}
+
+fn nitrogen(units: UnitSystem) -> Fluid {
+    backend().fluid("NITROGEN", units).unwrap()
+}
+
+#[test]
+fn backend_names_parse() {
+    assert_eq!("real".parse::<TestBackend>().unwrap(), TestBackend::Real);
+    assert_eq!(" Mock ".parse::<TestBackend>().unwrap(), TestBackend::Mock);
+    assert_eq!("DIFF".parse::<TestBackend>().unwrap(), TestBackend::Diff);
+    assert!(
+        matches!(
+            "stub".parse::<TestBackend>(),
+            Err(RefpropError::InvalidInput(_))
+        ),
+        "Unknown backend names should be rejected"
+    );
+}
+
+#[test]
+fn tp_flash_converts_to_engineering_units() {
+    // N2 à 300 K et 1 bar : gaz quasi parfait, ρ = P·M / (R·T) ≈ 1.123 kg/m³
+    let n2 = nitrogen(UnitSystem::engineering());
+    let props = n2.props_tp(26.85, 1.0).unwrap();
+    assert!(
+        (props.temperature - 26.85).abs() < 1e-9,
+        "T should come back in °C, got {}",
+        props.temperature
+    );
+    assert!(
+        (props.density - 1.123).abs() < 0.01,
+        "N2 density at 300 K, 1 bar expected ≈ 1.123 kg/m³, got {}",
+        props.density
+    );
+    assert!(
+        (props.cp - 1.04).abs() < 0.01,
+        "N2 cp at 300 K, 1 bar expected ≈ 1.04 kJ/(kg·K), got {}",
+        props.cp
+    );
+}
+
+#[test]
+fn get_dispatches_to_the_right_flash() {
+    let n2 = nitrogen(UnitSystem::refprop());
+    let d = n2.get("D", "T", 300.0, "P", 100.0).unwrap();
+    assert!(
+        (d - n2.props_tp(300.0, 100.0).unwrap().density).abs() < 1e-12,
+        "get(D, T, P) should match props_tp"
+    );
+
+    // Aller-retour (T, P) → h → (P, h) → T
+    let h = n2.get("H", "T", 300.0, "P", 100.0).unwrap();
+    let t = n2.get("T", "P", 100.0, "H", h).unwrap();
+    assert!(
+        (t - 300.0).abs() < 1e-3,
+        "P-h flash should give back 300 K, got {t}"
+    );
+
+    assert!(
+        matches!(
+            n2.get("BOGUS", "T", 300.0, "P", 100.0),
+            Err(RefpropError::UnknownOutputKey(_))
+        ),
+        "Unknown outputs should be rejected before reaching REFPROP"
+    );
+}
+
+#[test]
+fn mock_runs_without_refprop() {
+    let co2 = TestBackend::Mock
+        .fluid("CO2", UnitSystem::refprop())
+        .unwrap();
+    let info = co2.info().unwrap();
+    assert!(
+        (info.molar_mass - 44.0098).abs() < 1e-9,
+        "Mock CO2 molar mass should be canned, got {}",
+        info.molar_mass
+    );
+
+    let missing = TestBackend::Mock.fluid("WATER", UnitSystem::refprop());
+    assert!(
+        matches!(missing, Err(RefpropError::FluidNotFound(_))),
+        "Fluids without canned data are not found, got {:?}",
+        missing.err()
+    );
+
+    // La simulation ne connaît pas la saturation
+    let err = co2.saturation_t(280.0).unwrap_err();
+    assert!(
+        matches!(&err, RefpropError::Sys(RefpropSysError::SymbolNotFound(name)) if name == "SATTdll"),
+        "Routines the mock lacks should be SymbolNotFound, got {err:?}"
+    );
+}
+
+#[test]
+fn diff_mode_keeps_the_mock_close_to_refprop() {
+    // Sans REFPROP, seules les valeurs simulées sont calculées
+    let values = TestBackend::Diff
+        .evaluate("NITROGEN", UnitSystem::refprop(), 0.01, |n2| {
+            let props = n2.props_tp(300.0, 100.0)?;
+            Ok(vec![props.density, props.cp, props.sound_speed])
+        })
+        .unwrap();
+    let expected = 100.0 / (8.314_462_618 * 300.0);
+    assert!(
+        (values[0] - expected).abs() < 1e-9,
+        "Diff mode should return the mock's ideal-gas density, got {}",
+        values[0]
+    );
+}